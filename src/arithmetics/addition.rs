@@ -1,12 +1,14 @@
 use crate::base::{
     commutative_association::CommutativeAssociation, expression::Expression, symbol::Symbol,
 };
-use num::bigint::BigInt;
+use num::rational::BigRational;
 
 impl Expression {
     /**
      * Builds a additive expression
      *  - ignores neutral element
+     *  - folds integers and rationals into a single exact number
+     *  - folds exact multiples of the imaginary unit: 2i + 3i == 5i
      */
     pub fn addition(addends: Vec<Expression>) -> Expression {
         let addends: Vec<Expression> = addends
@@ -21,8 +23,9 @@ impl Expression {
 
         let mut pending_addends: Vec<Expression> = addends.iter().cloned().collect();
         let mut items_vec: Vec<Expression> = Vec::new();
-        let mut integer: Symbol<BigInt> = Symbol::integer(0);
+        let mut rational: Symbol<BigRational> = Symbol::rational(0, 1);
         let mut real: Symbol<f64> = Symbol::real(0.0);
+        let mut imaginary: Symbol<BigRational> = Symbol::rational(0, 1);
 
         while !pending_addends.is_empty() {
             let addend = &pending_addends.pop().unwrap();
@@ -37,21 +40,24 @@ impl Expression {
                     real = real + r;
                 }
                 Expression::Integer(n) => {
-                    integer = integer + n;
+                    rational = rational + Symbol::from(n.clone());
                 }
-                _ => {
-                    items_vec.push(addend.clone());
+                Expression::Rational(q) => {
+                    rational = rational + q;
                 }
+                _ => match imaginary_coefficient(addend) {
+                    Some(coefficient) => imaginary = imaginary + coefficient,
+                    None => items_vec.push(addend.clone()),
+                },
             }
         }
 
-        if integer < Symbol::integer(0) {
-            items_vec.push(Expression::multiplication(vec![
-                Symbol::integer(-1).expr(),
-                (Symbol::integer(-1) * integer).expr(),
-            ]));
-        } else if integer > Symbol::integer(0) {
-            items_vec.push(integer.expr());
+        if !rational.is_zero() {
+            items_vec.push(rational.expr());
+        }
+
+        if !imaginary.is_zero() {
+            items_vec.push(imaginary.expr() * Symbol::i().expr());
         }
 
         if real < Symbol::real(0.0) {
@@ -75,6 +81,28 @@ impl Expression {
     }
 }
 
+/**
+ * Exact coefficient of an imaginary addend, as 3/2 in 3/2 * i
+ */
+pub(crate) fn imaginary_coefficient(addend: &Expression) -> Option<Symbol<BigRational>> {
+    let i = Symbol::i().expr();
+    if addend == &i {
+        return Some(Symbol::rational(1, 1));
+    }
+    match addend {
+        Expression::Multiplication(factors) => match factors.items().as_slice() {
+            [Expression::Integer(n), unit] | [unit, Expression::Integer(n)] if unit == &i => {
+                Some(Symbol::from(n.clone()))
+            }
+            [Expression::Rational(q), unit] | [unit, Expression::Rational(q)] if unit == &i => {
+                Some(q.clone())
+            }
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
 /**
  * Overloads plus (*) Operation
  */
@@ -39,4 +39,28 @@ mod constructor {
 
         assert_eq!(sum, Symbol::real(13.0).expr());
     }
+
+    #[test]
+    fn addition_merges_rationals() {
+        let sum = Symbol::rational(2, 3).expr() + Symbol::rational(5, 7).expr();
+        assert_eq!(sum, Symbol::rational(29, 21).expr());
+
+        let sum = Symbol::integer(1).expr() / Symbol::integer(3).expr()
+            + Symbol::integer(1).expr() / Symbol::integer(6).expr();
+        assert_eq!(sum, Symbol::rational(1, 2).expr());
+    }
+
+    #[test]
+    fn addition_merges_rationals_into_integers() {
+        let sum = Expression::addition(vec![
+            Symbol::rational(1, 2).expr(),
+            Symbol::rational(1, 3).expr(),
+            Symbol::rational(1, 6).expr(),
+        ]);
+        assert_eq!(sum, Symbol::integer(1).expr());
+
+        let x = &Symbol::variable("x").expr();
+        let sum = Symbol::rational(1, 6).expr() + x + Symbol::rational(-1, 6).expr();
+        assert_eq!(sum, *x);
+    }
 } /* end - constructor tests */
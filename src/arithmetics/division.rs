@@ -1,72 +1,19 @@
 use crate::base::{expression::Expression, symbol::Symbol};
+use num::rational::BigRational;
 
 impl Expression {
     pub fn division(dividend: Expression, divisor: Expression) -> Expression {
         match &divisor {
+            /* Exact division folds into the multiplication */
             Expression::Integer(integer_divisor) => {
-                /* Avoid unnecessary power structures */
-                if integer_divisor == &Symbol::integer(1) {
-                    return dividend;
+                let rational_divisor: Symbol<BigRational> = Symbol::from(integer_divisor.clone());
+                if let Some(inverse) = rational_divisor.inverse() {
+                    return Expression::multiplication(vec![dividend, inverse.expr()]);
                 }
-                if integer_divisor == &Symbol::integer(-1) {
-                    return Expression::multiplication(vec![dividend, Symbol::integer(-1).expr()]);
-                }
-
-                match &dividend {
-                    Expression::Integer(integer_dividend) => {
-                        let gcd = &Symbol::gcd(integer_divisor, integer_dividend);
-                        if integer_divisor == gcd {
-                            return (integer_dividend / gcd).expr();
-                        } else if integer_dividend == gcd {
-                            return Expression::power(
-                                (integer_divisor / integer_dividend).expr(),
-                                Symbol::integer(-1).expr(),
-                            );
-                        } else {
-                            return Expression::multiplication(vec![
-                                (integer_dividend / gcd).expr(),
-                                Expression::power(
-                                    (integer_divisor / gcd).expr(),
-                                    Symbol::integer(-1).expr(),
-                                ),
-                            ]);
-                        }
-                    }
-                    Expression::Multiplication(factors) => {
-                        let possible_interger_factor: Option<Expression> =
-                            factors.items().iter().cloned().find(|factor| match factor {
-                                Expression::Integer(_) => true,
-                                _ => false,
-                            });
-
-                        match possible_interger_factor {
-                            Some(integer_factor) => match integer_factor {
-                                Expression::Integer(_) => {
-                                    let mut factors: Vec<Expression> = factors
-                                        .items()
-                                        .iter()
-                                        .filter(|factor| match factor {
-                                            Expression::Integer(_) => false,
-                                            _ => true,
-                                        })
-                                        .cloned()
-                                        .collect();
-
-                                    factors.push(Expression::division(integer_factor, divisor));
-
-                                    return Expression::multiplication(factors);
-                                }
-                                _ => {}
-                            },
-                            None => {
-                                let mut factors: Vec<Expression> = factors.items();
-                                factors
-                                    .push(Expression::power(divisor, Symbol::integer(-1).expr()));
-                                return Expression::multiplication(factors);
-                            }
-                        }
-                    }
-                    _ => { /* nothing to do */ }
+            }
+            Expression::Rational(rational_divisor) => {
+                if let Some(inverse) = rational_divisor.inverse() {
+                    return Expression::multiplication(vec![dividend, inverse.expr()]);
                 }
             }
             Expression::Real(real_divisor) => {
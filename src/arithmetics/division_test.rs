@@ -17,12 +17,8 @@ mod constructor {
     fn irreducible_fraction_is_kept_given_gcd() {
         let div = Symbol::integer(9).expr() / Symbol::integer(6).expr();
 
-        if let Expression::Multiplication(factors) = div {
-            assert!(factors.items().contains(&Symbol::integer(3).expr()));
-            assert!(factors.items().contains(&Expression::power(
-                Symbol::integer(2).expr(),
-                Symbol::integer(-1).expr(),
-            )));
+        if let Expression::Rational(q) = div {
+            assert_eq!(q, Symbol::rational(3, 2));
         } else {
             panic!();
         }
@@ -45,10 +41,7 @@ mod constructor {
 
         if let Expression::Multiplication(factors) = div {
             assert!(factors.items().contains(&Symbol::real(6.0).expr()));
-            assert!(factors.items().contains(&Expression::power(
-                Symbol::integer(3).expr(),
-                Symbol::integer(-1).expr(),
-            )));
+            assert!(factors.items().contains(&Symbol::rational(1, 3).expr()));
         } else {
             panic!();
         }
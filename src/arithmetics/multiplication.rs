@@ -1,13 +1,17 @@
+use crate::arithmetics::addition::imaginary_coefficient;
 use crate::base::{
     commutative_association::CommutativeAssociation, expression::Expression, symbol::Symbol,
 };
-use num::bigint::BigInt;
+use num::rational::BigRational;
 
 impl Expression {
     /**
      * Builds a multiplicative expression
      *  - ignores neutral element
      *  - separates sign with -1 integer part
+     *  - folds integers and rationals into a single exact number
+     *  - folds powers of the imaginary unit: i * i == -1
+     *  - folds Gaussian rationals with the exact part: 2 * (1 + i) * (1 - i) == 4
      */
     pub fn multiplication(factors: Vec<Expression>) -> Expression {
         let factors: Vec<Expression> = factors
@@ -23,8 +27,9 @@ impl Expression {
         let mut pending_factors: Vec<Expression> = factors.iter().cloned().collect();
         /* factors that builds multiplication */
         let mut items_vec: Vec<Expression> = Vec::new();
-        let mut integer: Symbol<BigInt> = Symbol::integer(1); /* integer part */
+        let mut rational: Symbol<BigRational> = Symbol::rational(1, 1); /* exact part */
         let mut real: Symbol<f64> = Symbol::real(1.0); /* real part */
+        let mut imaginary_units: usize = 0; /* count of i factors */
 
         while !pending_factors.is_empty() {
             let factor = &pending_factors.pop().unwrap();
@@ -36,7 +41,7 @@ impl Expression {
                     if r == &Symbol::real(0.0) {
                         return Symbol::integer(0).expr();
                     } else if r.value().unwrap() < 0.0 {
-                        integer = integer * Symbol::integer(-1);
+                        rational = rational.opposite();
                         real = real * r * Symbol::real(-1.0);
                         continue;
                     } else {
@@ -48,25 +53,67 @@ impl Expression {
                     if n == &Symbol::integer(0) {
                         return Symbol::integer(0).expr();
                     } else {
-                        integer = integer * n;
+                        rational = rational * Symbol::from(n.clone());
                         continue;
                     }
                 }
+                Expression::Rational(q) => {
+                    rational = rational * q;
+                }
+                Expression::Constant(c) if c == &Symbol::i() => {
+                    imaginary_units += 1;
+                }
                 _ => {
                     items_vec.push(factor.clone());
                 }
             }
         }
 
-        if integer < Symbol::integer(-1) && real != Symbol::real(1.0) {
+        /* exact part and i units fold into the Gaussian factors */
+        let (gaussians, mut items_vec): (Vec<Expression>, Vec<Expression>) = items_vec
+            .into_iter()
+            .partition(|factor| gaussian_parts(factor).is_some());
+        if !gaussians.is_empty() {
+            let unit = (Symbol::rational(0, 1), Symbol::rational(1, 1));
+            let (a, b) = gaussians
+                .iter()
+                .filter_map(gaussian_parts)
+                .chain(std::iter::repeat_n(unit, imaginary_units % 4))
+                .fold((rational, Symbol::rational(0, 1)), |product, factor| {
+                    gaussian_product(&product, &factor)
+                });
+            imaginary_units = 0;
+            if b.is_zero() {
+                rational = a;
+            } else if a.is_zero() {
+                rational = b;
+                imaginary_units = 1;
+            } else {
+                items_vec.push(Expression::addition(vec![
+                    a.expr(),
+                    b.expr() * Symbol::i().expr(),
+                ]));
+                rational = Symbol::rational(1, 1);
+            }
+        }
+
+        if imaginary_units % 4 >= 2 {
+            rational = rational.opposite();
+        }
+
+        if rational < Symbol::rational(-1, 1) && real != Symbol::real(1.0) {
             let real_value = real.value().unwrap();
-            let integer_value = integer.value().unwrap();
-            real = Symbol::real(real_value * (integer_value * -1) as f64);
-            integer = Symbol::integer(-1);
+            let rational_value = rational.abs().value().unwrap();
+            real = Symbol::real(real_value * rational_value);
+            rational = Symbol::rational(-1, 1);
         }
 
-        if integer != Symbol::integer(1) {
-            items_vec.push(integer.expr());
+        if imaginary_units % 2 == 1 {
+            items_vec.push(Symbol::i().expr());
+        }
+
+        if !rational.is_one() {
+            items_vec.push(rational.expr());
         }
 
         if real != Symbol::real(1.0) {
@@ -85,6 +132,36 @@ impl Expression {
     }
 }
 
+/**
+ * Exact parts of a Gaussian rational, as (1, 2) in 1 + 2 * i
+ */
+pub(crate) fn gaussian_parts(
+    expression: &Expression,
+) -> Option<(Symbol<BigRational>, Symbol<BigRational>)> {
+    match expression {
+        Expression::Addition(addends) => match addends.items().as_slice() {
+            [Expression::Integer(n), imaginary] | [imaginary, Expression::Integer(n)] => {
+                Some((Symbol::from(n.clone()), imaginary_coefficient(imaginary)?))
+            }
+            [Expression::Rational(q), imaginary] | [imaginary, Expression::Rational(q)] => {
+                Some((q.clone(), imaginary_coefficient(imaginary)?))
+            }
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/**
+ * Product of Gaussian rationals: (a + b i) * (c + d i) == (ac - bd) + (ad + bc) i
+ */
+pub(crate) fn gaussian_product(
+    (a, b): &(Symbol<BigRational>, Symbol<BigRational>),
+    (c, d): &(Symbol<BigRational>, Symbol<BigRational>),
+) -> (Symbol<BigRational>, Symbol<BigRational>) {
+    (a * c - b * d, a * d + b * c)
+}
+
 /**
  * Overloads plus (*) Operation
  */
@@ -55,4 +55,30 @@ mod constructor {
             Symbol::integer(-1).expr() * Symbol::variable("x").expr()
         );
     }
+
+    #[test]
+    fn multiply_merge_rationals() {
+        let product = Symbol::rational(2, 3).expr() * Symbol::rational(5, 7).expr();
+        assert_eq!(product, Symbol::rational(10, 21).expr());
+
+        let product = Symbol::rational(2, 3).expr() * Symbol::rational(6, 7).expr();
+        assert_eq!(product, Symbol::rational(4, 7).expr());
+
+        let product = Symbol::rational(2, 3).expr() * Symbol::integer(3).expr();
+        assert_eq!(product, Symbol::integer(2).expr());
+    }
+
+    #[test]
+    fn keeps_single_rational_factor() {
+        let x = &Symbol::variable("x").expr();
+        let product = Symbol::rational(1, 2).expr() * x * Symbol::integer(-3).expr();
+
+        if let Expression::Multiplication(m) = product {
+            assert_eq!(m.items().len(), 2);
+            assert!(m.items().contains(&Symbol::rational(-3, 2).expr()));
+            assert!(m.items().contains(x));
+        } else {
+            panic!();
+        }
+    }
 } /* end - constructor tests */
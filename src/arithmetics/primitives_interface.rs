@@ -1,55 +1,37 @@
-use crate::{
-    base::expression::Expression,
-    symbols::{integer::Integer, number::Number, variable::Variable},
-};
+use crate::base::{expression::Expression, symbol::Symbol};
 
 pub trait AsExpression {
-    fn as_expression(self) -> Expression;
+    fn as_expression(&self) -> Expression;
 }
 
 impl AsExpression for f64 {
-    fn as_expression(self) -> Expression {
-        Number::new(self as f64)
+    fn as_expression(&self) -> Expression {
+        Symbol::real(*self).expr()
     }
 }
 
 impl AsExpression for isize {
-    fn as_expression(self) -> Expression {
-        Integer::new(self)
+    fn as_expression(&self) -> Expression {
+        Symbol::integer(*self).expr()
     }
 }
 
-/*
-    TODO:
-        - Implement a proper expression interpreter when parsing a strings
-        - this is a temporary handle: "sin(a^2)" will generate a Variable!
-*/
+/**
+ * Strings are interpreted as infix expressions: "sin(a^2)" builds a Sine
+ *  - panics on malformed input; use `Expression::parse` to handle the error
+ */
 impl AsExpression for String {
-    fn as_expression(self) -> Expression {
-        Variable::new(self)
+    fn as_expression(&self) -> Expression {
+        self.as_str().as_expression()
     }
 }
 
 impl AsExpression for &str {
-    fn as_expression(self) -> Expression {
-        Variable::new(String::from(self))
-    }
-}
-
-// ========================= //
-//          Negation         //
-// ========================= //
-impl std::ops::Neg for Expression {
-    type Output = Expression;
-    fn neg(self) -> Self {
-        Integer::new(-1) * self
-    }
-}
-
-impl std::ops::Neg for &Expression {
-    type Output = Expression;
-    fn neg(self) -> Expression {
-        Integer::new(-1) * self
+    fn as_expression(&self) -> Expression {
+        match Expression::parse(self) {
+            Ok(expression) => expression,
+            Err(error) => panic!("Invalid expression \"{}\": {}", self, error),
+        }
     }
 }
 
@@ -59,112 +41,112 @@ impl std::ops::Neg for &Expression {
 impl std::ops::Add<isize> for Expression {
     type Output = Expression;
     fn add(self, other: isize) -> Expression {
-        self + Integer::new(other)
+        self + Symbol::integer(other).expr()
     }
 }
 
 impl std::ops::Add<Expression> for isize {
     type Output = Expression;
     fn add(self, other: Expression) -> Expression {
-        Integer::new(self) + other
+        Symbol::integer(self).expr() + other
     }
 }
 
 impl std::ops::Add<f64> for Expression {
     type Output = Expression;
     fn add(self, other: f64) -> Expression {
-        self + Number::new(other)
+        self + Symbol::real(other).expr()
     }
 }
 
 impl std::ops::Add<Expression> for f64 {
     type Output = Expression;
     fn add(self, other: Expression) -> Expression {
-        Number::new(self) + other
+        Symbol::real(self).expr() + other
     }
 }
 
 impl std::ops::Add<&str> for Expression {
     type Output = Expression;
     fn add(self, other: &str) -> Expression {
-        self + Variable::new(String::from(other))
+        self + other.as_expression()
     }
 }
 
 impl std::ops::Add<Expression> for &str {
     type Output = Expression;
     fn add(self, other: Expression) -> Expression {
-        Variable::new(String::from(self)) + other
+        self.as_expression() + other
     }
 }
 
 impl std::ops::Add<String> for Expression {
     type Output = Expression;
     fn add(self, other: String) -> Expression {
-        self + Variable::new(other)
+        self + other.as_expression()
     }
 }
 
 impl std::ops::Add<Expression> for String {
     type Output = Expression;
     fn add(self, other: Expression) -> Expression {
-        Variable::new(self) + other
+        self.as_expression() + other
     }
 }
 
 impl std::ops::Add<isize> for &Expression {
     type Output = Expression;
     fn add(self, other: isize) -> Expression {
-        self + Integer::new(other)
+        self + Symbol::integer(other).expr()
     }
 }
 
 impl std::ops::Add<&Expression> for isize {
     type Output = Expression;
     fn add(self, other: &Expression) -> Expression {
-        Integer::new(self) + other
+        Symbol::integer(self).expr() + other
     }
 }
 
 impl std::ops::Add<f64> for &Expression {
     type Output = Expression;
     fn add(self, other: f64) -> Expression {
-        self + Number::new(other)
+        self + Symbol::real(other).expr()
     }
 }
 
 impl std::ops::Add<&Expression> for f64 {
     type Output = Expression;
     fn add(self, other: &Expression) -> Expression {
-        Number::new(self) + other
+        Symbol::real(self).expr() + other
     }
 }
 
 impl std::ops::Add<&str> for &Expression {
     type Output = Expression;
     fn add(self, other: &str) -> Expression {
-        self + Variable::new(String::from(other))
+        self + other.as_expression()
     }
 }
 
 impl std::ops::Add<&Expression> for &str {
     type Output = Expression;
     fn add(self, other: &Expression) -> Expression {
-        Variable::new(String::from(self)) + other
+        self.as_expression() + other
     }
 }
 
 impl std::ops::Add<String> for &Expression {
     type Output = Expression;
     fn add(self, other: String) -> Expression {
-        self + Variable::new(other)
+        self + other.as_expression()
     }
 }
 
 impl std::ops::Add<&Expression> for String {
     type Output = Expression;
     fn add(self, other: &Expression) -> Expression {
-        Variable::new(self) + other
+        self.as_expression() + other
     }
 }
 
@@ -174,112 +156,112 @@ impl std::ops::Add<&Expression> for String {
 impl std::ops::Sub<isize> for Expression {
     type Output = Expression;
     fn sub(self, other: isize) -> Expression {
-        self - Integer::new(other)
+        self - Symbol::integer(other).expr()
     }
 }
 
 impl std::ops::Sub<Expression> for isize {
     type Output = Expression;
     fn sub(self, other: Expression) -> Expression {
-        Integer::new(self) - other
+        Symbol::integer(self).expr() - other
     }
 }
 
 impl std::ops::Sub<f64> for Expression {
     type Output = Expression;
     fn sub(self, other: f64) -> Expression {
-        self - Number::new(other as f64)
+        self - Symbol::real(other).expr()
     }
 }
 
 impl std::ops::Sub<Expression> for f64 {
     type Output = Expression;
     fn sub(self, other: Expression) -> Expression {
-        Number::new(self) - other
+        Symbol::real(self).expr() - other
     }
 }
 
 impl std::ops::Sub<&str> for Expression {
     type Output = Expression;
     fn sub(self, other: &str) -> Expression {
-        self - Variable::new(String::from(other))
+        self - other.as_expression()
     }
 }
 
 impl std::ops::Sub<Expression> for &str {
     type Output = Expression;
     fn sub(self, other: Expression) -> Expression {
-        Variable::new(String::from(self)) - other
+        self.as_expression() - other
     }
 }
 
 impl std::ops::Sub<String> for Expression {
     type Output = Expression;
     fn sub(self, other: String) -> Expression {
-        self - Variable::new(other)
+        self - other.as_expression()
     }
 }
 
 impl std::ops::Sub<Expression> for String {
     type Output = Expression;
     fn sub(self, other: Expression) -> Expression {
-        Variable::new(self) - other
+        self.as_expression() - other
     }
 }
 
 impl std::ops::Sub<isize> for &Expression {
     type Output = Expression;
     fn sub(self, other: isize) -> Expression {
-        self - Integer::new(other)
+        self - Symbol::integer(other).expr()
     }
 }
 
 impl std::ops::Sub<&Expression> for isize {
     type Output = Expression;
     fn sub(self, other: &Expression) -> Expression {
-        Integer::new(self) - other
+        Symbol::integer(self).expr() - other
     }
 }
 
 impl std::ops::Sub<f64> for &Expression {
     type Output = Expression;
     fn sub(self, other: f64) -> Expression {
-        self - Number::new(other as f64)
+        self - Symbol::real(other).expr()
     }
 }
 
 impl std::ops::Sub<&Expression> for f64 {
     type Output = Expression;
     fn sub(self, other: &Expression) -> Expression {
-        Number::new(self) - other
+        Symbol::real(self).expr() - other
     }
 }
 
 impl std::ops::Sub<&str> for &Expression {
     type Output = Expression;
     fn sub(self, other: &str) -> Expression {
-        self - Variable::new(String::from(other))
+        self - other.as_expression()
     }
 }
 
 impl std::ops::Sub<&Expression> for &str {
     type Output = Expression;
     fn sub(self, other: &Expression) -> Expression {
-        Variable::new(String::from(self)) - other
+        self.as_expression() - other
     }
 }
 
 impl std::ops::Sub<String> for &Expression {
     type Output = Expression;
     fn sub(self, other: String) -> Expression {
-        self - Variable::new(other)
+        self - other.as_expression()
     }
 }
 
 impl std::ops::Sub<&Expression> for String {
     type Output = Expression;
     fn sub(self, other: &Expression) -> Expression {
-        Variable::new(self) - other
+        self.as_expression() - other
     }
 }
 
@@ -289,111 +271,111 @@ impl std::ops::Sub<&Expression> for String {
 impl std::ops::Mul<isize> for Expression {
     type Output = Expression;
     fn mul(self, other: isize) -> Expression {
-        self * Integer::new(other)
+        self * Symbol::integer(other).expr()
     }
 }
 
 impl std::ops::Mul<Expression> for isize {
     type Output = Expression;
     fn mul(self, other: Expression) -> Expression {
-        Integer::new(self) * other
+        Symbol::integer(self).expr() * other
     }
 }
 
 impl std::ops::Mul<f64> for Expression {
     type Output = Expression;
     fn mul(self, other: f64) -> Expression {
-        self * Number::new(other)
+        self * Symbol::real(other).expr()
     }
 }
 
 impl std::ops::Mul<Expression> for f64 {
     type Output = Expression;
     fn mul(self, other: Expression) -> Expression {
-        Number::new(self) * other
+        Symbol::real(self).expr() * other
     }
 }
 
 impl std::ops::Mul<&str> for Expression {
     type Output = Expression;
     fn mul(self, other: &str) -> Expression {
-        self * Variable::new(String::from(other))
+        self * other.as_expression()
     }
 }
 
 impl std::ops::Mul<Expression> for &str {
     type Output = Expression;
     fn mul(self, other: Expression) -> Expression {
-        Variable::new(String::from(self)) * other
+        self.as_expression() * other
     }
 }
 
 impl std::ops::Mul<String> for Expression {
     type Output = Expression;
     fn mul(self, other: String) -> Expression {
-        self * Variable::new(other)
+        self * other.as_expression()
     }
 }
 
 impl std::ops::Mul<Expression> for String {
     type Output = Expression;
     fn mul(self, other: Expression) -> Expression {
-        Variable::new(self) * other
+        self.as_expression() * other
     }
 }
 impl std::ops::Mul<isize> for &Expression {
     type Output = Expression;
     fn mul(self, other: isize) -> Expression {
-        self * Integer::new(other)
+        self * Symbol::integer(other).expr()
     }
 }
 
 impl std::ops::Mul<&Expression> for isize {
     type Output = Expression;
     fn mul(self, other: &Expression) -> Expression {
-        Integer::new(self) * other
+        Symbol::integer(self).expr() * other
     }
 }
 
 impl std::ops::Mul<f64> for &Expression {
     type Output = Expression;
     fn mul(self, other: f64) -> Expression {
-        self * Number::new(other)
+        self * Symbol::real(other).expr()
     }
 }
 
 impl std::ops::Mul<&Expression> for f64 {
     type Output = Expression;
     fn mul(self, other: &Expression) -> Expression {
-        Number::new(self) * other
+        Symbol::real(self).expr() * other
     }
 }
 
 impl std::ops::Mul<&str> for &Expression {
     type Output = Expression;
     fn mul(self, other: &str) -> Expression {
-        self * Variable::new(String::from(other))
+        self * other.as_expression()
     }
 }
 
 impl std::ops::Mul<&Expression> for &str {
     type Output = Expression;
     fn mul(self, other: &Expression) -> Expression {
-        Variable::new(String::from(self)) * other
+        self.as_expression() * other
     }
 }
 
 impl std::ops::Mul<String> for &Expression {
     type Output = Expression;
     fn mul(self, other: String) -> Expression {
-        self * Variable::new(other)
+        self * other.as_expression()
     }
 }
 
 impl std::ops::Mul<&Expression> for String {
     type Output = Expression;
     fn mul(self, other: &Expression) -> Expression {
-        Variable::new(self) * other
+        self.as_expression() * other
     }
 }
 
@@ -403,111 +385,111 @@ impl std::ops::Mul<&Expression> for String {
 impl std::ops::Div<isize> for Expression {
     type Output = Expression;
     fn div(self, other: isize) -> Expression {
-        self / Integer::new(other)
+        self / Symbol::integer(other).expr()
     }
 }
 
 impl std::ops::Div<Expression> for isize {
     type Output = Expression;
     fn div(self, other: Expression) -> Expression {
-        Integer::new(self) / other
+        Symbol::integer(self).expr() / other
     }
 }
 
 impl std::ops::Div<f64> for Expression {
     type Output = Expression;
     fn div(self, other: f64) -> Expression {
-        self / Number::new(other)
+        self / Symbol::real(other).expr()
     }
 }
 
 impl std::ops::Div<Expression> for f64 {
     type Output = Expression;
     fn div(self, other: Expression) -> Expression {
-        Number::new(self) / other
+        Symbol::real(self).expr() / other
     }
 }
 
 impl std::ops::Div<&str> for Expression {
     type Output = Expression;
     fn div(self, other: &str) -> Expression {
-        self / Variable::new(String::from(other))
+        self / other.as_expression()
     }
 }
 
 impl std::ops::Div<Expression> for &str {
     type Output = Expression;
     fn div(self, other: Expression) -> Expression {
-        Variable::new(String::from(self)) / other
+        self.as_expression() / other
     }
 }
 
 impl std::ops::Div<String> for Expression {
     type Output = Expression;
     fn div(self, other: String) -> Expression {
-        self / Variable::new(other)
+        self / other.as_expression()
     }
 }
 
 impl std::ops::Div<Expression> for String {
     type Output = Expression;
     fn div(self, other: Expression) -> Expression {
-        Variable::new(self) / other
+        self.as_expression() / other
     }
 }
 
 impl std::ops::Div<isize> for &Expression {
     type Output = Expression;
     fn div(self, other: isize) -> Expression {
-        self / Integer::new(other)
+        self / Symbol::integer(other).expr()
     }
 }
 
 impl std::ops::Div<&Expression> for isize {
     type Output = Expression;
     fn div(self, other: &Expression) -> Expression {
-        Integer::new(self) / other
+        Symbol::integer(self).expr() / other
     }
 }
 
 impl std::ops::Div<f64> for &Expression {
     type Output = Expression;
     fn div(self, other: f64) -> Expression {
-        self / Number::new(other)
+        self / Symbol::real(other).expr()
     }
 }
 
 impl std::ops::Div<&Expression> for f64 {
     type Output = Expression;
     fn div(self, other: &Expression) -> Expression {
-        Number::new(self) / other
+        Symbol::real(self).expr() / other
     }
 }
 
 impl std::ops::Div<&str> for &Expression {
     type Output = Expression;
     fn div(self, other: &str) -> Expression {
-        self / Variable::new(String::from(other))
+        self / other.as_expression()
     }
 }
 
 impl std::ops::Div<&Expression> for &str {
     type Output = Expression;
     fn div(self, other: &Expression) -> Expression {
-        Variable::new(String::from(self)) / other
+        self.as_expression() / other
     }
 }
 
 impl std::ops::Div<String> for &Expression {
     type Output = Expression;
     fn div(self, other: String) -> Expression {
-        self / Variable::new(other)
+        self / other.as_expression()
     }
 }
 
 impl std::ops::Div<&Expression> for String {
     type Output = Expression;
     fn div(self, other: &Expression) -> Expression {
-        Variable::new(self) / other
+        self.as_expression() / other
     }
 }
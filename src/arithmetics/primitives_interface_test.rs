@@ -1,185 +1,119 @@
 #[cfg(test)]
 mod as_expression {
-    use crate::{
-        arithmetics::primitives_interface::AsExpression,
-        symbols::{integer::Integer, number::Number, variable::Variable},
-    };
+    use crate::{arithmetics::primitives_interface::AsExpression, base::symbol::Symbol};
 
     #[test]
     fn sample_1() {
-        assert_eq!(1.as_expression(), Integer::new(1));
-        assert_eq!((-1).as_expression(), Integer::new(-1));
-        assert_eq!(-1.as_expression(), Integer::new(-1) * Number::new(1.0));
-        assert_eq!(1.01.as_expression(), Number::new(1.01));
-        assert_eq!("a".as_expression(), Variable::new(String::from("a")));
+        assert_eq!(1.as_expression(), Symbol::integer(1).expr());
+        assert_eq!((-1).as_expression(), Symbol::integer(-1).expr());
         assert_eq!(
-            String::from("a").as_expression(),
-            Variable::new(String::from("a"))
+            -1.0.as_expression(),
+            Symbol::integer(-1).expr() * Symbol::real(1.0).expr()
         );
-    }
-}
-
-#[cfg(test)]
-mod negation {
-    use crate::arithmetics::multiplication::Multiplication;
-    use crate::{
-        manipulation::numeric_evaluation::NumericEvaluable,
-        symbols::{constant::Constant, integer::Integer, number::Number, variable::Variable},
-    };
-
-    #[test]
-    fn negate_number_sign() {
-        assert_eq!(-Number::new(1.0), Integer::new(-1) * Number::new(1.0));
-    }
-
-    #[test]
-    fn negate_number_sign_numerical_evaluation() {
-        assert_eq!((-Number::new(1.0)).into_num(), Ok(-1.0));
-    }
-
-    #[test]
-    fn negate_variable() {
+        assert_eq!(1.01.as_expression(), Symbol::real(1.01).expr());
+        assert_eq!("a".as_expression(), Symbol::variable("a").expr());
         assert_eq!(
-            -Variable::new(String::from("a")),
-            Multiplication::new(vec![Integer::new(-1), Variable::new(String::from("a"))])
+            String::from("a").as_expression(),
+            Symbol::variable("a").expr()
         );
     }
 
     #[test]
-    fn negate_constant() {
+    fn parses_strings() {
+        let a = &Symbol::variable("a").expr();
+        let two = &Symbol::integer(2).expr();
+
+        assert_eq!("a^2".as_expression(), a.clone().pow(two.clone()));
         assert_eq!(
-            -Constant::new(String::from("one"), 1.0),
-            Integer::new(-1) * Constant::new(String::from("one"), 1.0)
+            "2 * a + 1".as_expression(),
+            two * a + Symbol::integer(1).expr()
         );
     }
 
     #[test]
-    fn negate_constant_numerical_evaluation() {
-        assert_eq!(
-            (-Constant::new(String::from("one"), 1.0)).into_num(),
-            Ok(-1.0)
-        );
+    #[should_panic]
+    fn panics_on_malformed_strings() {
+        "a +".as_expression();
     }
 }
 
 #[cfg(test)]
 mod arithmetics {
-    use crate::arithmetics::{addition::Addition, multiplication::Multiplication};
-    use crate::manipulation::simplifiable::Simplifiable;
-    use crate::symbols::{number::Number, integer::Integer, variable::Variable};
+    use crate::base::{expression::Expression, symbol::Symbol};
 
     #[test]
     fn addition() {
+        let one = || Symbol::real(1.0).expr();
+        let a = || Symbol::variable("a").expr();
+
         /* numeric */
-        assert_eq!(1 + Number::new(1.0), Integer::new(1) + Number::new(1.0));
-        assert_eq!(1.0 + Number::new(1.0), Number::new(1.0) + Number::new(1.0));
-        assert_eq!(Number::new(1.0) + 1, Integer::new(1) + Number::new(1.0));
-        assert_eq!(Number::new(1.0) + 1.0, Number::new(1.0) + Number::new(1.0));
+        assert_eq!(1 + one(), Symbol::integer(1).expr() + one());
+        assert_eq!(1.0 + one(), one() + one());
+        assert_eq!(one() + 1, Symbol::integer(1).expr() + one());
+        assert_eq!(one() + 1.0, one() + one());
 
         /* algebraic */
         assert_eq!(
-            "a" - Integer::new(1),
-            Addition::new(vec![Variable::new(String::from("a")), Integer::new(-1)])
-        );
-        assert_eq!(
-            Variable::new(String::from("a")) - Variable::new(String::from("a")),
-            Addition::new(vec![
-                Variable::new(String::from("a")),
-                Multiplication::new(vec![Integer::new(-1), Variable::new(String::from("a")),])
-            ])
-        );
-        assert_eq!(
-            Number::new(1.0) + "a",
-            Number::new(1.0) + Variable::new(String::from("a"))
-        );
-        assert_eq!(
-            String::from("a") + Number::new(1.0),
-            Variable::new(String::from("a")) + Number::new(1.0)
-        );
-        assert_eq!(
-            Number::new(1.0) + String::from("a"),
-            Number::new(1.0) + Variable::new(String::from("a"))
+            "a" - Symbol::integer(1).expr(),
+            Expression::addition(vec![a(), Symbol::integer(-1).expr()])
         );
+        assert_eq!(one() + "a", one() + a());
+        assert_eq!(String::from("a") + one(), a() + one());
+        assert_eq!(one() + String::from("a"), one() + a());
+        assert_eq!(one() + "a^2", one() + a().pow(Symbol::integer(2).expr()));
     }
 
     #[test]
     fn subtraction() {
+        let one = || Symbol::real(1.0).expr();
+        let a = || Symbol::variable("a").expr();
+
         /* numeric */
-        assert_eq!(1 - Number::new(1.0), Integer::new(1) - Number::new(1.0));
-        assert_eq!(1.0 - Number::new(1.0), Number::new(1.0) - Number::new(1.0));
-        assert_eq!(Number::new(1.0) - 1, Number::new(1.0) + Integer::new(-1));
-        assert_eq!(Number::new(1.0) - 1.0, Number::new(1.0) - Number::new(1.0));
+        assert_eq!(1 - one(), Symbol::integer(1).expr() - one());
+        assert_eq!(1.0 - one(), one() - one());
+        assert_eq!(one() - 1, one() + Symbol::integer(-1).expr());
+        assert_eq!(one() - 1.0, one() - one());
 
         /* algebraic */
-        assert_eq!(
-            "a" - Number::new(1.0),
-            Variable::new(String::from("a")) - Number::new(1.0)
-        );
-        assert_eq!(
-            Number::new(1.0) - "a",
-            Number::new(1.0) - Variable::new(String::from("a"))
-        );
-        assert_eq!(
-            String::from("a") - Number::new(1.0),
-            Variable::new(String::from("a")) - Number::new(1.0)
-        );
-        assert_eq!(
-            Number::new(1.0) - String::from("a"),
-            Number::new(1.0) - Variable::new(String::from("a"))
-        );
+        assert_eq!("a" - one(), a() - one());
+        assert_eq!(one() - "a", one() - a());
+        assert_eq!(String::from("a") - one(), a() - one());
+        assert_eq!(one() - String::from("a"), one() - a());
     }
 
     #[test]
     fn multiplication() {
+        let one = || Symbol::real(1.0).expr();
+        let a = || Symbol::variable("a").expr();
+
         /* numeric */
-        assert_eq!(2 * Number::new(1.0), Integer::new(2) * Number::new(1.0));
-        assert_eq!(2.0 * Number::new(1.0), Number::new(2.0) * Number::new(1.0));
-        assert_eq!(Number::new(1.0) * 2, Number::new(1.0) * Integer::new(2));
-        assert_eq!(Number::new(1.0) * 2.0, Number::new(1.0) * Number::new(2.0));
+        assert_eq!(2 * one(), Symbol::integer(2).expr() * one());
+        assert_eq!(2.0 * one(), Symbol::real(2.0).expr() * one());
+        assert_eq!(one() * 2, one() * Symbol::integer(2).expr());
+        assert_eq!(one() * 2.0, one() * Symbol::real(2.0).expr());
 
         /* algebraic */
-        assert_eq!(
-            "a" * Number::new(1.0),
-            Variable::new(String::from("a")) * Number::new(1.0)
-        );
-        assert_eq!(
-            Number::new(1.0) * "a",
-            Number::new(1.0) * Variable::new(String::from("a"))
-        );
-        assert_eq!(
-            String::from("a") * Number::new(1.0),
-            Variable::new(String::from("a")) * Number::new(1.0)
-        );
-        assert_eq!(
-            Number::new(1.0) * String::from("a"),
-            Number::new(1.0) * Variable::new(String::from("a"))
-        );
+        assert_eq!("a" * one(), a() * one());
+        assert_eq!(one() * "a", one() * a());
+        assert_eq!(String::from("a") * one(), a() * one());
+        assert_eq!(one() * String::from("a"), one() * a());
     }
 
     #[test]
     fn division() {
+        let one = || Symbol::real(1.0).expr();
+        let a = || Symbol::variable("a").expr();
+
         /* numeric */
-        assert_eq!(1 / Number::new(1.0), Integer::new(1) / Number::new(1.0));
-        assert_eq!(1.0 / Number::new(1.0), Number::new(1.0) / Number::new(1.0));
-        assert_eq!(Number::new(1.0) / 1, Number::new(1.0) / Integer::new(1));
-        assert_eq!(Number::new(1.0) / 1.0, Number::new(1.0) / Number::new(1.0));
+        assert_eq!(1 / one(), Symbol::integer(1).expr() / one());
+        assert_eq!(1.0 / one(), one() / one());
+        assert_eq!(one() / 1, one() / Symbol::integer(1).expr());
+        assert_eq!(one() / 1.0, one() / one());
 
         /* algebraic */
-        assert_eq!(
-            "a" / Number::new(1.0),
-            Variable::new(String::from("a")) / Number::new(1.0)
-        );
-        assert_eq!(
-            Number::new(1.0) / "a",
-            Number::new(1.0) / Variable::new(String::from("a"))
-        );
-        assert_eq!(
-            String::from("a") / Number::new(1.0),
-            Variable::new(String::from("a")) / Number::new(1.0)
-        );
-        assert_eq!(
-            Number::new(1.0) / String::from("a"),
-            Number::new(1.0) / Variable::new(String::from("a"))
-        );
+        assert_eq!("a" / one(), a() / one());
+        assert_eq!(one() / "a", one() / a());
+        assert_eq!(String::from("a") / one(), a() / one());
+        assert_eq!(one() / String::from("a"), one() / a());
     }
 }
@@ -104,3 +104,29 @@ impl std::ops::Neg for &Expression {
         Symbol::integer(-1).expr() * self
     }
 }
+
+impl Expression {
+    /**
+     * Separates the sign from an expression
+     *  - negative numbers and products with a negative numeric factor (the `-1 *` factors
+     *    inserted by the constructors) are reported as negative with their magnitude
+     */
+    pub fn split_sign(&self) -> (bool, Expression) {
+        let negative = match self {
+            Expression::Integer(n) => n.is_negative(),
+            Expression::Rational(q) => q.is_negative(),
+            Expression::Real(r) => r.data < 0.0,
+            Expression::Multiplication(factors) => factors
+                .get_rationals()
+                .iter()
+                .any(|factor| factor.split_sign().0),
+            _ => false,
+        };
+
+        if negative {
+            (true, -self)
+        } else {
+            (false, self.clone())
+        }
+    }
+}
@@ -69,44 +69,8 @@ impl CommutativeAssociation {
     }
 
     /**
-     * Get integer numerators
-     *  - only one integer is expected if the addition of multiplication is simplified at construction
-     */
-    pub fn get_rational_numerators(&self) -> Vec<Expression> {
-        self.items()
-            .iter()
-            .cloned()
-            .filter(|factor| match factor {
-                Expression::Integer(_) => true,
-                _ => false,
-            })
-            .collect()
-    }
-
-    /**
-     * Get integer denominators
-     *  - addition may have serveral fractional parts
-     */
-    pub fn get_rational_denominators(&self) -> Vec<Expression> {
-        self.items()
-            .iter()
-            .cloned()
-            .filter(|factor| match factor {
-                Expression::Power(power) => match (power.argument(), power.modifier()) {
-                    (Expression::Integer(_), Expression::Integer(_)) => true,
-                    _ => false,
-                },
-                _ => false,
-            })
-            .map(|factor| match factor {
-                Expression::Power(power) => return power.argument(),
-                _ => panic!(),
-            })
-            .collect()
-    }
-
-    /**
-     * Get integer divided by another integer
+     * Get exact numbers: integers and rationals
+     *  - only one is expected if the association is simplified at construction
      */
     pub fn get_rationals(&self) -> Vec<Expression> {
         self.items()
@@ -114,24 +78,14 @@ impl CommutativeAssociation {
             .cloned()
             .filter(|factor| match factor {
                 Expression::Integer(_) => true,
-                Expression::Power(power) => match (power.argument(), power.modifier()) {
-                    (Expression::Integer(_), Expression::Integer(_)) => true,
-                    _ => false,
-                },
-                Expression::Multiplication(factors) => {
-                    let possible_numerator: Vec<Expression> = factors.get_rational_numerators();
-                    let possible_denominator: Vec<Expression> = factors.get_rational_denominators();
-
-                    return factors.items().len()
-                        == possible_numerator.len() + possible_denominator.len();
-                }
+                Expression::Rational(_) => true,
                 _ => false,
             })
             .collect()
     }
 
     /**
-     * Get all non rational, non integer, non inverted integer
+     * Get all non integer, non rational items
      */
     pub fn get_non_rationals(&self) -> Vec<Expression> {
         self.items()
@@ -139,25 +93,14 @@ impl CommutativeAssociation {
             .cloned()
             .filter(|factor| match factor {
                 Expression::Integer(_) => false,
-                Expression::Power(power) => match (power.argument(), power.modifier()) {
-                    (Expression::Integer(_), Expression::Integer(_)) => false,
-                    _ => true,
-                },
-                Expression::Multiplication(factors) => {
-                    let possible_numerator: Vec<Expression> = factors.get_rational_numerators();
-                    let possible_denominator: Vec<Expression> = factors.get_rational_denominators();
-
-                    return factors.items().len()
-                        != possible_numerator.len() + possible_denominator.len();
-                }
+                Expression::Rational(_) => false,
                 _ => true,
             })
             .collect()
     }
 
     /**
-     * Get integer denominators
-     *  - addition may have serveral fractional parts
+     * Get all non numerical items
      */
     pub fn get_symbolics(&self) -> Vec<Expression> {
         self.items()
@@ -165,11 +108,8 @@ impl CommutativeAssociation {
             .cloned()
             .filter(|factor| match factor {
                 Expression::Integer(_) => false,
+                Expression::Rational(_) => false,
                 Expression::Real(_) => false,
-                Expression::Power(power) => match (power.argument(), power.modifier()) {
-                    (Expression::Integer(_), Expression::Integer(_)) => false,
-                    _ => true,
-                },
                 _ => true,
             })
             .collect()
@@ -198,52 +138,6 @@ mod getters {
         assert!(items.get_reals().contains(&Symbol::real(3.0).expr()));
     }
 
-    #[test]
-    fn numerators() {
-        let items = CommutativeAssociation::new(vec![
-            Symbol::integer(1).expr(),
-            Symbol::integer(2).expr(),
-            Symbol::integer(3).expr(),
-            Symbol::real(1.0).expr(),
-            Symbol::real(2.0).expr(),
-            Symbol::real(3.0).expr(),
-        ]);
-
-        assert_eq!(items.get_rational_numerators().len(), 3);
-        assert!(items
-            .get_rational_numerators()
-            .contains(&Symbol::integer(1).expr()));
-        assert!(items
-            .get_rational_numerators()
-            .contains(&Symbol::integer(2).expr()));
-        assert!(items
-            .get_rational_numerators()
-            .contains(&Symbol::integer(3).expr()));
-    }
-
-    #[test]
-    fn denominators() {
-        let items = CommutativeAssociation::new(vec![
-            Symbol::integer(1).expr(),
-            Symbol::integer(2).expr(),
-            Symbol::integer(3).expr(),
-            Expression::power(Symbol::integer(5).expr(), Symbol::integer(-1).expr()),
-            Expression::power(Symbol::integer(2).expr(), Symbol::integer(-1).expr()),
-            Expression::power(Symbol::integer(3).expr(), Symbol::integer(-1).expr()),
-        ]);
-
-        assert_eq!(items.get_rational_denominators().len(), 3);
-        assert!(items
-            .get_rational_denominators()
-            .contains(&Symbol::integer(2).expr()));
-        assert!(items
-            .get_rational_denominators()
-            .contains(&Symbol::integer(3).expr()));
-        assert!(items
-            .get_rational_denominators()
-            .contains(&Symbol::integer(5).expr()));
-    }
-
     #[test]
     fn rationals() {
         let items = CommutativeAssociation::new(vec![
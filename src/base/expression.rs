@@ -1,4 +1,5 @@
 use num::bigint::BigInt;
+use num::rational::BigRational;
 use std::fmt::{Debug, Display};
 use std::hash::Hash;
 
@@ -8,6 +9,7 @@ use crate::base::{
 };
 
 use crate::base::symbol::Symbol;
+use crate::symbols::constant::Constant;
 
 /**
  * Symbols related through composition of Associations, Operations and AssociativeOperations
@@ -18,6 +20,8 @@ pub enum Expression {
     Variable(Symbol<String>),
     Real(Symbol<f64>),
     Integer(Symbol<BigInt>),
+    Rational(Symbol<BigRational>),
+    Constant(Symbol<Constant>),
 
     /* Commutative associations */
     Multiplication(CommutativeAssociation),
@@ -30,13 +34,34 @@ pub enum Expression {
     /* Single Operations */
     Sine(Operation),
     Cossine(Operation),
+    Tangent(Operation),
+    Cotangent(Operation),
+    Secant(Operation),
+    Cossecant(Operation),
+    ArcSine(Operation),
+    ArcCossine(Operation),
+    ArcTangent(Operation),
+    ArcCotangent(Operation),
+    ArcSecant(Operation),
+    ArcCossecant(Operation),
+    RealPart(Operation),
+    ImaginaryPart(Operation),
+    Conjugate(Operation),
+    Absolute(Operation),
+    Argument(Operation),
 }
 
 /**
  * Display implementation for Expression
+ *  - infix notation that parses back into the same expression
+ *  - the alternate form `{:#}` prints the explicit tree
  */
 impl Display for Expression {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if !f.alternate() {
+            return write!(f, "{}", self.to_infix());
+        }
+
         match self {
             /* Symbols */
             Expression::Integer(n) => {
@@ -45,24 +70,83 @@ impl Display for Expression {
             Expression::Real(r) => {
                 return write!(f, "{}", r.label());
             }
+            Expression::Rational(q) => {
+                return write!(f, "{}", q.label());
+            }
+            Expression::Constant(c) => {
+                return write!(f, "{}", c.label());
+            }
             Expression::Variable(v) => {
                 return write!(f, "{}", v.label());
             }
 
             /* Exponential */
+            Expression::Power(p) if p.argument() == Symbol::e().expr() => {
+                return write!(f, "exp({:#})", p.modifier());
+            }
             Expression::Power(p) => {
-                return write!(f, "pow({}, {})", p.argument(), p.modifier());
+                return write!(f, "pow({:#}, {:#})", p.argument(), p.modifier());
+            }
+            Expression::Logarithm(l) if l.modifier() == Symbol::e().expr() => {
+                return write!(f, "ln({:#})", l.argument());
             }
             Expression::Logarithm(l) => {
-                return write!(f, "log({}, {})", l.argument(), l.modifier());
+                return write!(f, "log({:#}, {:#})", l.argument(), l.modifier());
             }
 
             /* Trigonometrics */
             Expression::Sine(p) => {
-                return write!(f, "sin({})", p.argument());
+                return write!(f, "sin({:#})", p.argument());
             }
             Expression::Cossine(l) => {
-                return write!(f, "cos({})", l.argument());
+                return write!(f, "cos({:#})", l.argument());
+            }
+            Expression::Tangent(t) => {
+                return write!(f, "tan({:#})", t.argument());
+            }
+            Expression::Cotangent(t) => {
+                return write!(f, "cot({:#})", t.argument());
+            }
+            Expression::Secant(s) => {
+                return write!(f, "sec({:#})", s.argument());
+            }
+            Expression::Cossecant(s) => {
+                return write!(f, "csc({:#})", s.argument());
+            }
+            Expression::ArcSine(a) => {
+                return write!(f, "asin({:#})", a.argument());
+            }
+            Expression::ArcCossine(a) => {
+                return write!(f, "acos({:#})", a.argument());
+            }
+            Expression::ArcTangent(a) => {
+                return write!(f, "atan({:#})", a.argument());
+            }
+            Expression::ArcCotangent(a) => {
+                return write!(f, "acot({:#})", a.argument());
+            }
+            Expression::ArcSecant(a) => {
+                return write!(f, "asec({:#})", a.argument());
+            }
+            Expression::ArcCossecant(a) => {
+                return write!(f, "acsc({:#})", a.argument());
+            }
+
+            /* Complex */
+            Expression::RealPart(c) => {
+                return write!(f, "re({:#})", c.argument());
+            }
+            Expression::ImaginaryPart(c) => {
+                return write!(f, "im({:#})", c.argument());
+            }
+            Expression::Conjugate(c) => {
+                return write!(f, "conj({:#})", c.argument());
+            }
+            Expression::Absolute(c) => {
+                return write!(f, "abs({:#})", c.argument());
+            }
+            Expression::Argument(c) => {
+                return write!(f, "arg({:#})", c.argument());
             }
 
             /* Commutative associations */
@@ -73,10 +157,10 @@ impl Display for Expression {
                 let addition_items = addition.items();
                 let mut iterator = addition_items.iter();
                 if let Some(first_item) = iterator.next() {
-                    write!(f, "({}", first_item).expect("");
+                    write!(f, "({:#}", first_item).expect("");
                 }
                 while let Some(item) = iterator.next() {
-                    write!(f, " + {}", item).expect("");
+                    write!(f, " + {:#}", item).expect("");
                 }
                 write!(f, ")")
             }
@@ -87,13 +171,60 @@ impl Display for Expression {
                 let multiplication_items = multiplication.items();
                 let mut iterator = multiplication_items.iter();
                 if let Some(first_item) = iterator.next() {
-                    write!(f, "({}", first_item).expect("");
+                    write!(f, "({:#}", first_item).expect("");
                 }
                 while let Some(item) = iterator.next() {
-                    write!(f, " * {}", item).expect("");
+                    write!(f, " * {:#}", item).expect("");
                 }
                 write!(f, ")")
             }
         }
     }
 }
+
+impl Expression {
+    /**
+     * Rebuilds the node from its transformed children
+     *  - uses the normalising constructors, so the result is simplified at construction
+     *  - symbols have no children and are returned as they are
+     */
+    pub fn map_children(&self, f: &dyn Fn(&Expression) -> Expression) -> Expression {
+        match self {
+            Expression::Variable(_)
+            | Expression::Real(_)
+            | Expression::Integer(_)
+            | Expression::Rational(_)
+            | Expression::Constant(_) => self.clone(),
+
+            Expression::Multiplication(factors) => Expression::multiplication(factors.map(f)),
+            Expression::Addition(addends) => Expression::addition(addends.map(f)),
+
+            Expression::Power(power) => {
+                Expression::power(f(&power.argument()), f(&power.modifier()))
+            }
+            Expression::Logarithm(log) => {
+                Expression::logarithm(f(&log.argument()), f(&log.modifier()))
+            }
+
+            Expression::Sine(sine) => Expression::sin(f(&sine.argument())),
+            Expression::Cossine(cossine) => Expression::cos(f(&cossine.argument())),
+            Expression::Tangent(tangent) => Expression::tan(f(&tangent.argument())),
+            Expression::Cotangent(cotangent) => Expression::cot(f(&cotangent.argument())),
+            Expression::Secant(secant) => Expression::sec(f(&secant.argument())),
+            Expression::Cossecant(cossecant) => Expression::csc(f(&cossecant.argument())),
+
+            Expression::ArcSine(arc) => Expression::asin(f(&arc.argument())),
+            Expression::ArcCossine(arc) => Expression::acos(f(&arc.argument())),
+            Expression::ArcTangent(arc) => Expression::atan(f(&arc.argument())),
+            Expression::ArcCotangent(arc) => Expression::acot(f(&arc.argument())),
+            Expression::ArcSecant(arc) => Expression::asec(f(&arc.argument())),
+            Expression::ArcCossecant(arc) => Expression::acsc(f(&arc.argument())),
+
+            Expression::RealPart(part) => Expression::re(f(&part.argument())),
+            Expression::ImaginaryPart(part) => Expression::im(f(&part.argument())),
+            Expression::Conjugate(conjugate) => Expression::conjugate(f(&conjugate.argument())),
+            Expression::Absolute(absolute) => Expression::abs(f(&absolute.argument())),
+            Expression::Argument(argument) => Expression::arg(f(&argument.argument())),
+        }
+    }
+}
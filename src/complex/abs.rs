@@ -0,0 +1,45 @@
+use crate::base::{expression::Expression, operation::Operation, symbol::Symbol};
+use crate::complex::rectangular::known_sign;
+use std::cmp::Ordering;
+
+impl Expression {
+    /**
+     * Modulus: abs(a + bi) == sqrt(a^2 + b^2)
+     *  - real values of known sign are folded, real products split by factor: abs(-2x) == 2 abs(x)
+     */
+    pub fn abs(value: Expression) -> Expression {
+        let (real, imaginary) = match value.rectangular() {
+            Some(parts) => parts,
+            None => return Expression::Absolute(Operation::new(value)),
+        };
+
+        if imaginary != Symbol::integer(0).expr() {
+            let square = |part: Expression| Expression::power(part, Symbol::integer(2).expr());
+            return Expression::power(
+                square(real) + square(imaginary),
+                Symbol::rational(1, 2).expr(),
+            );
+        }
+
+        if let Some(sign) = known_sign(&real) {
+            return if sign == Ordering::Less { -real } else { real };
+        }
+        match real.split_sign().1 {
+            /* abs(a * b) == abs(a) * abs(b) */
+            Expression::Multiplication(factors) => {
+                Expression::multiplication(factors.map(&|factor| Expression::abs(factor.clone())))
+            }
+            /* abs(a ^ 2n) == a ^ 2n */
+            Expression::Power(power) if is_even(&power.modifier()) => Expression::Power(power),
+            magnitude @ Expression::Absolute(_) => magnitude,
+            magnitude => Expression::Absolute(Operation::new(magnitude)),
+        }
+    }
+}
+
+fn is_even(exponent: &Expression) -> bool {
+    match exponent {
+        Expression::Integer(n) => num::Integer::is_even(&n.data),
+        _ => false,
+    }
+}
@@ -0,0 +1,51 @@
+use crate::base::{expression::Expression, operation::Operation, symbol::Symbol};
+use crate::complex::rectangular::known_sign;
+use crate::trigonometrics::trigonometric::pi_coefficient;
+use std::cmp::Ordering;
+
+impl Expression {
+    /**
+     * Principal argument, the angle of a + bi in (-pi, pi]
+     *  - evaluated when the sign of the real part is known, and of the imaginary part if needed
+     */
+    pub fn arg(value: Expression) -> Expression {
+        let node = || Expression::Argument(Operation::new(value.clone()));
+        let (real, imaginary) = match value.rectangular() {
+            Some(parts) => parts,
+            None => return node(),
+        };
+
+        let quadrant = |turns: isize| {
+            let angle = Expression::atan(imaginary.clone() / real.clone());
+            shifted(angle, turns)
+        };
+
+        match (known_sign(&real), known_sign(&imaginary)) {
+            (Some(Ordering::Greater), _) => quadrant(0),
+            (Some(Ordering::Less), Some(Ordering::Less)) => quadrant(-1),
+            (Some(Ordering::Less), Some(_)) => quadrant(1),
+            (Some(Ordering::Equal), Some(Ordering::Greater)) => {
+                Symbol::rational(1, 2).expr() * Symbol::pi().expr()
+            }
+            (Some(Ordering::Equal), Some(Ordering::Less)) => {
+                Symbol::rational(-1, 2).expr() * Symbol::pi().expr()
+            }
+            _ => node(),
+        }
+    }
+}
+
+/**
+ * Angle plus half turns, exact on rational multiples of pi
+ */
+fn shifted(angle: Expression, turns: isize) -> Expression {
+    if turns == 0 {
+        return angle;
+    }
+    match pi_coefficient(&angle) {
+        (rest, coefficient) if rest == Symbol::integer(0).expr() => {
+            (coefficient + Symbol::rational(turns, 1)).expr() * Symbol::pi().expr()
+        }
+        _ => angle + Symbol::integer(turns).expr() * Symbol::pi().expr(),
+    }
+}
@@ -0,0 +1,221 @@
+#[cfg(test)]
+mod gaussian_folding {
+    use crate::base::{expression::Expression, symbol::Symbol};
+
+    #[test]
+    fn folds_imaginary_unit_powers() {
+        let i = Symbol::i().expr();
+        let x = Symbol::variable("x").expr();
+        assert_eq!(i.clone() * i.clone(), Symbol::integer(-1).expr());
+        assert_eq!(
+            Expression::multiplication(vec![i.clone(), x.clone(), i.clone(), i.clone()]),
+            -(i.clone() * x)
+        );
+        assert_eq!(
+            Expression::multiplication(vec![i.clone(), i.clone(), i.clone(), i]),
+            Symbol::integer(1).expr()
+        );
+    }
+
+    #[test]
+    fn folds_imaginary_unit_squares_with_reals() {
+        let i = Symbol::i().expr();
+        assert_eq!(
+            Expression::multiplication(vec![
+                Symbol::integer(3).expr(),
+                i.clone(),
+                Symbol::real(2.0).expr(),
+                i
+            ]),
+            Expression::multiplication(vec![Symbol::integer(-3).expr(), Symbol::real(2.0).expr()])
+        );
+    }
+
+    #[test]
+    fn folds_imaginary_multiples() {
+        assert_eq!(
+            Expression::parse("2*i + 3*i - x").unwrap(),
+            Expression::parse("5*i - x").unwrap()
+        );
+        assert_eq!(Expression::parse("i/2 + i/2").unwrap(), Symbol::i().expr());
+    }
+
+    #[test]
+    fn folds_gaussian_products() {
+        let gaussian = |a: isize, b: isize| {
+            Expression::addition(vec![
+                Symbol::integer(a).expr(),
+                Symbol::integer(b).expr() * Symbol::i().expr(),
+            ])
+        };
+        let x = Symbol::variable("x").expr();
+        assert_eq!(gaussian(1, 1) * gaussian(1, -1), Symbol::integer(2).expr());
+        assert_eq!(gaussian(1, 2) * gaussian(3, -1), gaussian(5, 5));
+        assert_eq!(Symbol::i().expr() * gaussian(1, 1), gaussian(-1, 1));
+        assert_eq!(Symbol::integer(2).expr() * gaussian(1, 1), gaussian(2, 2));
+        assert_eq!(
+            Expression::multiplication(vec![gaussian(1, 1), x.clone(), gaussian(1, -1)]),
+            Symbol::integer(2).expr() * x.clone()
+        );
+        assert_eq!(
+            Symbol::integer(2).expr() * gaussian(1, 1) * x.clone(),
+            Symbol::integer(2).expr() * (gaussian(1, 1) * x.clone())
+        );
+        assert_eq!(-(gaussian(1, 1) * x.clone()), gaussian(-1, -1) * x);
+    }
+
+    #[test]
+    fn folds_gaussian_integer_powers() {
+        let power = |input: &str, exponent: isize| {
+            Expression::power(
+                Expression::parse(input).unwrap(),
+                Symbol::integer(exponent).expr(),
+            )
+        };
+        let parse = |input: &str| Expression::parse(input).unwrap();
+        assert_eq!(power("1 + i", 2), parse("2*i"));
+        assert_eq!(power("1 + i", 4), parse("-4"));
+        assert_eq!(power("1/2 - i", 3), parse("-11/8 + i/4"));
+        assert_eq!(power("1 + i", -1), parse("1/2 - i/2"));
+        assert_eq!(power("1 + i", -2), parse("-i/2"));
+        assert_eq!(parse("(3 + 4*i) * (3 + 4*i)^-1"), parse("1"));
+    }
+
+    #[test]
+    fn takes_principal_roots_of_negative_reals() {
+        let power = |base: isize, exponent: f64| {
+            Expression::power(Symbol::integer(base).expr(), Symbol::real(exponent).expr())
+        };
+        assert_eq!(power(-1, 0.5), Symbol::i().expr());
+        assert_eq!(
+            power(-4, 1.5),
+            Symbol::real(-8.0).expr() * Symbol::i().expr()
+        );
+    }
+}
+
+#[cfg(test)]
+mod parts {
+    use crate::parsing::parser::parse;
+
+    #[test]
+    fn separates_gaussian_numbers() {
+        assert_eq!(parse("re(3 + 4*i)"), parse("3"));
+        assert_eq!(parse("im(3 + 4*i)"), parse("4"));
+        assert_eq!(parse("re(1 / (1 + i))"), parse("1/2"));
+        assert_eq!(parse("im(1 / (1 + i))"), parse("-1/2"));
+        assert_eq!(parse("conj(2 - i)"), parse("2 + i"));
+    }
+
+    #[test]
+    fn assumes_real_variables() {
+        assert_eq!(parse("re(x + i*y)"), parse("x"));
+        assert_eq!(parse("im(x + i*y)"), parse("y"));
+        assert_eq!(parse("im((x + i*y)^2)").simplify(), parse("2*x*y"));
+        assert_eq!(parse("conj(x + i*y)"), parse("x - i*y"));
+        assert_eq!(parse("conj(sin(x))"), parse("sin(x)"));
+    }
+
+    #[test]
+    fn separates_known_complex_values() {
+        assert_eq!(parse("re(exp(i*x))"), parse("cos(x)"));
+        assert_eq!(parse("im(exp(2 + i*x))"), parse("exp(2) * sin(x)"));
+        assert_eq!(parse("im(ln(-1))"), parse("pi"));
+        assert_eq!(parse("im((-4)^(1/2))"), parse("2"));
+    }
+
+    #[test]
+    fn keeps_unknown_parts() {
+        assert_eq!(format!("{}", parse("re(sin(i))")), "re(sin(i))");
+        assert_eq!(parse("conj(conj(sin(i)))"), parse("sin(i)"));
+        assert_eq!(parse("re(re(sin(i)))"), parse("re(sin(i))"));
+    }
+}
+
+#[cfg(test)]
+mod polar {
+    use crate::parsing::parser::parse;
+
+    #[test]
+    fn computes_modulus() {
+        assert_eq!(parse("abs(3 + 4*i)"), parse("5"));
+        assert_eq!(parse("abs(-pi)"), parse("pi"));
+        assert_eq!(parse("abs(x + i*y)"), parse("(x^2 + y^2)^(1/2)"));
+        assert_eq!(parse("abs(exp(i*x))").simplify(), parse("1"));
+    }
+
+    #[test]
+    fn keeps_modulus_of_unknown_sign() {
+        assert_eq!(parse("abs(-2*x)"), parse("2 * abs(x)"));
+        assert_eq!(parse("abs(abs(x))"), parse("abs(x)"));
+        assert_eq!(parse("abs(x^2)"), parse("x^2"));
+    }
+
+    #[test]
+    fn computes_principal_argument() {
+        assert_eq!(parse("arg(1 + i)"), parse("pi/4"));
+        assert_eq!(parse("arg(-1 + i)"), parse("3*pi/4"));
+        assert_eq!(parse("arg(-1 - i)"), parse("-3*pi/4"));
+        assert_eq!(parse("arg(-1)"), parse("pi"));
+        assert_eq!(parse("arg(-2*i)"), parse("-pi/2"));
+        assert_eq!(parse("arg(2 + i*x)"), parse("atan(x/2)"));
+        assert_eq!(format!("{}", parse("arg(x)")), "arg(x)");
+    }
+}
+
+#[cfg(test)]
+mod evaluation {
+    use crate::base::expression::Expression;
+    use num::complex::Complex;
+    use std::collections::HashMap;
+
+    fn evaluate(input: &str) -> Complex<f64> {
+        let env: HashMap<String, f64> = [(String::from("x"), 0.5)].iter().cloned().collect();
+        Expression::parse(input)
+            .unwrap()
+            .evaluate_complex(&env)
+            .unwrap()
+    }
+
+    fn close(left: Complex<f64>, right: Complex<f64>) -> bool {
+        (left - right).norm() < 1e-12
+    }
+
+    #[test]
+    fn evaluates_complex_values() {
+        assert_eq!(evaluate("2 + 3*i"), Complex::new(2.0, 3.0));
+        assert!(close(evaluate("exp(i*pi)"), Complex::new(-1.0, 0.0)));
+        assert!(close(
+            evaluate("ln(-x)"),
+            Complex::new(0.5f64.ln(), std::f64::consts::PI)
+        ));
+        assert!(close(evaluate("(-x)^x"), Complex::new(0.0, 0.5f64.sqrt())));
+        assert!(close(
+            evaluate("sin(i*x)"),
+            Complex::new(0.0, 0.5f64.sinh())
+        ));
+    }
+
+    #[test]
+    fn evaluates_complex_functions() {
+        assert!(close(
+            evaluate("abs(x + sin(i))"),
+            Complex::new((0.25 + 1f64.sinh().powi(2)).sqrt(), 0.0)
+        ));
+        assert!(close(
+            evaluate("conj(cos(i + x))"),
+            Complex::new(0.5, 1.0).cos().conj()
+        ));
+        assert!(close(
+            evaluate("arg(sin(i))"),
+            Complex::new(std::f64::consts::FRAC_PI_2, 0.0)
+        ));
+    }
+
+    #[test]
+    fn evaluates_real_parts_as_real() {
+        let env: HashMap<String, f64> = HashMap::new();
+        let trial = Expression::parse("re(sin(i)) + im(sin(i))").unwrap();
+        assert!((trial.evaluate(&env).unwrap() - 1f64.sinh()).abs() < 1e-12);
+    }
+}
@@ -0,0 +1,17 @@
+use crate::base::{expression::Expression, operation::Operation, symbol::Symbol};
+
+impl Expression {
+    /**
+     * Complex conjugate: conj(a + bi) == a - bi
+     *  - real values and double conjugates are folded
+     */
+    pub fn conjugate(value: Expression) -> Expression {
+        if let Expression::Conjugate(conjugate) = &value {
+            return conjugate.argument();
+        }
+        match value.rectangular() {
+            Some((real, imaginary)) => real - imaginary * Symbol::i().expr(),
+            None => Expression::Conjugate(Operation::new(value)),
+        }
+    }
+}
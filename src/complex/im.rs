@@ -0,0 +1,13 @@
+use crate::base::{expression::Expression, operation::Operation};
+
+impl Expression {
+    /**
+     * Imaginary part, evaluated when the rectangular form is known
+     */
+    pub fn im(value: Expression) -> Expression {
+        match value.rectangular() {
+            Some((_, imaginary)) => imaginary,
+            None => Expression::ImaginaryPart(Operation::new(value)),
+        }
+    }
+}
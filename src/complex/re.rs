@@ -0,0 +1,13 @@
+use crate::base::{expression::Expression, operation::Operation};
+
+impl Expression {
+    /**
+     * Real part, evaluated when the rectangular form is known
+     */
+    pub fn re(value: Expression) -> Expression {
+        match value.rectangular() {
+            Some((real, _)) => real,
+            None => Expression::RealPart(Operation::new(value)),
+        }
+    }
+}
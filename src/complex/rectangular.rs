@@ -0,0 +1,178 @@
+use crate::base::{expression::Expression, symbol::Symbol};
+use std::cmp::Ordering;
+use std::collections::HashMap;
+
+/**
+ * Realness assumptions for the complex functions
+ *  - variables are real, and so are real functions of real arguments
+ *  - the imaginary unit, powers of negative numbers to fractional exponents and
+ *    logarithms of negative numbers are the known complex values
+ */
+impl Expression {
+    /**
+     * Whether the expression is real under the realness assumptions
+     */
+    pub fn is_real(&self) -> bool {
+        match self {
+            Expression::Constant(constant) => constant != &Symbol::i(),
+            Expression::Variable(_)
+            | Expression::Integer(_)
+            | Expression::Rational(_)
+            | Expression::Real(_) => true,
+
+            Expression::Addition(items) | Expression::Multiplication(items) => {
+                items.items().iter().all(Expression::is_real)
+            }
+            Expression::Power(power) => {
+                let (base, exponent) = (power.argument(), power.modifier());
+                let negative_root =
+                    is_negative_number(&base) && !matches!(exponent, Expression::Integer(_));
+                base.is_real() && exponent.is_real() && !negative_root
+            }
+            Expression::Logarithm(log) => {
+                log.argument().is_real()
+                    && log.modifier().is_real()
+                    && !is_negative_number(&log.argument())
+            }
+
+            Expression::RealPart(_)
+            | Expression::ImaginaryPart(_)
+            | Expression::Absolute(_)
+            | Expression::Argument(_) => true,
+
+            Expression::Conjugate(op)
+            | Expression::Sine(op)
+            | Expression::Cossine(op)
+            | Expression::Tangent(op)
+            | Expression::Cotangent(op)
+            | Expression::Secant(op)
+            | Expression::Cossecant(op)
+            | Expression::ArcSine(op)
+            | Expression::ArcCossine(op)
+            | Expression::ArcTangent(op)
+            | Expression::ArcCotangent(op)
+            | Expression::ArcSecant(op)
+            | Expression::ArcCossecant(op) => op.argument().is_real(),
+        }
+    }
+
+    /**
+     * Real and imaginary parts, as (a, b) in a + b * i
+     *  - `None` when the parts can not be separated symbolically
+     */
+    pub fn rectangular(&self) -> Option<(Expression, Expression)> {
+        if self.is_real() {
+            return Some((self.clone(), Symbol::integer(0).expr()));
+        }
+
+        match self {
+            Expression::Constant(_) => Some((Symbol::integer(0).expr(), Symbol::integer(1).expr())),
+            Expression::Addition(addends) => {
+                let mut real: Vec<Expression> = Vec::new();
+                let mut imaginary: Vec<Expression> = Vec::new();
+                for addend in addends.items().iter() {
+                    let (a, b) = addend.rectangular()?;
+                    real.push(a);
+                    imaginary.push(b);
+                }
+                Some((Expression::addition(real), Expression::addition(imaginary)))
+            }
+            Expression::Multiplication(factors) => factors.items().iter().try_fold(
+                (Symbol::integer(1).expr(), Symbol::integer(0).expr()),
+                |product, factor| Some(multiply(&product, &factor.rectangular()?)),
+            ),
+            Expression::Power(power) => {
+                let (base, exponent) = (power.argument(), power.modifier());
+                match &exponent {
+                    /* (a + bi) ^ n by repeated products */
+                    Expression::Integer(n) => {
+                        let parts = base.rectangular()?;
+                        let count = n.value()?;
+                        let power = (0..count.abs()).fold(
+                            (Symbol::integer(1).expr(), Symbol::integer(0).expr()),
+                            |product, _| multiply(&product, &parts),
+                        );
+                        if count < 0 {
+                            Some(reciprocal(&power))
+                        } else {
+                            Some(power)
+                        }
+                    }
+                    /* e ^ (x + iy) == e ^ x * (cos(y) + i sin(y)) */
+                    _ if base == Symbol::e().expr() => {
+                        let (x, y) = exponent.rectangular()?;
+                        let magnitude = Expression::exp(x);
+                        Some((
+                            magnitude.clone() * Expression::cos(y.clone()),
+                            magnitude * Expression::sin(y),
+                        ))
+                    }
+                    /* (-a) ^ q == a ^ q * (cos(q pi) + i sin(q pi)) */
+                    Expression::Rational(_) if is_negative_number(&base) => {
+                        let magnitude = Expression::power(-base, exponent.clone());
+                        let angle = exponent * Symbol::pi().expr();
+                        Some((
+                            magnitude.clone() * Expression::cos(angle.clone()),
+                            magnitude * Expression::sin(angle),
+                        ))
+                    }
+                    _ => None,
+                }
+            }
+            /* log(-a, b) == log(a, b) + i pi / ln(b) */
+            Expression::Logarithm(log) if is_negative_number(&log.argument()) => {
+                let base = log.modifier();
+                Some((
+                    Expression::logarithm(-log.argument(), base.clone()),
+                    Symbol::pi().expr() / Expression::ln(base),
+                ))
+            }
+            Expression::Conjugate(conjugate) => {
+                let (a, b) = conjugate.argument().rectangular()?;
+                Some((a, -b))
+            }
+            _ => None,
+        }
+    }
+}
+
+/**
+ * Sign of an expression free of variables, `None` when it can not be evaluated
+ */
+pub fn known_sign(value: &Expression) -> Option<Ordering> {
+    if value == &Symbol::integer(0).expr() {
+        return Some(Ordering::Equal);
+    }
+    value
+        .evaluate(&HashMap::new())
+        .ok()
+        .and_then(|number| number.partial_cmp(&0.0))
+}
+
+fn is_negative_number(value: &Expression) -> bool {
+    match value {
+        Expression::Integer(_) | Expression::Rational(_) | Expression::Real(_) => {
+            value.split_sign().0
+        }
+        _ => false,
+    }
+}
+
+/**
+ * (a + bi) * (c + di) == (ac - bd) + (ad + bc) i
+ */
+fn multiply(
+    (a, b): &(Expression, Expression),
+    (c, d): &(Expression, Expression),
+) -> (Expression, Expression) {
+    (a * c - b * d, a * d + b * c)
+}
+
+/**
+ * 1 / (a + bi) == (a - bi) / (a^2 + b^2)
+ */
+fn reciprocal((a, b): &(Expression, Expression)) -> (Expression, Expression) {
+    let square = |value: &Expression| Expression::power(value.clone(), Symbol::integer(2).expr());
+    let modulus = square(a) + square(b);
+    (a / &modulus, -(b / &modulus))
+}
@@ -1 +1,10 @@
+use crate::base::{expression::Expression, symbol::Symbol};
 
+impl Expression {
+    /**
+     * Natural exponential, power with the e constant as base
+     */
+    pub fn exp(exponent: Expression) -> Expression {
+        Expression::power(Symbol::e().expr(), exponent)
+    }
+}
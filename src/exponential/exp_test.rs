@@ -0,0 +1,65 @@
+#[cfg(test)]
+mod constructor {
+    use crate::base::{expression::Expression, symbol::Symbol};
+
+    #[test]
+    fn builds_on_e_constant() {
+        let x = Symbol::variable("x").expr();
+
+        assert_eq!(
+            Expression::exp(x.clone()),
+            Symbol::e().expr().pow(x.clone())
+        );
+        assert_eq!(
+            Expression::ln(x.clone()),
+            Expression::logarithm(x, Symbol::e().expr())
+        );
+    }
+
+    #[test]
+    fn evaluates_identities() {
+        assert_eq!(
+            Expression::exp(Symbol::integer(0).expr()),
+            Symbol::integer(1).expr()
+        );
+        assert_eq!(
+            Expression::exp(Symbol::integer(1).expr()),
+            Symbol::e().expr()
+        );
+        assert_eq!(
+            Expression::ln(Symbol::e().expr()),
+            Symbol::integer(1).expr()
+        );
+    }
+
+    #[test]
+    fn inverts_each_other() {
+        let x = Symbol::variable("x").expr();
+
+        assert_eq!(Expression::ln(Expression::exp(x.clone())), x);
+        assert_eq!(Expression::exp(Expression::ln(x.clone())), x);
+    }
+}
+
+#[cfg(test)]
+mod simplify {
+    use crate::base::{expression::Expression, symbol::Symbol};
+
+    #[test]
+    fn multiplies_exponentials() {
+        let a = Symbol::variable("a").expr();
+        let b = Symbol::variable("b").expr();
+        let product = Expression::exp(a.clone()) * Expression::exp(b.clone());
+
+        assert_eq!(product.simplify(), Expression::exp(a + b));
+    }
+
+    #[test]
+    fn divides_exponentials() {
+        let x = Symbol::variable("x").expr();
+        let quotient =
+            Expression::exp(Symbol::integer(2).expr() * x.clone()) / Expression::exp(x.clone());
+
+        assert_eq!(quotient.simplify(), Expression::exp(x));
+    }
+}
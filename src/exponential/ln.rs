@@ -0,0 +1,10 @@
+use crate::base::{expression::Expression, symbol::Symbol};
+
+impl Expression {
+    /**
+     * Natural logarithm, logarithm with the e constant as base
+     */
+    pub fn ln(argument: Expression) -> Expression {
+        Expression::logarithm(argument, Symbol::e().expr())
+    }
+}
@@ -31,6 +31,11 @@ impl Expression {
             _ => {}
         }
 
+        /* Identity (log of base): log(b, b) == 1 */
+        if argument == base {
+            return Symbol::integer(1).expr();
+        }
+
         return Expression::Logarithm(AssociativeOperation::new(argument, base));
     }
     pub fn log(self, base: Expression) -> Self {
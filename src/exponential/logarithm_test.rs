@@ -41,4 +41,28 @@ mod constructor {
 
         assert_eq!(trial, Symbol::integer(4).expr());
     }
+
+    #[test]
+    fn simplifies_identity_log_base() {
+        let e = Symbol::e().expr();
+        assert_eq!(
+            Expression::logarithm(e.clone(), e),
+            Symbol::integer(1).expr()
+        );
+
+        let whatever = Symbol::variable("a").expr() + Symbol::variable("b").expr();
+        let trial = Expression::logarithm(whatever.clone(), whatever);
+        assert_eq!(trial, Symbol::integer(1).expr());
+    }
+
+    #[test]
+    fn simplifies_natural_log_of_exponential() {
+        let x = Symbol::variable("x").expr();
+        let trial = Expression::logarithm(
+            Expression::power(Symbol::e().expr(), x.clone()),
+            Symbol::e().expr(),
+        );
+
+        assert_eq!(trial, x);
+    }
 } /* end - constructor test */
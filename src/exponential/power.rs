@@ -1,17 +1,28 @@
+use crate::arithmetics::multiplication::{gaussian_parts, gaussian_product};
 use crate::base::associative_operation::AssociativeOperation;
 use crate::base::{expression::Expression, symbol::Symbol};
+use num::bigint::BigInt;
+use num::rational::BigRational;
 
 impl Expression {
     /**
      * Builds power associative operation
      *  - ignores neutral exponent
      *  - keeps signal separated
+     *  - folds exact numbers to integer exponents and exact roots
+     *  - folds Gaussian rationals to integer exponents: (1 + i) ^ -2 == -i / 2
+     *  - keeps zero to a negative exponent unevaluated, as a division by zero
+     *  - negative bases take the principal branch, for rational and real exponents alike:
+     *    (-4) ^ (1/2) == 2 i, and (-8) ^ (1/3) is kept, as its principal value is 1 + sqrt(3) i
      */
     pub fn power(base: Expression, exponent: Expression) -> Expression {
         match &base {
             Expression::Integer(n) => {
                 /* Identity (power 0 base) */
                 if n == &Symbol::integer(0) {
+                    if exponent.split_sign().0 {
+                        return Expression::Power(AssociativeOperation::new(base, exponent));
+                    }
                     return base;
                 }
                 /* Identity (power 1 base) */
@@ -22,6 +33,9 @@ impl Expression {
             Expression::Real(r) => {
                 /* Identity (power 0.0 base) */
                 if r == &Symbol::real(0.0) {
+                    if exponent.split_sign().0 {
+                        return Expression::Power(AssociativeOperation::new(base, exponent));
+                    }
                     return base;
                 }
                 /* Identity (power 1.0 base) */
@@ -73,6 +87,7 @@ impl Expression {
                 let mut numerical_factors: Vec<Expression> = base_factors
                     .get(&|factor| match factor {
                         Expression::Integer(_) => true,
+                        Expression::Rational(_) => true,
                         Expression::Real(_) => true,
                         _ => false,
                     })
@@ -84,6 +99,7 @@ impl Expression {
                 if !numerical_factors.is_empty() {
                     let other_factors: Vec<Expression> = base_factors.get(&|factor| match factor {
                         Expression::Integer(_) => false,
+                        Expression::Rational(_) => false,
                         Expression::Real(_) => false,
                         _ => true,
                     });
@@ -112,6 +128,30 @@ impl Expression {
             _ => {}
         }
 
+        match (&base, &exponent) {
+            /* Cycle of the imaginary unit: i ^ 2 == -1 */
+            (Expression::Constant(constant), Expression::Integer(integer_exponent))
+                if constant == &Symbol::i() =>
+            {
+                let remainder = num::Integer::mod_floor(&integer_exponent.data, &BigInt::from(4));
+                return match num::traits::ToPrimitive::to_u8(&remainder) {
+                    Some(0) => Symbol::integer(1).expr(),
+                    Some(1) => base,
+                    Some(2) => Symbol::integer(-1).expr(),
+                    _ => -base,
+                };
+            }
+            /* Gaussian rationals by repeated squaring: (1 + i) ^ 2 == 2 i */
+            (Expression::Addition(_), Expression::Integer(integer_exponent)) => {
+                if let (Some(parts), Some(count)) =
+                    (gaussian_parts(&base), integer_exponent.value())
+                {
+                    return gaussian_power(parts, count);
+                }
+            }
+            _ => {}
+        }
+
         match &base {
             Expression::Integer(integer_base) => {
                 match &exponent {
@@ -119,22 +159,26 @@ impl Expression {
                     Expression::Integer(integer_exponent) => {
                         if integer_exponent.is_negative() {
                             /* Rational Denominator */
-                            return Expression::Power(AssociativeOperation::new(
-                                integer_base
-                                    .pow(&integer_exponent.opposite())
-                                    .unwrap()
-                                    .expr(),
-                                Symbol::integer(-1).expr(),
-                            ));
+                            let rational_base: Symbol<BigRational> =
+                                Symbol::from(integer_base.clone());
+                            return rational_base.pow(integer_exponent).unwrap().expr();
                         } else {
                             return integer_base.pow(integer_exponent).unwrap().expr();
                         }
                     }
+                    /* Simplification (Integer to Rational), when the root is exact */
+                    Expression::Rational(rational_exponent) => {
+                        if let Some(power) =
+                            exact_power(&Symbol::from(integer_base.clone()), rational_exponent)
+                        {
+                            return power;
+                        }
+                    }
                     /* Simplification (Integer to Real) */
                     Expression::Real(real_exponent) => {
                         let base_value: f64 = integer_base.value().unwrap() as f64;
                         let exp_value: f64 = real_exponent.value().unwrap();
-                        return Symbol::real(base_value.powf(exp_value)).expr();
+                        return real_power(base_value, exp_value);
                     }
                     /* Simplification (Integer to multiplication with Integer or Real) */
                     Expression::Multiplication(exponent_factors) => {
@@ -175,6 +219,49 @@ impl Expression {
             _ => {}
         }
 
+        match &base {
+            Expression::Rational(rational_base) => {
+                match &exponent {
+                    /* Simplification (Rational to Integer) */
+                    Expression::Integer(integer_exponent) => {
+                        return rational_base.pow(integer_exponent).unwrap().expr();
+                    }
+                    /* Simplification (Rational to Rational), when the root is exact */
+                    Expression::Rational(rational_exponent) => {
+                        if let Some(power) = exact_power(rational_base, rational_exponent) {
+                            return power;
+                        }
+                    }
+                    /* Simplification (Rational to Real) */
+                    Expression::Real(real_exponent) => {
+                        let base_value = rational_base.value().unwrap();
+                        let exponent_value = real_exponent.value().unwrap();
+                        return real_power(base_value, exponent_value);
+                    }
+                    /* Simplification (Rational to multiplication with Integer) */
+                    Expression::Multiplication(exponent_factors) => {
+                        if let Some(integer_exponent_factor) =
+                            exponent_factors.get_one(&|factor| match factor {
+                                Expression::Integer(_) => true,
+                                _ => false,
+                            })
+                        {
+                            let other_factors = exponent_factors.get(&|factor| match factor {
+                                Expression::Integer(_) => false,
+                                _ => true,
+                            });
+                            return Expression::power(
+                                Expression::power(base, integer_exponent_factor), /* Recursion: rational to int */
+                                Expression::multiplication(other_factors),
+                            );
+                        }
+                    }
+                    _ => {}
+                }
+            }
+            _ => {}
+        }
+
         match &base {
             Expression::Real(real_base) => {
                 match &exponent {
@@ -182,8 +269,13 @@ impl Expression {
                     Expression::Real(real_exponent) => {
                         let base_value = real_base.value().unwrap();
                         let exponent_value = real_exponent.value().unwrap();
-                        let power_value = (base_value).powf(exponent_value);
-                        return Symbol::real(power_value).expr();
+                        return real_power(base_value, exponent_value);
+                    }
+                    /* Simplification (Real to Rational) */
+                    Expression::Rational(rational_exponent) => {
+                        let base_value = real_base.value().unwrap();
+                        let exponent_value = rational_exponent.value().unwrap();
+                        return real_power(base_value, exponent_value);
                     }
                     /* Simplification (Real to Integer) */
                     Expression::Integer(integer_exponent) => {
@@ -238,3 +330,72 @@ impl Expression {
         Self::power(self, exponent)
     }
 } /* end - power expression */
+
+/**
+ * Floating point power, the principal complex value for negative bases
+ *  - (-b) ^ x == b ^ x * (cos(pi x) + i sin(pi x))
+ *  - parts below the floating point precision of the result are dropped: (-1) ^ 0.5 == i
+ */
+fn real_power(base: f64, exponent: f64) -> Expression {
+    if base >= 0.0 || exponent.fract() == 0.0 {
+        return Symbol::real(base.powf(exponent)).expr();
+    }
+
+    let magnitude = (-base).powf(exponent);
+    let angle = std::f64::consts::PI * exponent;
+    let precision = magnitude * 4.0 * f64::EPSILON;
+    let part = |value: f64| {
+        if value.abs() < precision {
+            Symbol::real(0.0).expr()
+        } else {
+            Symbol::real(value).expr()
+        }
+    };
+    part(magnitude * angle.cos()) + part(magnitude * angle.sin()) * Symbol::i().expr()
+}
+
+/**
+ * Exact principal value of a rational power, if the root of the base is rational
+ *  - (-b) ^ (p/2) == b ^ (p/2) * i ^ p, other roots of negative bases are not rational
+ */
+fn exact_power(base: &Symbol<BigRational>, exponent: &Symbol<BigRational>) -> Option<Expression> {
+    if base.is_negative() {
+        if exponent.denominator() != Symbol::integer(2) {
+            return None;
+        }
+        let magnitude = exact_power(&base.abs(), exponent)?;
+        let rotation = Expression::power(Symbol::i().expr(), exponent.numerator().expr());
+        return Some(magnitude * rotation);
+    }
+
+    let root: Symbol<BigRational> = base.root(&exponent.denominator())?;
+    let numerator: Symbol<BigInt> = exponent.numerator();
+    Some(root.pow(&numerator)?.expr())
+}
+
+/**
+ * Integer power of a nonzero Gaussian rational, negative exponents invert the base:
+ * (a + b i) ^ -1 == (a - b i) / (a^2 + b^2)
+ */
+fn gaussian_power(
+    (a, b): (Symbol<BigRational>, Symbol<BigRational>),
+    exponent: isize,
+) -> Expression {
+    let mut base = if exponent < 0 {
+        let norm = &a * &a + &b * &b;
+        (&a / &norm, b.opposite() / norm)
+    } else {
+        (a, b)
+    };
+    let mut power = (Symbol::rational(1, 1), Symbol::rational(0, 1));
+    let mut count = exponent.unsigned_abs();
+    while count > 0 {
+        if count % 2 == 1 {
+            power = gaussian_product(&power, &base);
+        }
+        base = gaussian_product(&base, &base);
+        count /= 2;
+    }
+    let (real, imaginary) = power;
+    Expression::addition(vec![real.expr(), imaginary.expr() * Symbol::i().expr()])
+}
@@ -127,6 +127,121 @@ mod constructor {
         }
     }
 
+    #[test]
+    fn evaluates_integer_to_negative_integer() {
+        let two = Symbol::integer(2).expr();
+        let minus_three = Symbol::integer(-3).expr();
+        assert_eq!(two.pow(minus_three), Symbol::rational(1, 8).expr());
+    }
+
+    #[test]
+    fn evaluates_rational_to_integer() {
+        let two_thirds = Symbol::rational(2, 3).expr();
+        assert_eq!(
+            two_thirds.clone().pow(Symbol::integer(2).expr()),
+            Symbol::rational(4, 9).expr()
+        );
+        assert_eq!(
+            two_thirds.pow(Symbol::integer(-1).expr()),
+            Symbol::rational(3, 2).expr()
+        );
+    }
+
+    #[test]
+    fn evaluates_exact_roots() {
+        let half = Symbol::rational(1, 2).expr();
+        assert_eq!(
+            Symbol::integer(4).expr().pow(half.clone()),
+            Symbol::integer(2).expr()
+        );
+        assert_eq!(
+            Symbol::rational(8, 27)
+                .expr()
+                .pow(Symbol::rational(2, 3).expr()),
+            Symbol::rational(4, 9).expr()
+        );
+
+        /* irrational roots are kept */
+        if let Expression::Power(p) = Symbol::integer(2).expr().pow(half.clone()) {
+            assert_eq!(p.argument(), Symbol::integer(2).expr());
+            assert_eq!(p.modifier(), half);
+        } else {
+            panic!();
+        }
+    }
+
+    #[test]
+    fn takes_principal_roots_of_negative_bases() {
+        let i = Symbol::i().expr();
+        assert_eq!(
+            Symbol::integer(-4)
+                .expr()
+                .pow(Symbol::rational(1, 2).expr()),
+            Symbol::integer(2).expr() * i.clone()
+        );
+        assert_eq!(
+            Symbol::rational(-1, 9)
+                .expr()
+                .pow(Symbol::rational(-3, 2).expr()),
+            Symbol::integer(27).expr() * i
+        );
+
+        /* the real cube root -2 is not the principal value */
+        let cube_root = Symbol::integer(-8)
+            .expr()
+            .pow(Symbol::rational(1, 3).expr());
+        assert!(matches!(cube_root, Expression::Power(_)));
+    }
+
+    #[test]
+    fn agrees_on_branch_for_rational_and_real_exponents() {
+        let env = std::collections::HashMap::new();
+        for (base, numerator, denominator) in [(-8, 1, 3), (-4, 1, 2), (-27, 2, 3), (-2, 3, 2)] {
+            let exact = Symbol::integer(base)
+                .expr()
+                .pow(Symbol::rational(numerator, denominator).expr());
+            let real = Symbol::integer(base)
+                .expr()
+                .pow(Symbol::real(numerator as f64 / denominator as f64).expr());
+
+            let exact = exact.evaluate_complex(&env).unwrap();
+            let real = real.evaluate_complex(&env).unwrap();
+            assert!((exact - real).norm() < 1e-12, "{} != {}", exact, real);
+        }
+    }
+
+    #[test]
+    fn negative_symbolic_exponent_terminates() {
+        let x = Symbol::variable("x").expr();
+        let power = Symbol::integer(2).expr().pow(-x.clone());
+        assert_eq!(power, Symbol::rational(1, 2).expr().pow(x));
+    }
+
+    #[test]
+    fn cycles_imaginary_unit() {
+        let i = Symbol::i().expr();
+        assert_eq!(
+            i.clone().pow(Symbol::integer(2).expr()),
+            Symbol::integer(-1).expr()
+        );
+        assert_eq!(i.clone().pow(Symbol::integer(3).expr()), -i.clone());
+        assert_eq!(
+            i.clone().pow(Symbol::integer(4).expr()),
+            Symbol::integer(1).expr()
+        );
+        assert_eq!(i.clone().pow(Symbol::integer(-1).expr()), -i.clone());
+        assert_eq!(i.clone().pow(Symbol::integer(9).expr()), i);
+    }
+
+    #[test]
+    fn simplifies_exponential_of_natural_log() {
+        let x = Symbol::variable("x").expr();
+        let e = Symbol::e().expr();
+        let trial = e.clone().pow(Expression::logarithm(x.clone(), e));
+
+        assert_eq!(trial, x);
+    }
+
     #[test]
     fn addition_to_integer() {
         let arg = Symbol::variable("a").expr() + Symbol::real(2.0).expr();
@@ -14,8 +14,12 @@ pub mod base {
 }
 
 pub mod symbols {
+    pub mod constant;
+    mod constant_test;
     pub mod integer;
     mod integer_test;
+    pub mod rational;
+    mod rational_test;
     pub mod real;
     mod real_test;
     pub mod variable;
@@ -23,23 +27,37 @@ pub mod symbols {
 }
 
 pub mod manipulation {
-//     pub mod differentiate;
+    pub mod cancel;
+    mod cancel_test;
+    pub mod differentiate;
+    mod differentiate_test;
     pub mod expand;
     mod expand_test;
     pub mod expression_equality;
     pub mod expression_comparison;
     pub mod expression_comparison_test;
-//     pub mod numeric_evaluation;
+    pub mod numeric_evaluation;
+    mod numeric_evaluation_test;
     pub mod ordeable;
 //     mod ordeable_test;
-//     pub mod pattern_matchable;
+    pub mod pattern_matchable;
+    mod pattern_matchable_test;
     pub mod simplifiable;
     mod simplifiable_test;
+    pub mod variables;
+    mod variables_test;
     pub mod simplification_rules {
+        pub mod pattern_rule;
         pub mod rule;
+        pub mod rule_set;
+        mod rule_set_test;
         pub mod identities {
+            pub mod change_of_base;
+            pub mod double_angle;
             pub mod inverse_power_log;
-            // pub mod pitagorean_identity;
+            pub mod pitagorean_identity;
+            pub mod tangent_quotient;
+            pub mod trigonometric_term;
         }
         pub mod factoring {
             // pub mod factorable;
@@ -48,20 +66,22 @@ pub mod manipulation {
             pub mod multiplicative_common_factor;
             pub mod single_algebraic_denominator;
         }
-        pub mod rational {
-            pub mod rationals;
-            pub mod rationals_addition;
-            pub mod rationals_multiplication;
-        }
     }
     pub mod expansion_rules {
         pub mod rule;
+        pub mod angle_addition;
+        pub mod multiple_angle;
         pub mod multiplicative_distributive;
         mod multiplicative_distributive_test;
         pub mod power_distributive_addition;
         pub mod power_distributive_multiplication;
+        pub mod power_reduction;
+        pub mod product_to_sum;
     }
-//     // pub mod replaceable;
+    pub mod replaceable;
+    mod replaceable_test;
+    pub mod rewritable;
+    mod rewritable_test;
 }
 
 pub mod arithmetics {
@@ -71,20 +91,96 @@ pub mod arithmetics {
     mod division_test;
     pub mod multiplication;
     mod multiplication_test;
-//     pub mod primitives_interface;
-//     mod primitives_interface_test;
+    pub mod primitives_interface;
+    mod primitives_interface_test;
     pub mod subtraction;
     mod subtraction_test;
 }
 
+pub mod parsing {
+    pub mod parser;
+    mod parser_test;
+    pub mod tokenizer;
+}
+
 pub mod exponential {
+    pub mod exp;
+    mod exp_test;
+    pub mod ln;
     pub mod logarithm;
     pub mod logarithm_test;
     pub mod power;
     pub mod power_test;
 }
 
-// pub mod trigonometrics {
-//     pub mod cossine;
-//     pub mod sine;
-// }
+pub mod trigonometrics {
+    pub mod trigonometric;
+
+    pub mod cossecant;
+    pub mod cossine;
+    mod cossine_test;
+    pub mod cotangent;
+    pub mod secant;
+    mod secant_test;
+    pub mod sine;
+    mod sine_test;
+    pub mod tangent;
+    mod tangent_test;
+
+    pub mod arccossecant;
+    pub mod arccossine;
+    pub mod arccotangent;
+    pub mod arcsecant;
+    pub mod arcsine;
+    pub mod arctangent;
+    mod inverse_test;
+}
+
+pub mod complex {
+    pub mod rectangular;
+
+    pub mod abs;
+    pub mod arg;
+    pub mod conjugate;
+    pub mod im;
+    pub mod re;
+    mod complex_test;
+}
+
+pub mod polynomials {
+    pub mod algebraic_extension;
+    pub mod berlekamp;
+    mod berlekamp_test;
+    pub mod cantor_zassenhaus;
+    mod cantor_zassenhaus_test;
+    pub mod division;
+    mod division_test;
+    pub mod factorisation;
+    mod factorisation_test;
+    pub mod finite_field;
+    pub mod finite_field_polynomial;
+    mod finite_field_test;
+    pub mod gcd;
+    mod gcd_test;
+    pub mod hensel;
+    pub mod monomial_order;
+    pub mod multi_polynomial;
+    mod multi_polynomial_test;
+    pub mod polynomial;
+    mod polynomial_test;
+    pub mod random;
+    pub mod resultant;
+    mod resultant_test;
+    pub mod squarefree;
+    mod squarefree_test;
+    pub mod trager;
+    mod trager_test;
+}
+
+pub mod printing {
+    pub mod infix;
+    mod infix_test;
+    pub mod latex;
+    mod latex_test;
+    pub mod layout;
+}
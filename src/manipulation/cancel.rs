@@ -0,0 +1,93 @@
+use crate::base::{expression::Expression, symbol::Symbol};
+use crate::polynomials::multi_polynomial::MultiPolynomial;
+
+impl Expression {
+    /**
+     * Normalises rational functions: a single fraction of polynomials without common factors
+     *  - the denominator has coprime integer coefficients and a positive leading coefficient
+     *  - non polynomial subexpressions are canceled on their own
+     */
+    pub fn cancel(&self) -> Expression {
+        let variables = self.variables();
+        match rational_function(self, &variables) {
+            Some((numerator, denominator)) => {
+                let (numerator, denominator) = reduced(numerator, denominator);
+                if denominator.is_zero() {
+                    return self.clone();
+                }
+                match denominator.total_degree() {
+                    Some(0) => numerator.to_expression(),
+                    _ => numerator.to_expression() / denominator.to_expression(),
+                }
+            }
+            None => self.map_children(&|child| child.cancel()),
+        }
+    }
+}
+
+/**
+ * Numerator and denominator polynomials of an expression
+ *  - `None` when the expression is not a ratio of polynomials in the variables
+ */
+fn rational_function(
+    expression: &Expression,
+    variables: &[Symbol<String>],
+) -> Option<(MultiPolynomial, MultiPolynomial)> {
+    let one = MultiPolynomial::constant(variables.to_vec(), Symbol::rational(1, 1));
+    match expression {
+        Expression::Addition(addends) => addends.items().iter().try_fold(
+            (MultiPolynomial::zero(variables.to_vec()), one),
+            |(numerator, denominator), addend| {
+                let (addend_numerator, addend_denominator) = rational_function(addend, variables)?;
+                Some(reduced(
+                    &(&numerator * &addend_denominator) + &(&addend_numerator * &denominator),
+                    &denominator * &addend_denominator,
+                ))
+            },
+        ),
+        Expression::Multiplication(factors) => factors.items().iter().try_fold(
+            (one.clone(), one),
+            |(numerator, denominator), factor| {
+                let (factor_numerator, factor_denominator) = rational_function(factor, variables)?;
+                Some(reduced(
+                    &numerator * &factor_numerator,
+                    &denominator * &factor_denominator,
+                ))
+            },
+        ),
+        Expression::Power(power) => match power.modifier() {
+            Expression::Integer(n) => {
+                let (numerator, denominator) = rational_function(&power.argument(), variables)?;
+                let exponent = n.abs().value()? as usize;
+                match n.is_negative() {
+                    true => Some((denominator.pow(exponent), numerator.pow(exponent))),
+                    false => Some((numerator.pow(exponent), denominator.pow(exponent))),
+                }
+            }
+            _ => None,
+        },
+        _ => Some((expression.as_multi_polynomial(variables)?, one)),
+    }
+}
+
+/**
+ * Divides both polynomials by their greatest common divisor and moves the content of the
+ * denominator to the numerator
+ */
+fn reduced(
+    numerator: MultiPolynomial,
+    denominator: MultiPolynomial,
+) -> (MultiPolynomial, MultiPolynomial) {
+    if denominator.is_zero() {
+        return (numerator, denominator);
+    }
+    let gcd = numerator.gcd(&denominator);
+    let numerator = numerator.divide(&gcd).unwrap();
+    let denominator = denominator.divide(&gcd).unwrap();
+
+    let content = denominator.content();
+    (
+        numerator.scale(&content.inverse().unwrap()),
+        denominator.primitive_part(),
+    )
+}
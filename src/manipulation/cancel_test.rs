@@ -0,0 +1,52 @@
+#[cfg(test)]
+mod rational_functions {
+    use crate::base::expression::Expression;
+
+    fn cancel(input: &str, expected: &str) {
+        let trial = Expression::parse(input).unwrap().cancel();
+        let expected = Expression::parse(expected).unwrap();
+
+        assert_eq!(trial, expected);
+    }
+
+    #[test]
+    fn cancels_common_factors() {
+        cancel("(x^2 - 1)/(x + 1)", "x - 1");
+        cancel("(x^2 - 2*x + 1)/(x^2 - 1)", "(x - 1)/(x + 1)");
+        cancel("(x^2*y - x*y^2)/(x^2 - y^2)", "x*y/(x + y)");
+    }
+
+    #[test]
+    fn normalises_denominators() {
+        cancel("(x + 1)/(2 - 2*x)", "(-1/2*x - 1/2)/(x - 1)");
+        cancel("(2*x + 2)/4", "1/2*x + 1/2");
+    }
+
+    #[test]
+    fn single_fraction_of_sums() {
+        cancel("1/(x - 1) - 1/(x + 1)", "2/(x^2 - 1)");
+        cancel("x/(x - y) + y/(y - x)", "1");
+    }
+
+    #[test]
+    fn cancels_inside_other_expressions() {
+        cancel("sin((x^2 - 1)/(x - 1)) + x", "sin(x + 1) + x");
+    }
+}
+
+#[cfg(test)]
+mod identities {
+    use crate::parsing::parser::parse;
+
+    #[test]
+    fn equal_rational_functions() {
+        assert!(parse("(x^2 - 1)/(x + 1)").equal(&parse("x - 1")));
+        assert!(parse("1/x + 1/y").equal(&parse("(x + y)/(x*y)")));
+        assert!(parse("(x^3 - y^3)/(x - y)").equal(&parse("x^2 + x*y + y^2")));
+    }
+
+    #[test]
+    fn different_rational_functions() {
+        assert!(!parse("(x^2 - 1)/(x + 1)").equal(&parse("x + 1")));
+    }
+}
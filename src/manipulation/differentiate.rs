@@ -1,5 +1,240 @@
-use crate::base::expression::Expression;
+// =================================== //
+//      Recursion on Expression        //
+// =================================== //
+use crate::base::{expression::Expression, symbol::Symbol};
 
-pub trait Differentiable {
-    fn differentiate(&self) -> Result<Expression, Expression>;
+impl Expression {
+    /**
+     * Derivative with respect to a variable, simplified
+     */
+    pub fn differentiate(&self, variable: &Symbol<String>) -> Expression {
+        self.derivative(variable).simplify()
+    }
+
+    /**
+     * Repeated differentiation with respect to the same variable
+     */
+    pub fn nth_derivative(&self, variable: &Symbol<String>, order: usize) -> Expression {
+        (0..order).fold(self.clone(), |derivative, _| {
+            derivative.differentiate(variable)
+        })
+    }
+
+    /**
+     * Mixed partial derivative, differentiating in the given variable order
+     */
+    pub fn partial_derivative(&self, variables: &[Symbol<String>]) -> Expression {
+        variables.iter().fold(self.clone(), |derivative, variable| {
+            derivative.differentiate(variable)
+        })
+    }
+
+    /**
+     * sqrt(1 - u^2), denominator of the arcsine and arccossine derivatives
+     */
+    fn complement_root(value: Expression) -> Expression {
+        let square = Expression::power(value, Symbol::integer(2).expr());
+        Expression::power(
+            Symbol::integer(1).expr() - square,
+            Symbol::rational(1, 2).expr(),
+        )
+    }
+
+    /**
+     * 1 + u^2, denominator of the arctangent and arccotangent derivatives
+     */
+    fn unit_addition(value: Expression) -> Expression {
+        Symbol::integer(1).expr() + Expression::power(value, Symbol::integer(2).expr())
+    }
+
+    /**
+     * u^2 * sqrt(1 - u^-2), denominator of the arcsecant and arccossecant derivatives
+     */
+    fn reciprocal_complement_root(value: Expression) -> Expression {
+        let inverse_square = Expression::power(value.clone(), Symbol::integer(-2).expr());
+        Expression::power(value, Symbol::integer(2).expr())
+            * Expression::power(
+                Symbol::integer(1).expr() - inverse_square,
+                Symbol::rational(1, 2).expr(),
+            )
+    }
+
+    /**
+     * Non simplified derivative
+     */
+    fn derivative(&self, variable: &Symbol<String>) -> Expression {
+        if self.is_free_of(variable) {
+            return Symbol::integer(0).expr();
+        }
+
+        match self {
+            Expression::Variable(_) => Symbol::integer(1).expr(),
+
+            /* Linearity */
+            Expression::Addition(addends) => {
+                Expression::addition(addends.map(&|addend| addend.derivative(variable)))
+            }
+
+            /* Product rule: (f1 f2 ... fn)' = sum(f1 ... fi' ... fn) */
+            Expression::Multiplication(factors) => {
+                let factor_list = factors.items();
+                let mut addends: Vec<Expression> = Vec::new();
+                for (index, factor) in factor_list.iter().enumerate() {
+                    if factor.is_free_of(variable) {
+                        continue;
+                    }
+                    let mut product: Vec<Expression> = factor_list
+                        .iter()
+                        .enumerate()
+                        .filter(|&(other_index, _)| other_index != index)
+                        .map(|(_, other_factor)| other_factor.clone())
+                        .collect();
+                    product.push(factor.derivative(variable));
+                    addends.push(Expression::multiplication(product));
+                }
+                Expression::addition(addends)
+            }
+
+            Expression::Power(power) => {
+                let base = power.argument();
+                let exponent = power.modifier();
+
+                if exponent.is_free_of(variable) {
+                    /* Power rule: (f ^ g)' = g * f ^ (g - 1) * f' */
+                    Expression::multiplication(vec![
+                        exponent.clone(),
+                        Expression::power(base.clone(), exponent - Symbol::integer(1).expr()),
+                        base.derivative(variable),
+                    ])
+                } else if base.is_free_of(variable) {
+                    /* Exponential rule: (f ^ g)' = f ^ g * ln(f) * g' */
+                    Expression::multiplication(vec![
+                        self.clone(),
+                        Expression::ln(base),
+                        exponent.derivative(variable),
+                    ])
+                } else {
+                    /* Generalised power rule: (f ^ g)' = f ^ g * (g' * ln(f) + g * f' / f) */
+                    Expression::multiplication(vec![
+                        self.clone(),
+                        Expression::addition(vec![
+                            exponent.derivative(variable) * Expression::ln(base.clone()),
+                            exponent * base.derivative(variable) / base,
+                        ]),
+                    ])
+                }
+            }
+
+            Expression::Logarithm(log) => {
+                let argument = log.argument();
+                let base = log.modifier();
+
+                if base.is_free_of(variable) {
+                    /* log(f, b)' = f' / (f * ln(b)) */
+                    argument.derivative(variable)
+                        / Expression::multiplication(vec![argument.clone(), Expression::ln(base)])
+                } else {
+                    /* log(f, g) = ln(f) / ln(g) through the quotient rule */
+                    let ln_argument = Expression::ln(argument.clone());
+                    let ln_base = Expression::ln(base.clone());
+                    Expression::subtraction(
+                        argument.derivative(variable) / argument * ln_base.clone(),
+                        ln_argument * base.derivative(variable) / base,
+                    ) / Expression::power(ln_base, Symbol::integer(2).expr())
+                }
+            }
+
+            /* Chain rule on trigonometrics */
+            Expression::Sine(sine) => {
+                let angle = sine.argument();
+                Expression::cos(angle.clone()) * angle.derivative(variable)
+            }
+            Expression::Cossine(cossine) => {
+                let angle = cossine.argument();
+                -Expression::sin(angle.clone()) * angle.derivative(variable)
+            }
+            Expression::Tangent(tangent) => {
+                let angle = tangent.argument();
+                Expression::power(Expression::sec(angle.clone()), Symbol::integer(2).expr())
+                    * angle.derivative(variable)
+            }
+            Expression::Cotangent(cotangent) => {
+                let angle = cotangent.argument();
+                -Expression::power(Expression::csc(angle.clone()), Symbol::integer(2).expr())
+                    * angle.derivative(variable)
+            }
+            Expression::Secant(secant) => {
+                let angle = secant.argument();
+                Expression::multiplication(vec![
+                    Expression::sec(angle.clone()),
+                    Expression::tan(angle.clone()),
+                    angle.derivative(variable),
+                ])
+            }
+            Expression::Cossecant(cossecant) => {
+                let angle = cossecant.argument();
+                -Expression::multiplication(vec![
+                    Expression::csc(angle.clone()),
+                    Expression::cot(angle.clone()),
+                    angle.derivative(variable),
+                ])
+            }
+
+            /* Chain rule on inverse trigonometrics */
+            Expression::ArcSine(arc) => {
+                let value = arc.argument();
+                value.derivative(variable) / Self::complement_root(value)
+            }
+            Expression::ArcCossine(arc) => {
+                let value = arc.argument();
+                -(value.derivative(variable) / Self::complement_root(value))
+            }
+            Expression::ArcTangent(arc) => {
+                let value = arc.argument();
+                value.derivative(variable) / Self::unit_addition(value)
+            }
+            Expression::ArcCotangent(arc) => {
+                let value = arc.argument();
+                -(value.derivative(variable) / Self::unit_addition(value))
+            }
+            Expression::ArcSecant(arc) => {
+                let value = arc.argument();
+                value.derivative(variable) / Self::reciprocal_complement_root(value)
+            }
+            Expression::ArcCossecant(arc) => {
+                let value = arc.argument();
+                -(value.derivative(variable) / Self::reciprocal_complement_root(value))
+            }
+
+            /* Complex parts, through the real variable */
+            Expression::RealPart(part) => Expression::re(part.argument().derivative(variable)),
+            Expression::ImaginaryPart(part) => Expression::im(part.argument().derivative(variable)),
+            Expression::Conjugate(conjugate) => {
+                Expression::conjugate(conjugate.argument().derivative(variable))
+            }
+            Expression::Absolute(absolute) => {
+                /* abs(f)' = (re(f) re(f') + im(f) im(f')) / abs(f) */
+                let value = absolute.argument();
+                let derivative = value.derivative(variable);
+                Expression::addition(vec![
+                    Expression::re(value.clone()) * Expression::re(derivative.clone()),
+                    Expression::im(value.clone()) * Expression::im(derivative),
+                ]) / Expression::abs(value)
+            }
+            Expression::Argument(argument) => {
+                /* arg(f)' = (re(f) im(f') - im(f) re(f')) / abs(f)^2 */
+                let value = argument.argument();
+                let derivative = value.derivative(variable);
+                Expression::subtraction(
+                    Expression::re(value.clone()) * Expression::im(derivative.clone()),
+                    Expression::im(value.clone()) * Expression::re(derivative),
+                ) / Expression::power(Expression::abs(value), Symbol::integer(2).expr())
+            }
+
+            Expression::Integer(_)
+            | Expression::Rational(_)
+            | Expression::Real(_)
+            | Expression::Constant(_) => Symbol::integer(0).expr(),
+        }
+    }
 }
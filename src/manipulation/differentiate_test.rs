@@ -0,0 +1,215 @@
+#[cfg(test)]
+mod differentiate {
+    use crate::base::{expression::Expression, symbol::Symbol};
+
+    #[test]
+    fn constants_and_variables() {
+        let x = &Symbol::variable("x");
+        let y = &Symbol::variable("y");
+
+        assert_eq!(
+            Symbol::integer(3).expr().differentiate(x),
+            Symbol::integer(0).expr()
+        );
+        assert_eq!(
+            Symbol::real(3.0).expr().differentiate(x),
+            Symbol::integer(0).expr()
+        );
+        assert_eq!(x.clone().expr().differentiate(x), Symbol::integer(1).expr());
+        assert_eq!(y.clone().expr().differentiate(x), Symbol::integer(0).expr());
+    }
+
+    #[test]
+    fn polynomial() {
+        let x = &Symbol::variable("x");
+        let fx = Expression::parse("3*x^2 + 2*x + 1").unwrap();
+
+        assert_eq!(
+            fx.differentiate(x),
+            Symbol::integer(6).expr() * x.clone().expr() + Symbol::integer(2).expr()
+        );
+    }
+
+    #[test]
+    fn product_rule() {
+        let x = &Symbol::variable("x");
+        let sin = Expression::sin(x.clone().expr());
+        let cos = Expression::cos(x.clone().expr());
+        let two = &Symbol::integer(2).expr();
+
+        /* cos(x)^2 - sin(x)^2, contracted by the double angle identity */
+        assert_eq!(
+            (sin.clone() * cos.clone()).differentiate(x),
+            Expression::cos(two * x.clone().expr())
+        );
+    }
+
+    #[test]
+    fn n_ary_product_rule() {
+        let x = &Symbol::variable("x");
+        let fx = Expression::parse("x * sin(x) * cos(x)").unwrap();
+
+        let two_x = Symbol::integer(2).expr() * x.clone().expr();
+        let expected = Expression::sin(two_x.clone()) / Symbol::integer(2).expr()
+            + x.clone().expr() * Expression::cos(two_x);
+
+        assert_eq!(fx.differentiate(x), expected);
+    }
+
+    #[test]
+    fn chain_rule() {
+        let x = &Symbol::variable("x");
+        let x_squared = x.clone().expr().pow(Symbol::integer(2).expr());
+
+        assert_eq!(
+            Expression::sin(x_squared.clone()).differentiate(x),
+            Symbol::integer(2).expr() * x.clone().expr() * Expression::cos(x_squared.clone())
+        );
+        assert_eq!(
+            Expression::cos(x_squared.clone()).differentiate(x),
+            Symbol::integer(-2).expr() * x.clone().expr() * Expression::sin(x_squared)
+        );
+    }
+
+    #[test]
+    fn trigonometric_ratios() {
+        let x = &Symbol::variable("x");
+        let derivative = |input: &str| Expression::parse(input).unwrap().differentiate(x);
+
+        assert_eq!(
+            derivative("tan(x)"),
+            Expression::sec(x.clone().expr()).pow(Symbol::integer(2).expr())
+        );
+        assert_eq!(
+            derivative("cot(x)"),
+            -Expression::csc(x.clone().expr()).pow(Symbol::integer(2).expr())
+        );
+        assert_eq!(
+            derivative("sec(x)"),
+            Expression::sec(x.clone().expr()) * Expression::tan(x.clone().expr())
+        );
+        assert_eq!(
+            derivative("csc(x)"),
+            -Expression::csc(x.clone().expr()) * Expression::cot(x.clone().expr())
+        );
+    }
+
+    #[test]
+    fn inverse_trigonometrics() {
+        use std::collections::HashMap;
+
+        let x = &Symbol::variable("x");
+        let mut env: HashMap<String, f64> = HashMap::new();
+        let slope = |input: &str, at: f64, env: &mut HashMap<String, f64>| {
+            env.insert(String::from("x"), at);
+            let derivative = Expression::parse(input).unwrap().differentiate(x);
+            derivative.evaluate(env).unwrap()
+        };
+
+        let root = (1.0 - 0.25f64).sqrt();
+        assert!((slope("asin(x)", 0.5, &mut env) - 1.0 / root).abs() < 1e-12);
+        assert!((slope("acos(x)", 0.5, &mut env) + 1.0 / root).abs() < 1e-12);
+        assert!((slope("atan(x)", 0.5, &mut env) - 1.0 / 1.25).abs() < 1e-12);
+        assert!((slope("acot(x)", 0.5, &mut env) + 1.0 / 1.25).abs() < 1e-12);
+
+        let root = (1.0 - 0.25f64).sqrt();
+        assert!((slope("asec(x)", 2.0, &mut env) - 1.0 / (4.0 * root)).abs() < 1e-12);
+        assert!((slope("acsc(x)", 2.0, &mut env) + 1.0 / (4.0 * root)).abs() < 1e-12);
+        assert!((slope("atan(x^2)", 0.5, &mut env) - 1.0 / (1.0 + 0.0625)).abs() < 1e-12);
+    }
+
+    #[test]
+    fn exponential() {
+        let x = &Symbol::variable("x");
+        let two = &Symbol::integer(2).expr();
+        let fx = two.clone().pow(x.clone().expr());
+
+        assert_eq!(
+            fx.differentiate(x),
+            fx.clone() * Expression::ln(two.clone())
+        );
+    }
+
+    #[test]
+    fn generalised_power_rule() {
+        let x = &Symbol::variable("x");
+        let fx = x.clone().expr().pow(x.clone().expr());
+
+        assert_eq!(
+            fx.differentiate(x),
+            fx.clone() + fx * Expression::ln(x.clone().expr())
+        );
+    }
+
+    #[test]
+    fn logarithm_with_constant_base() {
+        let x = &Symbol::variable("x");
+        let two = &Symbol::integer(2).expr();
+        let fx = Expression::logarithm(x.clone().expr(), two.clone());
+
+        assert_eq!(
+            fx.differentiate(x),
+            Symbol::integer(1).expr() / (x.clone().expr() * Expression::ln(two.clone()))
+        );
+    }
+
+    #[test]
+    fn logarithm_with_variable_base() {
+        let x = &Symbol::variable("x");
+        let y = &Symbol::variable("y");
+        let fx = Expression::logarithm(y.clone().expr(), x.clone().expr());
+
+        /* log(y, x) = ln(y) / ln(x) */
+        let expected = -Expression::ln(y.clone().expr())
+            * x.clone().expr().pow(Symbol::integer(-1).expr())
+            * Expression::ln(x.clone().expr()).pow(Symbol::integer(-2).expr());
+
+        assert_eq!(fx.differentiate(x), expected);
+    }
+}
+
+#[cfg(test)]
+mod higher_order {
+    use crate::base::{expression::Expression, symbol::Symbol};
+
+    #[test]
+    fn nth_derivative() {
+        let x = &Symbol::variable("x");
+        let fx = Expression::parse("x^4").unwrap();
+
+        assert_eq!(
+            fx.nth_derivative(x, 2),
+            Symbol::integer(12).expr() * x.clone().expr().pow(Symbol::integer(2).expr())
+        );
+        assert_eq!(fx.nth_derivative(x, 4), Symbol::integer(24).expr());
+        assert_eq!(fx.nth_derivative(x, 5), Symbol::integer(0).expr());
+        assert_eq!(fx.nth_derivative(x, 0), fx);
+    }
+
+    #[test]
+    fn sine_cycle() {
+        let x = &Symbol::variable("x");
+        let fx = Expression::sin(x.clone().expr());
+
+        assert_eq!(fx.nth_derivative(x, 2), -fx.clone());
+        assert_eq!(fx.nth_derivative(x, 4), fx);
+    }
+
+    #[test]
+    fn partial_derivative() {
+        let x = &Symbol::variable("x");
+        let y = &Symbol::variable("y");
+        let fxy = Expression::parse("x^2 * y^3 + x").unwrap();
+
+        assert_eq!(
+            fxy.partial_derivative(&[x.clone(), y.clone()]),
+            Symbol::integer(6).expr()
+                * x.clone().expr()
+                * y.clone().expr().pow(Symbol::integer(2).expr())
+        );
+        assert_eq!(
+            fxy.partial_derivative(&[x.clone(), y.clone()]),
+            fxy.partial_derivative(&[y.clone(), x.clone()])
+        );
+    }
+}
@@ -2,37 +2,140 @@
 //      Recursion on Expression        //
 // =================================== //
 use crate::{
-    base::expression::Expression,
+    base::{expression::Expression, symbol::Symbol},
     manipulation::expansion_rules::{
+        angle_addition::AngleAddition, multiple_angle::MultipleAngle,
         multiplicative_distributive::MultiplicativeDistributive,
-        power_distributive_addition::PowerDistributiveAddition, rule::Rule,
+        power_distributive_addition::PowerDistributiveAddition, power_reduction::PowerReduction,
+        product_to_sum::ProductToSum, rule::Rule,
     },
 };
 
 impl Expression {
     pub fn expand(self) -> Expression {
+        /* polynomial inputs are expanded and collected in the sparse representation */
+        if let Some(polynomial) = self.as_multi_polynomial(&self.variables()) {
+            return polynomial.to_expression();
+        }
+
         /* recursive expansion */
-        match &self {
-            Expression::Multiplication(factors) => {
-                return MultiplicativeDistributive::apply(&Expression::multiplication(
-                    factors.map(&|factor| factor.clone().expand()),
-                ));
-            }
+        let expanded = match &self {
+            Expression::Multiplication(factors) => MultiplicativeDistributive::apply(
+                &Expression::multiplication(factors.map(&|factor| factor.clone().expand())),
+            ),
             Expression::Addition(addends) => {
-                return Expression::addition(addends.map(&|addend| addend.clone().expand()));
+                Expression::addition(addends.map(&|addend| addend.clone().expand()))
             }
 
-            Expression::Power(power) => {
-                return PowerDistributiveAddition::apply(&Expression::power(
-                    power.argument().expand(),
-                    power.modifier().expand(),
-                ));
-            }
+            Expression::Power(power) => PowerDistributiveAddition::apply(&Expression::power(
+                power.argument().expand(),
+                power.modifier().expand(),
+            )),
             Expression::Logarithm(log) => {
                 return Expression::logarithm(log.argument().expand(), log.modifier().expand())
             }
 
             _ => return self,
+        };
+        collect_terms(expanded)
+    }
+
+    /**
+     * Expands sines and cossines of sums and integer multiples of angles, then distributes
+     *  - sin(a + b) == sin(a) * cos(b) + cos(a) * sin(b)
+     *  - cos(2x) == 2 * cos(x)^2 - 1
+     */
+    pub fn expand_trig(self) -> Expression {
+        let node = self.map_children(&|child| child.clone().expand_trig());
+        let rewritten = MultipleAngle::apply(&AngleAddition::apply(&node));
+        if rewritten == node {
+            return node.expand();
         }
+        rewritten.expand_trig()
     }
+
+    /**
+     * Rewrites products and powers of sines and cossines as sums of multiple angles
+     *  - sin(a) * cos(b) == (sin(a + b) + sin(a - b)) / 2
+     *  - sin(x)^2 == (1 - cos(2x)) / 2
+     */
+    pub fn linearize_trig(self) -> Expression {
+        let node = self.map_children(&|child| child.clone().linearize_trig());
+        let expanded = node.clone().expand();
+        if expanded != node {
+            return expanded.linearize_trig();
+        }
+        let rewritten = ProductToSum::apply(&PowerReduction::apply(&node));
+        if rewritten == node {
+            return node;
+        }
+        rewritten.linearize_trig()
+    }
+}
+
+/**
+ * Collects like terms in the sparse representation, taking non polynomial nodes as variables
+ *  - cos(x) / 4 + cos(x) / 2 == 3 * cos(x) / 4
+ */
+fn collect_terms(expression: Expression) -> Expression {
+    let mut kernels: Vec<Expression> = Vec::new();
+    let generic = replace_kernels(&expression, &mut kernels);
+    match generic.as_multi_polynomial(&generic.variables()) {
+        Some(polynomial) => polynomial.to_expression().substitute_all(
+            &kernels
+                .into_iter()
+                .enumerate()
+                .map(|(index, kernel)| (kernel_variable(index), kernel))
+                .collect(),
+        ),
+        None => expression,
+    }
+}
+
+/**
+ * Replaces the nodes out of the polynomial structure by variables, the same node by the same one
+ */
+fn replace_kernels(expression: &Expression, kernels: &mut Vec<Expression>) -> Expression {
+    match expression {
+        Expression::Integer(_) | Expression::Rational(_) | Expression::Variable(_) => {
+            expression.clone()
+        }
+        Expression::Addition(addends) => Expression::addition(
+            addends
+                .items()
+                .iter()
+                .map(|addend| replace_kernels(addend, kernels))
+                .collect(),
+        ),
+        Expression::Multiplication(factors) => Expression::multiplication(
+            factors
+                .items()
+                .iter()
+                .map(|factor| replace_kernels(factor, kernels))
+                .collect(),
+        ),
+        Expression::Power(power) if matches!(power.modifier(), Expression::Integer(n) if !n.is_negative()) => {
+            Expression::power(
+                replace_kernels(&power.argument(), kernels),
+                power.modifier(),
+            )
+        }
+        _ => {
+            let index = match kernels.iter().position(|kernel| kernel == expression) {
+                Some(index) => index,
+                None => {
+                    kernels.push(expression.clone());
+                    kernels.len() - 1
+                }
+            };
+            kernel_variable(index)
+        }
+    }
+}
+
+/**
+ * Placeholder variable, with a label no parsed variable can take
+ */
+fn kernel_variable(index: usize) -> Expression {
+    Symbol::variable(&format!("#{}", index)).expr()
 }
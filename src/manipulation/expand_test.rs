@@ -45,7 +45,15 @@ mod multiplication {
         let expression = (a + b) * (a + b) * a;
         let expanded = expression.expand();
 
-        assert_eq!(expanded, (a * a * a + a * b * a) + (b * a * a + b * b * a));
+        /* like terms are collected */
+        let two = &Symbol::integer(2).expr();
+        let three = &Symbol::integer(3).expr();
+        assert_eq!(
+            expanded,
+            a.clone().pow(three.clone())
+                + two * a.clone().pow(two.clone()) * b
+                + a * b.clone().pow(two.clone())
+        );
     }
 
     #[test]
@@ -54,10 +62,17 @@ mod multiplication {
         let a = &Symbol::variable("a").expr();
         let b = &Symbol::variable("b").expr();
 
+        let two = &Symbol::integer(2).expr();
+        let three = &Symbol::integer(3).expr();
+
         let expression = Expression::power(a.clone(), (a + b) * (a + b) * a);
         let expanded = expression.expand();
-        let expected =
-            Expression::power(a.clone(), (a * a * a + a * b * a) + (b * a * a + b * b * a));
+        let expected = Expression::power(
+            a.clone(),
+            a.clone().pow(three.clone())
+                + two * a.clone().pow(two.clone()) * b
+                + a * b.clone().pow(two.clone()),
+        );
 
         assert_eq!(expanded, expected);
     }
@@ -75,7 +90,7 @@ mod power {
         let d = &Symbol::integer(2).expr();
 
         let expanded = ((a + b).pow(d.clone())).expand();
-        let expected = (a * a) + (a * b) + (a * b) + (b * b);
+        let expected = a.clone().pow(d.clone()) + d * a * b + b.clone().pow(d.clone());
 
         assert_eq!(expanded, expected);
     }
@@ -96,7 +111,7 @@ mod power {
 
 #[cfg(test)]
 mod mixed_expansions {
-    use crate::base::symbol::Symbol;
+    use crate::base::{expression::Expression, symbol::Symbol};
 
     #[test]
     fn sample_1() {
@@ -108,15 +123,62 @@ mod mixed_expansions {
         let two = &Symbol::integer(2).expr();
 
         let expanded = ((a + b) * (c + d).pow(two.clone())).expand();
-        let expected = (a * c * c)
-            + (a * c * d)
-            + (a * c * d)
-            + (a * d * d)
-            + (b * c * c)
-            + (b * c * d)
-            + (b * c * d)
-            + (b * d * d);
+        let square = |x: &Expression| x.clone().pow(two.clone());
+        let expected = (a * square(c))
+            + (two * a * c * d)
+            + (a * square(d))
+            + (b * square(c))
+            + (two * b * c * d)
+            + (b * square(d));
 
         assert_eq!(expanded, expected);
     }
 }
+
+#[cfg(test)]
+mod trigonometric {
+    use crate::parsing::parser::parse;
+
+    #[test]
+    fn plain_expansion_keeps_angles() {
+        let trial = parse("sin(a + b) * (x + 1)");
+        assert_eq!(trial.expand(), parse("sin(a + b) * x + sin(a + b)"));
+    }
+
+    #[test]
+    fn expands_sum_of_three_angles() {
+        let trial = parse("sin(a + b + c)").expand_trig();
+        let expected = parse(
+            "sin(a) * cos(b) * cos(c) - sin(a) * sin(b) * sin(c) \
+             + cos(a) * sin(b) * cos(c) + cos(a) * cos(b) * sin(c)",
+        );
+        assert_eq!(trial, expected);
+    }
+
+    #[test]
+    fn expands_multiple_angles_inside_sums() {
+        let trial = parse("cos(2 * x + y)").expand_trig();
+        let expected = parse("2 * cos(x)^2 * cos(y) - cos(y) - 2 * sin(x) * cos(x) * sin(y)");
+        assert_eq!(trial, expected);
+    }
+
+    #[test]
+    fn linearizes_powers_and_products() {
+        assert_eq!(
+            parse("sin(x)^2").linearize_trig(),
+            parse("1/2 - cos(2 * x) / 2").expand()
+        );
+        assert_eq!(
+            parse("cos(x)^3").linearize_trig(),
+            parse("3 * cos(x) / 4 + cos(3 * x) / 4")
+        );
+        assert_eq!(
+            parse("sin(x)^2 * cos(x)^2").linearize_trig(),
+            parse("1/8 - cos(4 * x) / 8")
+        );
+        assert_eq!(
+            parse("sin(x) * cos(y) * 2").linearize_trig(),
+            parse("sin(x + y) + sin(x - y)")
+        );
+    }
+}
@@ -0,0 +1,61 @@
+use crate::base::expression::Expression;
+use crate::manipulation::expansion_rules::rule::Rule;
+
+/**
+ * Expands sine and cossine of a sum of angles
+ *      sin(a + b) == sin(a) * cos(b) + cos(a) * sin(b)
+ *      cos(a + b) == cos(a) * cos(b) - sin(a) * sin(b)
+ *  - splits the first addend from the others, the remaining sum is left for the next pass
+ */
+pub struct AngleAddition {}
+impl Rule for AngleAddition {
+    fn apply(expression: &Expression) -> Expression {
+        let (is_sine, angle) = match expression {
+            Expression::Sine(sine) => (true, sine.argument()),
+            Expression::Cossine(cossine) => (false, cossine.argument()),
+            _ => return expression.clone(),
+        };
+
+        let mut addends = match angle {
+            Expression::Addition(addends) => addends.items(),
+            _ => return expression.clone(),
+        };
+        let a = addends.remove(0);
+        let b = Expression::addition(addends);
+
+        let (sin_a, cos_a) = (Expression::sin(a.clone()), Expression::cos(a));
+        let (sin_b, cos_b) = (Expression::sin(b.clone()), Expression::cos(b));
+
+        if is_sine {
+            sin_a * cos_b + cos_a * sin_b
+        } else {
+            cos_a * cos_b - sin_a * sin_b
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::parsing::parser::parse;
+
+    #[test]
+    fn expands_sum_of_two_angles() {
+        assert_eq!(
+            AngleAddition::apply(&parse("sin(a + b)")),
+            parse("sin(a) * cos(b) + cos(a) * sin(b)")
+        );
+        assert_eq!(
+            AngleAddition::apply(&parse("cos(a + b)")),
+            parse("cos(a) * cos(b) - sin(a) * sin(b)")
+        );
+    }
+
+    #[test]
+    fn keeps_single_angles() {
+        assert_eq!(
+            AngleAddition::apply(&parse("sin(2 * a)")),
+            parse("sin(2 * a)")
+        );
+    }
+}
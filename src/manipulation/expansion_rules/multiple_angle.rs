@@ -0,0 +1,139 @@
+use crate::base::{expression::Expression, symbol::Symbol};
+use crate::manipulation::expansion_rules::rule::Rule;
+use num::bigint::BigInt;
+
+/**
+ * Expands sine and cossine of integer multiples of an angle through Chebyshev polynomials
+ *      cos(n * x) == T_n(cos(x))
+ *      sin(n * x) == sin(x) * U_(n-1)(cos(x))
+ */
+pub struct MultipleAngle {}
+impl Rule for MultipleAngle {
+    fn apply(expression: &Expression) -> Expression {
+        let (is_sine, angle) = match expression {
+            Expression::Sine(sine) => (true, sine.argument()),
+            Expression::Cossine(cossine) => (false, cossine.argument()),
+            _ => return expression.clone(),
+        };
+
+        let (multiple, angle) = match integer_multiple(&angle) {
+            Some(multiple) if multiple >= 2 => (multiple, multiple_base(&angle)),
+            _ => return expression.clone(),
+        };
+
+        let cossine = Expression::cos(angle.clone());
+        if is_sine {
+            Expression::sin(angle) * polynomial(&chebyshev(multiple - 1, 2), &cossine)
+        } else {
+            polynomial(&chebyshev(multiple, 1), &cossine)
+        }
+    }
+}
+
+/**
+ * Integer coefficient of the angle, as n in n * x
+ */
+fn integer_multiple(angle: &Expression) -> Option<isize> {
+    match angle {
+        Expression::Multiplication(factors) => match factors.get_rationals().as_slice() {
+            [Expression::Integer(n)] => n.value(),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+fn multiple_base(angle: &Expression) -> Expression {
+    match angle {
+        Expression::Multiplication(factors) => {
+            Expression::multiplication(factors.get_non_rationals())
+        }
+        _ => angle.clone(),
+    }
+}
+
+/**
+ * Coefficients, from the constant term, of the Chebyshev polynomial of degree n
+ *  - P_0 = 1, P_1 = k * c, P_(n+1) = 2c * P_n - P_(n-1)
+ *  - k = 1 gives the first kind T_n, k = 2 the second kind U_n
+ */
+fn chebyshev(degree: isize, first_coefficient: isize) -> Vec<BigInt> {
+    let mut previous: Vec<BigInt> = vec![BigInt::from(1)];
+    let mut current: Vec<BigInt> = vec![BigInt::from(0), BigInt::from(first_coefficient)];
+    if degree == 0 {
+        return previous;
+    }
+
+    for _ in 1..degree {
+        let mut next: Vec<BigInt> = vec![BigInt::from(0); current.len() + 1];
+        for (power, coefficient) in current.iter().enumerate() {
+            next[power + 1] += coefficient * 2;
+        }
+        for (power, coefficient) in previous.iter().enumerate() {
+            next[power] -= coefficient;
+        }
+        previous = current;
+        current = next;
+    }
+    current
+}
+
+/**
+ * Sum of coefficient * variable ^ power
+ */
+fn polynomial(coefficients: &[BigInt], variable: &Expression) -> Expression {
+    Expression::addition(
+        coefficients
+            .iter()
+            .enumerate()
+            .map(|(power, coefficient)| {
+                Expression::Integer(Symbol {
+                    data: coefficient.clone(),
+                }) * Expression::power(variable.clone(), Symbol::integer(power as isize).expr())
+            })
+            .collect(),
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::parsing::parser::parse;
+
+    #[test]
+    fn expands_double_angles() {
+        assert_eq!(
+            MultipleAngle::apply(&parse("sin(2 * x)")),
+            parse("2 * sin(x) * cos(x)")
+        );
+        assert_eq!(
+            MultipleAngle::apply(&parse("cos(2 * x)")),
+            parse("2 * cos(x)^2 - 1")
+        );
+    }
+
+    #[test]
+    fn expands_through_chebyshev_recurrence() {
+        assert_eq!(
+            MultipleAngle::apply(&parse("cos(3 * x)")),
+            parse("4 * cos(x)^3 - 3 * cos(x)")
+        );
+        assert_eq!(
+            MultipleAngle::apply(&parse("sin(3 * a * b)")),
+            parse("sin(a * b) * (4 * cos(a * b)^2 - 1)")
+        );
+        assert_eq!(
+            MultipleAngle::apply(&parse("cos(4 * x)")),
+            parse("8 * cos(x)^4 - 8 * cos(x)^2 + 1")
+        );
+    }
+
+    #[test]
+    fn keeps_non_integer_multiples() {
+        assert_eq!(
+            MultipleAngle::apply(&parse("sin(x / 2)")),
+            parse("sin(x / 2)")
+        );
+        assert_eq!(MultipleAngle::apply(&parse("cos(x)")), parse("cos(x)"));
+    }
+}
@@ -0,0 +1,73 @@
+use crate::base::{expression::Expression, symbol::Symbol};
+use crate::manipulation::expansion_rules::rule::Rule;
+
+/**
+ * Lowers integer powers of sine and cossine through the squares
+ *      sin(u)^2 == (1 - cos(2u)) / 2
+ *      cos(u)^2 == (1 + cos(2u)) / 2
+ *  - higher powers keep the remaining factor: sin(u)^3 == sin(u) * (1 - cos(2u)) / 2
+ */
+pub struct PowerReduction {}
+impl Rule for PowerReduction {
+    fn apply(expression: &Expression) -> Expression {
+        let power = match expression {
+            Expression::Power(power) => power,
+            _ => return expression.clone(),
+        };
+        let exponent = match power.modifier() {
+            Expression::Integer(n) => match n.value() {
+                Some(exponent) if exponent >= 2 => exponent,
+                _ => return expression.clone(),
+            },
+            _ => return expression.clone(),
+        };
+
+        let base = power.argument();
+        let reduced_square = match &base {
+            Expression::Sine(sine) => {
+                Symbol::integer(1).expr() - Expression::cos(double(&sine.argument()))
+            }
+            Expression::Cossine(cossine) => {
+                Symbol::integer(1).expr() + Expression::cos(double(&cossine.argument()))
+            }
+            _ => return expression.clone(),
+        };
+
+        Expression::multiplication(vec![
+            Symbol::rational(1, 2).expr(),
+            reduced_square,
+            Expression::power(base, Symbol::integer(exponent - 2).expr()),
+        ])
+    }
+}
+
+fn double(angle: &Expression) -> Expression {
+    Symbol::integer(2).expr() * angle.clone()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::parsing::parser::parse;
+
+    #[test]
+    fn reduces_squares() {
+        assert_eq!(
+            PowerReduction::apply(&parse("sin(x)^2")),
+            parse("(1 - cos(2 * x)) / 2")
+        );
+        assert_eq!(
+            PowerReduction::apply(&parse("cos(y)^2")),
+            parse("(1 + cos(2 * y)) / 2")
+        );
+    }
+
+    #[test]
+    fn keeps_remaining_factor() {
+        assert_eq!(
+            PowerReduction::apply(&parse("cos(x)^3")),
+            parse("cos(x) * (1 + cos(2 * x)) / 2")
+        );
+        assert_eq!(PowerReduction::apply(&parse("x^2")), parse("x^2"));
+    }
+}
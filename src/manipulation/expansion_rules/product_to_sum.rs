@@ -0,0 +1,96 @@
+use crate::base::{expression::Expression, symbol::Symbol};
+use crate::manipulation::expansion_rules::rule::Rule;
+
+/**
+ * Rewrites the first product of two sines or cossines as a sum
+ *      sin(a) * sin(b) == (cos(a - b) - cos(a + b)) / 2
+ *      cos(a) * cos(b) == (cos(a - b) + cos(a + b)) / 2
+ *      sin(a) * cos(b) == (sin(a + b) + sin(a - b)) / 2
+ */
+pub struct ProductToSum {}
+impl Rule for ProductToSum {
+    fn apply(expression: &Expression) -> Expression {
+        let factors = match expression {
+            Expression::Multiplication(factors) => factors.items(),
+            _ => return expression.clone(),
+        };
+
+        let ratios: Vec<usize> = factors
+            .iter()
+            .enumerate()
+            .filter(|(_, factor)| matches!(factor, Expression::Sine(_) | Expression::Cossine(_)))
+            .map(|(index, _)| index)
+            .take(2)
+            .collect();
+        if ratios.len() < 2 {
+            return expression.clone();
+        }
+
+        let sum = match (&factors[ratios[0]], &factors[ratios[1]]) {
+            (Expression::Sine(a), Expression::Sine(b)) => {
+                let (sum, difference) = angles(a.argument(), b.argument());
+                Expression::cos(difference) - Expression::cos(sum)
+            }
+            (Expression::Cossine(a), Expression::Cossine(b)) => {
+                let (sum, difference) = angles(a.argument(), b.argument());
+                Expression::cos(difference) + Expression::cos(sum)
+            }
+            (Expression::Sine(a), Expression::Cossine(b))
+            | (Expression::Cossine(b), Expression::Sine(a)) => {
+                let (sum, difference) = angles(a.argument(), b.argument());
+                Expression::sin(sum) + Expression::sin(difference)
+            }
+            _ => return expression.clone(),
+        };
+
+        let mut product: Vec<Expression> = factors
+            .iter()
+            .enumerate()
+            .filter(|(index, _)| !ratios.contains(index))
+            .map(|(_, factor)| factor.clone())
+            .collect();
+        product.push(Symbol::rational(1, 2).expr());
+        product.push(sum);
+        Expression::multiplication(product)
+    }
+}
+
+/**
+ * Sum and difference of the angles, with like terms collected: sin(x) * sin(x) has cos(0)
+ */
+fn angles(a: Expression, b: Expression) -> (Expression, Expression) {
+    (
+        (a.clone() + b.clone()).simplify(),
+        Expression::subtraction(a, b).simplify(),
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::parsing::parser::parse;
+
+    #[test]
+    fn rewrites_products_as_sums() {
+        assert_eq!(
+            ProductToSum::apply(&parse("sin(a) * sin(b)")),
+            parse("(cos(a - b) - cos(a + b)) / 2")
+        );
+        assert_eq!(
+            ProductToSum::apply(&parse("cos(a) * cos(b)")),
+            parse("(cos(a - b) + cos(a + b)) / 2")
+        );
+        assert_eq!(
+            ProductToSum::apply(&parse("2 * x * cos(b) * sin(a)")),
+            parse("x * (sin(a + b) + sin(a - b))")
+        );
+    }
+
+    #[test]
+    fn squares_collapse_to_double_angle() {
+        assert_eq!(
+            ProductToSum::apply(&parse("sin(x) * sin(x)")),
+            parse("(1 - cos(2 * x)) / 2")
+        );
+    }
+}
@@ -5,24 +5,27 @@ impl Expression {
     pub fn comparable(&self, other: &Expression) -> bool {
         match (self - other).simplify() {
             Expression::Integer(_) => return true,
+            Expression::Rational(_) => return true,
             Expression::Real(_) => return true,
             _ => return false,
         }
     }
 
     pub fn equal(&self, other: &Self) -> bool {
-        let simplified_difference = (self - other).simplify();
+        let simplified_difference = (self - other).cancel().simplify();
         match simplified_difference {
             Expression::Integer(n) => return n == Symbol::integer(0),
+            Expression::Rational(_) => return false,
             Expression::Real(r) => return r == Symbol::real(0.0),
             _ => return false,
         }
     }
 
     pub fn not_equal(&self, other: &Self) -> bool {
-        let simplified_difference = (self - other).simplify();
+        let simplified_difference = (self - other).cancel().simplify();
         match simplified_difference {
             Expression::Integer(n) => return n != Symbol::integer(0),
+            Expression::Rational(_) => return true,
             Expression::Real(r) => return r != Symbol::real(0.0),
             _ => return false,
         }
@@ -32,6 +35,7 @@ impl Expression {
         let simplified_difference = (self - other).simplify();
         match simplified_difference {
             Expression::Integer(n) => return n > Symbol::integer(0),
+            Expression::Rational(q) => return !q.is_negative(),
             Expression::Real(r) => return r > Symbol::real(0.0),
             _ => return false,
         }
@@ -41,6 +45,7 @@ impl Expression {
         let simplified_difference = (self - other).simplify();
         match simplified_difference {
             Expression::Integer(n) => return n < Symbol::integer(0),
+            Expression::Rational(q) => return q.is_negative(),
             Expression::Real(r) => return r < Symbol::real(0.0),
             _ => return false,
         }
@@ -50,6 +55,7 @@ impl Expression {
         let simplified_difference = (self - other).simplify();
         match simplified_difference {
             Expression::Integer(n) => return n == Symbol::integer(0) || n > Symbol::integer(0),
+            Expression::Rational(q) => return !q.is_negative(),
             Expression::Real(r) => return r == Symbol::real(0.0) || r > Symbol::real(0.0),
             _ => return false,
         }
@@ -58,6 +64,7 @@ impl Expression {
         let simplified_difference = (self - other).simplify();
         match simplified_difference {
             Expression::Integer(n) => return n == Symbol::integer(0) || n < Symbol::integer(0),
+            Expression::Rational(q) => return q.is_negative(),
             Expression::Real(r) => return r == Symbol::real(0.0) || r < Symbol::real(0.0),
             _ => return false,
         }
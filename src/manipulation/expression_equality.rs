@@ -9,6 +9,8 @@ impl PartialEq for Expression {
     fn eq(&self, other: &Self) -> bool {
         match (self, other) {
             (Expression::Integer(s1), Expression::Integer(s2)) => s1 == s2,
+            (Expression::Rational(s1), Expression::Rational(s2)) => s1 == s2,
+            (Expression::Constant(s1), Expression::Constant(s2)) => s1 == s2,
             (Expression::Real(s1), Expression::Real(s2)) => s1 == s2,
             (Expression::Variable(s1), Expression::Variable(s2)) => s1 == s2,
 
@@ -20,6 +22,21 @@ impl PartialEq for Expression {
 
             (Expression::Sine(a1), Expression::Sine(a2)) => a1 == a2,
             (Expression::Cossine(a1), Expression::Cossine(a2)) => a1 == a2,
+            (Expression::Tangent(a1), Expression::Tangent(a2)) => a1 == a2,
+            (Expression::Cotangent(a1), Expression::Cotangent(a2)) => a1 == a2,
+            (Expression::Secant(a1), Expression::Secant(a2)) => a1 == a2,
+            (Expression::Cossecant(a1), Expression::Cossecant(a2)) => a1 == a2,
+            (Expression::ArcSine(a1), Expression::ArcSine(a2)) => a1 == a2,
+            (Expression::ArcCossine(a1), Expression::ArcCossine(a2)) => a1 == a2,
+            (Expression::ArcTangent(a1), Expression::ArcTangent(a2)) => a1 == a2,
+            (Expression::ArcCotangent(a1), Expression::ArcCotangent(a2)) => a1 == a2,
+            (Expression::ArcSecant(a1), Expression::ArcSecant(a2)) => a1 == a2,
+            (Expression::ArcCossecant(a1), Expression::ArcCossecant(a2)) => a1 == a2,
+            (Expression::RealPart(c1), Expression::RealPart(c2)) => c1 == c2,
+            (Expression::ImaginaryPart(c1), Expression::ImaginaryPart(c2)) => c1 == c2,
+            (Expression::Conjugate(c1), Expression::Conjugate(c2)) => c1 == c2,
+            (Expression::Absolute(c1), Expression::Absolute(c2)) => c1 == c2,
+            (Expression::Argument(c1), Expression::Argument(c2)) => c1 == c2,
             _ => false,
         }
     }
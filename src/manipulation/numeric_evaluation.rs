@@ -1,179 +1,326 @@
+use crate::base::{expression::Expression, symbol::Symbol};
+use num::complex::Complex;
+use std::collections::HashMap;
+
 /**
- * Expression evaluation
+ * Reasons for a failed numeric evaluation
  */
-pub trait NumericEvaluable {
-    fn into_num(&self) -> Result<f64, Expression>;
+#[derive(Debug, Clone, PartialEq)]
+pub enum EvalError {
+    /* variable without a value in the binding environment */
+    UnboundVariable(String),
+    /* log(argument, base) with argument <= 0 */
+    NonPositiveLogarithm(f64),
+    /* log(argument, base) with base <= 0 or base == 1 */
+    InvalidLogarithmBase(f64),
+    /* 0 ^ exponent with exponent < 0 */
+    DivisionByZero,
+    /* base ^ exponent with base < 0 and non integer exponent */
+    ComplexPower { base: f64, exponent: f64 },
+    /* constant without real value, as the imaginary unit */
+    NonRealConstant(String),
+    /* inverse trigonometric outside of its domain, as asin(2) */
+    OutOfDomain { function: String, argument: f64 },
 }
 
-// =================================== //
-//      Recursion on Expression        //
-// =================================== //
-use crate::base::{
-    associative_operation::AssociativeOperation, commutative_association::CommutativeAssociation,
-    expression::Expression, symbol::Symbol,
-};
-impl NumericEvaluable for Expression {
-    fn into_num(&self) -> Result<f64, Expression> {
+impl std::fmt::Display for EvalError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            Expression::Symbol(symbol) => symbol.into_num(),
-            Expression::Operation(op) => op.into_num(),
-            Expression::Association(association) => association.into_num(),
-            Expression::AssociativeOperation(op) => op.into_num(),
-            Expression::CommutativeAssociation(op) => op.into_num(),
+            EvalError::UnboundVariable(name) => write!(f, "unbound variable '{}'", name),
+            EvalError::NonPositiveLogarithm(argument) => {
+                write!(f, "logarithm of non positive value {}", argument)
+            }
+            EvalError::InvalidLogarithmBase(base) => {
+                write!(f, "invalid logarithm base {}", base)
+            }
+            EvalError::DivisionByZero => write!(f, "division by zero"),
+            EvalError::ComplexPower { base, exponent } => write!(
+                f,
+                "power of negative base {} to non integer exponent {}",
+                base, exponent
+            ),
+            EvalError::NonRealConstant(label) => write!(f, "constant '{}' is not real", label),
+            EvalError::OutOfDomain { function, argument } => {
+                write!(f, "{} is undefined at {}", function, argument)
+            }
         }
     }
 }
 
-// =============================== //
-//              Symbols            //
-// =============================== //
-use crate::symbols::{constant::Constant, integer::Integer, number::Number, variable::Variable};
+impl std::error::Error for EvalError {}
 
-impl NumericEvaluable for Constant {
-    fn into_num(&self) -> Result<f64, Expression> {
-        match self.value() {
-            Some(value) => return Ok(value),
-            None => return Err(Expression::Symbol(Box::new(self.clone()))),
-        }
-    }
-}
+// =================================== //
+//      Recursion on Expression        //
+// =================================== //
+impl Expression {
+    /**
+     * Evaluates the expression to a floating point value
+     *  - variables are looked up by label in the binding environment
+     */
+    pub fn evaluate(&self, env: &HashMap<String, f64>) -> Result<f64, EvalError> {
+        match self {
+            /* Symbols */
+            Expression::Integer(n) => Ok(num::traits::ToPrimitive::to_f64(&n.data).unwrap()),
+            Expression::Rational(q) => Ok(q.value().unwrap()),
+            Expression::Real(r) => Ok(r.data),
+            Expression::Constant(c) => match c.value() {
+                Some(value) => Ok(value),
+                None => Err(EvalError::NonRealConstant(c.label())),
+            },
+            Expression::Variable(v) => match env.get(&v.data) {
+                Some(value) => Ok(*value),
+                None => Err(EvalError::UnboundVariable(v.label())),
+            },
 
-impl NumericEvaluable for Number {
-    fn into_num(&self) -> Result<f64, Expression> {
-        Ok(self.value().expect("Expected number to hold a f64 value"))
-    }
-}
+            /* Commutative associations */
+            Expression::Addition(addends) => addends
+                .items()
+                .iter()
+                .try_fold(0.0, |sum, addend| Ok(sum + addend.evaluate(env)?)),
+            Expression::Multiplication(factors) => factors
+                .items()
+                .iter()
+                .try_fold(1.0, |product, factor| Ok(product * factor.evaluate(env)?)),
 
-impl NumericEvaluable for Integer {
-    fn into_num(&self) -> Result<f64, Expression> {
-        Ok(self.value().expect("Expected number to hold a f64 value"))
-    }
-}
+            /* Exponentials */
+            Expression::Power(power) => {
+                let base = power.argument().evaluate(env)?;
+                let exponent = power.modifier().evaluate(env)?;
 
-impl NumericEvaluable for Variable {
-    fn into_num(&self) -> Result<f64, Expression> {
-        match self.value() {
-            Some(value) => return Ok(value),
-            None => return Err(Expression::Symbol(Box::new(self.clone()))),
-        }
-    }
-}
+                if base == 0.0 && exponent < 0.0 {
+                    return Err(EvalError::DivisionByZero);
+                }
+                if base < 0.0 && exponent.fract() != 0.0 {
+                    return Err(EvalError::ComplexPower { base, exponent });
+                }
 
-// =================================== //
-//              Arithmetics            //
-// =================================== //
-use crate::arithmetics::{addition::Addition, multiplication::Multiplication};
+                Ok(base.powf(exponent))
+            }
+            Expression::Logarithm(log) => {
+                let argument = log.argument().evaluate(env)?;
+                let base = log.modifier().evaluate(env)?;
 
-impl NumericEvaluable for Addition {
-    fn into_num(&self) -> Result<f64, Expression> {
-        let results: Vec<Result<f64, Expression>> = self
-            .items()
-            .iter()
-            .map(|item| item.into_num()) /* Recursion: numeric evaluation */
-            .collect();
+                if base <= 0.0 || base == 1.0 {
+                    return Err(EvalError::InvalidLogarithmBase(base));
+                }
+                if argument <= 0.0 {
+                    return Err(EvalError::NonPositiveLogarithm(argument));
+                }
 
-        for res in results.iter() {
-            if res.is_err() {
-                return res.clone();
+                Ok(argument.ln() / base.ln())
             }
-        }
 
-        return Ok(results
-            .iter()
-            .cloned()
-            .map(|res| res.unwrap())
-            .fold(0.0, |acc, new| acc + new));
-    }
-}
-
-impl NumericEvaluable for Multiplication {
-    fn into_num(&self) -> Result<f64, Expression> {
-        let results: Vec<Result<f64, Expression>> = self
-            .items()
-            .iter()
-            .map(|item| item.into_num()) /* Recursion: numeric evaluation */
-            .collect();
+            /* Trigonometrics */
+            Expression::Sine(sine) => Ok(sine.argument().evaluate(env)?.sin()),
+            Expression::Cossine(cossine) => Ok(cossine.argument().evaluate(env)?.cos()),
+            Expression::Tangent(tangent) => {
+                let angle = tangent.argument().evaluate(env)?;
+                reciprocal(angle.cos(), angle).map(|inverse| angle.sin() * inverse)
+            }
+            Expression::Cotangent(cotangent) => {
+                let angle = cotangent.argument().evaluate(env)?;
+                reciprocal(angle.sin(), angle).map(|inverse| angle.cos() * inverse)
+            }
+            Expression::Secant(secant) => {
+                let angle = secant.argument().evaluate(env)?;
+                reciprocal(angle.cos(), angle)
+            }
+            Expression::Cossecant(cossecant) => {
+                let angle = cossecant.argument().evaluate(env)?;
+                reciprocal(angle.sin(), angle)
+            }
 
-        for res in results.iter() {
-            if res.is_err() {
-                return res.clone();
+            /* Inverse trigonometrics */
+            Expression::ArcSine(arc) => {
+                let value = bounded("asin", arc.argument().evaluate(env)?)?;
+                Ok(value.asin())
+            }
+            Expression::ArcCossine(arc) => {
+                let value = bounded("acos", arc.argument().evaluate(env)?)?;
+                Ok(value.acos())
+            }
+            Expression::ArcTangent(arc) => Ok(arc.argument().evaluate(env)?.atan()),
+            Expression::ArcCotangent(arc) => {
+                let value = arc.argument().evaluate(env)?;
+                Ok(std::f64::consts::FRAC_PI_2 - value.atan())
+            }
+            Expression::ArcSecant(arc) => {
+                let value = unbounded("asec", arc.argument().evaluate(env)?)?;
+                Ok(value.recip().acos())
+            }
+            Expression::ArcCossecant(arc) => {
+                let value = unbounded("acsc", arc.argument().evaluate(env)?)?;
+                Ok(value.recip().asin())
             }
-        }
 
-        return Ok(results
-            .iter()
-            .cloned()
-            .map(|res| res.unwrap())
-            .fold(1.0, |acc, new| acc * new));
+            /* Complex parts of complex values */
+            Expression::RealPart(part) => Ok(part.argument().evaluate_complex(env)?.re),
+            Expression::ImaginaryPart(part) => Ok(part.argument().evaluate_complex(env)?.im),
+            Expression::Conjugate(conjugate) => conjugate.argument().evaluate(env),
+            Expression::Absolute(absolute) => Ok(absolute.argument().evaluate_complex(env)?.norm()),
+            Expression::Argument(argument) => Ok(argument.argument().evaluate_complex(env)?.arg()),
+        }
     }
-}
 
-// =================================== //
-//              Exponential            //
-// =================================== //
-use crate::exponential::power::Power;
-impl NumericEvaluable for Power {
-    fn into_num(&self) -> Result<f64, Expression> {
-        let base = self.argument().into_num();
-        let exp = self.modifier().into_num();
-
-        if base.is_ok() && exp.is_ok() {
-            return Ok(base.unwrap().powf(exp.unwrap()));
-        }
+    /**
+     * Evaluates the expression to a complex floating point value
+     *  - variables are real, looked up by label in the binding environment
+     *  - results are principal values: (-1) ^ 0.5 == i, ln(-1) == i pi
+     */
+    pub fn evaluate_complex(&self, env: &HashMap<String, f64>) -> Result<Complex<f64>, EvalError> {
+        match self {
+            /* Symbols */
+            Expression::Constant(c) if c == &Symbol::i() => Ok(Complex::i()),
+            Expression::Integer(_)
+            | Expression::Rational(_)
+            | Expression::Real(_)
+            | Expression::Constant(_)
+            | Expression::Variable(_) => Ok(Complex::from(self.evaluate(env)?)),
 
-        if base.is_err() {
-            return base;
-        } else {
-            return exp;
-        }
-    }
-}
+            /* Commutative associations */
+            Expression::Addition(addends) => addends
+                .items()
+                .iter()
+                .try_fold(Complex::from(0.0), |sum, addend| {
+                    Ok(sum + addend.evaluate_complex(env)?)
+                }),
+            Expression::Multiplication(factors) => factors
+                .items()
+                .iter()
+                .try_fold(Complex::from(1.0), |product, factor| {
+                    Ok(product * factor.evaluate_complex(env)?)
+                }),
 
-use crate::exponential::logarithm::Log;
-impl NumericEvaluable for Log {
-    fn into_num(&self) -> Result<f64, Expression> {
-        let argument = self.argument().into_num();
-        let base = self.modifier().into_num();
+            /* Exponentials */
+            Expression::Power(power) => {
+                let base = power.argument().evaluate_complex(env)?;
+                let exponent = power.modifier().evaluate_complex(env)?;
 
-        if argument.is_ok() && base.is_ok() {
-            return Ok(argument.unwrap().log(base.unwrap()));
-        }
+                if base == Complex::from(0.0) {
+                    if exponent == Complex::from(0.0) {
+                        return Ok(Complex::from(1.0));
+                    }
+                    if exponent.re <= 0.0 {
+                        return Err(EvalError::DivisionByZero);
+                    }
+                    return Ok(base);
+                }
+                if exponent.im == 0.0 && exponent.re.fract() == 0.0 && exponent.re.abs() < 1e9 {
+                    return Ok(base.powi(exponent.re as i32));
+                }
 
-        if argument.is_err() {
-            return argument;
-        } else {
-            return base;
-        }
-    }
-}
+                Ok(base.powc(exponent))
+            }
+            Expression::Logarithm(log) => {
+                let argument = log.argument().evaluate_complex(env)?;
+                let base = log.modifier().evaluate_complex(env)?;
+
+                if base == Complex::from(0.0) || base == Complex::from(1.0) {
+                    return Err(EvalError::InvalidLogarithmBase(base.re));
+                }
+                if argument == Complex::from(0.0) {
+                    return Err(EvalError::NonPositiveLogarithm(0.0));
+                }
 
-// ============================== //
-//         Trigonometrics         //
-// ============================== //
-use crate::base::operation::Operation;
+                Ok(argument.ln() / base.ln())
+            }
 
-use crate::trigonometrics::sine::Sin;
-impl NumericEvaluable for Sin {
-    fn into_num(&self) -> Result<f64, Expression> {
-        let angle = self.argument().into_num();
+            /* Trigonometrics */
+            Expression::Sine(sine) => Ok(sine.argument().evaluate_complex(env)?.sin()),
+            Expression::Cossine(cossine) => Ok(cossine.argument().evaluate_complex(env)?.cos()),
+            Expression::Tangent(tangent) => {
+                let angle = tangent.argument().evaluate_complex(env)?;
+                complex_reciprocal(angle.cos()).map(|inverse| angle.sin() * inverse)
+            }
+            Expression::Cotangent(cotangent) => {
+                let angle = cotangent.argument().evaluate_complex(env)?;
+                complex_reciprocal(angle.sin()).map(|inverse| angle.cos() * inverse)
+            }
+            Expression::Secant(secant) => {
+                complex_reciprocal(secant.argument().evaluate_complex(env)?.cos())
+            }
+            Expression::Cossecant(cossecant) => {
+                complex_reciprocal(cossecant.argument().evaluate_complex(env)?.sin())
+            }
 
-        if angle.is_ok() {
-            return Ok(angle.unwrap().sin());
+            /* Inverse trigonometrics */
+            Expression::ArcSine(arc) => Ok(arc.argument().evaluate_complex(env)?.asin()),
+            Expression::ArcCossine(arc) => Ok(arc.argument().evaluate_complex(env)?.acos()),
+            Expression::ArcTangent(arc) => Ok(arc.argument().evaluate_complex(env)?.atan()),
+            Expression::ArcCotangent(arc) => {
+                let value = arc.argument().evaluate_complex(env)?;
+                Ok(Complex::from(std::f64::consts::FRAC_PI_2) - value.atan())
+            }
+            Expression::ArcSecant(arc) => {
+                Ok(complex_reciprocal(arc.argument().evaluate_complex(env)?)?.acos())
+            }
+            Expression::ArcCossecant(arc) => {
+                Ok(complex_reciprocal(arc.argument().evaluate_complex(env)?)?.asin())
+            }
+
+            /* Complex */
+            Expression::RealPart(part) => {
+                Ok(Complex::from(part.argument().evaluate_complex(env)?.re))
+            }
+            Expression::ImaginaryPart(part) => {
+                Ok(Complex::from(part.argument().evaluate_complex(env)?.im))
+            }
+            Expression::Conjugate(conjugate) => {
+                Ok(conjugate.argument().evaluate_complex(env)?.conj())
+            }
+            Expression::Absolute(absolute) => Ok(Complex::from(
+                absolute.argument().evaluate_complex(env)?.norm(),
+            )),
+            Expression::Argument(argument) => Ok(Complex::from(
+                argument.argument().evaluate_complex(env)?.arg(),
+            )),
         }
+    }
+}
 
-        return angle;
+/**
+ * Complex multiplicative inverse, failing on zero
+ */
+fn complex_reciprocal(value: Complex<f64>) -> Result<Complex<f64>, EvalError> {
+    if value == Complex::from(0.0) {
+        return Err(EvalError::DivisionByZero);
     }
+    Ok(value.inv())
 }
 
-use crate::trigonometrics::cossine::Cos;
-impl NumericEvaluable for Cos {
-    fn into_num(&self) -> Result<f64, Expression> {
-        let angle = self.argument().into_num();
+/**
+ * Multiplicative inverse of the sine or cossine of an angle, failing on zero
+ *  - values within the rounding of the angle are zero: cos(pi / 2) != 0.0 in floating point
+ */
+fn reciprocal(value: f64, angle: f64) -> Result<f64, EvalError> {
+    if value.abs() <= 2.0 * f64::EPSILON * angle.abs().max(1.0) {
+        return Err(EvalError::DivisionByZero);
+    }
+    Ok(value.recip())
+}
 
-        if angle.is_ok() {
-            return Ok(angle.unwrap().cos());
-        }
+/**
+ * Domain of asin and acos: [-1, 1]
+ */
+fn bounded(function: &str, value: f64) -> Result<f64, EvalError> {
+    if value.abs() > 1.0 {
+        return Err(EvalError::OutOfDomain {
+            function: String::from(function),
+            argument: value,
+        });
+    }
+    Ok(value)
+}
 
-        return angle;
+/**
+ * Domain of asec and acsc: outside of (-1, 1)
+ */
+fn unbounded(function: &str, value: f64) -> Result<f64, EvalError> {
+    if value.abs() < 1.0 {
+        return Err(EvalError::OutOfDomain {
+            function: String::from(function),
+            argument: value,
+        });
     }
+    Ok(value)
 }
@@ -0,0 +1,174 @@
+#[cfg(test)]
+mod evaluate {
+    use crate::base::{expression::Expression, symbol::Symbol};
+    use std::collections::HashMap;
+
+    fn environment(bindings: &[(&str, f64)]) -> HashMap<String, f64> {
+        bindings
+            .iter()
+            .map(|(name, value)| (String::from(*name), *value))
+            .collect()
+    }
+
+    #[test]
+    fn symbols() {
+        let env = environment(&[("x", 2.5)]);
+
+        assert_eq!(Symbol::integer(3).expr().evaluate(&env), Ok(3.0));
+        assert_eq!(Symbol::real(1.5).expr().evaluate(&env), Ok(1.5));
+        assert_eq!(Symbol::variable("x").expr().evaluate(&env), Ok(2.5));
+    }
+
+    #[test]
+    fn arithmetics() {
+        let env = environment(&[("x", 2.0), ("y", 3.0)]);
+        let fx = Expression::parse("x^2 + 3*x*y - y/x").unwrap();
+
+        assert_eq!(fx.evaluate(&env), Ok(4.0 + 18.0 - 1.5));
+    }
+
+    #[test]
+    fn exponentials() {
+        let env = environment(&[("x", 8.0)]);
+
+        assert_eq!(
+            Expression::parse("log(x, 2)").unwrap().evaluate(&env),
+            Ok(3.0)
+        );
+        assert_eq!(
+            Expression::parse("x^(1/3)").unwrap().evaluate(&env),
+            Ok(2.0)
+        );
+        assert_eq!(
+            Expression::parse("(-x)^3").unwrap().evaluate(&env),
+            Ok(-512.0)
+        );
+    }
+
+    #[test]
+    fn trigonometrics() {
+        let env = environment(&[("x", 0.5)]);
+        let fx = Expression::parse("sin(x)^2 + cos(x)^2").unwrap();
+
+        assert!((fx.evaluate(&env).unwrap() - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn trigonometric_ratios_and_inverses() {
+        let env = environment(&[("x", 0.5)]);
+        let evaluate = |input: &str| Expression::parse(input).unwrap().evaluate(&env).unwrap();
+
+        assert!((evaluate("tan(x) * cot(x)") - 1.0).abs() < 1e-12);
+        assert!((evaluate("sec(x)^2 - tan(x)^2") - 1.0).abs() < 1e-12);
+        assert!((evaluate("csc(x)") - 1.0 / 0.5f64.sin()).abs() < 1e-12);
+        assert!((evaluate("asin(x) + acos(x)") - std::f64::consts::FRAC_PI_2).abs() < 1e-12);
+        assert!((evaluate("atan(x) + acot(x)") - std::f64::consts::FRAC_PI_2).abs() < 1e-12);
+        assert!((evaluate("asec(1/x)") - 0.5f64.acos()).abs() < 1e-12);
+        assert!((evaluate("acsc(1/x)") - 0.5f64.asin()).abs() < 1e-12);
+        assert!((evaluate("pi") - std::f64::consts::PI).abs() < 1e-12);
+    }
+}
+
+#[cfg(test)]
+mod errors {
+    use crate::base::expression::Expression;
+    use crate::manipulation::numeric_evaluation::EvalError;
+    use std::collections::HashMap;
+
+    #[test]
+    fn names_unbound_variable() {
+        let mut env: HashMap<String, f64> = HashMap::new();
+        env.insert(String::from("x"), 1.0);
+
+        assert_eq!(
+            Expression::parse("x + sin(y)").unwrap().evaluate(&env),
+            Err(EvalError::UnboundVariable(String::from("y")))
+        );
+    }
+
+    #[test]
+    fn logarithm_domain() {
+        let mut env: HashMap<String, f64> = HashMap::new();
+        env.insert(String::from("x"), -2.0);
+
+        assert_eq!(
+            Expression::parse("log(x, 10)").unwrap().evaluate(&env),
+            Err(EvalError::NonPositiveLogarithm(-2.0))
+        );
+        assert_eq!(
+            Expression::parse("log(10, x + 3)").unwrap().evaluate(&env),
+            Err(EvalError::InvalidLogarithmBase(1.0))
+        );
+    }
+
+    #[test]
+    fn power_domain() {
+        let mut env: HashMap<String, f64> = HashMap::new();
+        env.insert(String::from("x"), 0.0);
+
+        assert_eq!(
+            Expression::parse("x^-1").unwrap().evaluate(&env),
+            Err(EvalError::DivisionByZero)
+        );
+        assert_eq!(
+            Expression::parse("0^-1").unwrap().evaluate(&env),
+            Err(EvalError::DivisionByZero)
+        );
+        assert_eq!(
+            Expression::parse("(x - 4)^0.5").unwrap().evaluate(&env),
+            Err(EvalError::ComplexPower {
+                base: -4.0,
+                exponent: 0.5
+            })
+        );
+    }
+
+    #[test]
+    fn trigonometric_poles() {
+        let mut env: HashMap<String, f64> = HashMap::new();
+        env.insert(String::from("x"), 5.0 * std::f64::consts::FRAC_PI_2);
+        env.insert(String::from("y"), 3.0 * std::f64::consts::PI);
+
+        assert_eq!(
+            Expression::parse("tan(pi/2)").unwrap().evaluate(&env),
+            Err(EvalError::DivisionByZero)
+        );
+        assert_eq!(
+            Expression::parse("sec(x)").unwrap().evaluate(&env),
+            Err(EvalError::DivisionByZero)
+        );
+        assert_eq!(
+            Expression::parse("cot(y)").unwrap().evaluate(&env),
+            Err(EvalError::DivisionByZero)
+        );
+        assert!(Expression::parse("tan(x - 0.001)")
+            .unwrap()
+            .evaluate(&env)
+            .is_ok());
+    }
+
+    #[test]
+    fn trigonometric_domain() {
+        let mut env: HashMap<String, f64> = HashMap::new();
+        env.insert(String::from("x"), 0.0);
+
+        assert_eq!(
+            Expression::parse("cot(x)").unwrap().evaluate(&env),
+            Err(EvalError::DivisionByZero)
+        );
+        assert_eq!(
+            Expression::parse("asin(x + 2)").unwrap().evaluate(&env),
+            Err(EvalError::OutOfDomain {
+                function: String::from("asin"),
+                argument: 2.0
+            })
+        );
+        assert_eq!(
+            Expression::parse("asec(x + 0.5)").unwrap().evaluate(&env),
+            Err(EvalError::OutOfDomain {
+                function: String::from("asec"),
+                argument: 0.5
+            })
+        );
+    }
+}
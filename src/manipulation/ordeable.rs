@@ -10,6 +10,8 @@ impl Expression {
         match self {
             Expression::Real(_) => 1010,
             Expression::Integer(_) => 1020,
+            Expression::Rational(_) => 1025,
+            Expression::Constant(_) => 1028,
             Expression::Variable(_) => 1030,
 
             Expression::Power(_) => 2010,
@@ -17,6 +19,23 @@ impl Expression {
 
             Expression::Sine(_) => 4010,
             Expression::Cossine(_) => 4020,
+            Expression::Tangent(_) => 4030,
+            Expression::Cotangent(_) => 4040,
+            Expression::Secant(_) => 4050,
+            Expression::Cossecant(_) => 4060,
+
+            Expression::ArcSine(_) => 4110,
+            Expression::ArcCossine(_) => 4120,
+            Expression::ArcTangent(_) => 4130,
+            Expression::ArcCotangent(_) => 4140,
+            Expression::ArcSecant(_) => 4150,
+            Expression::ArcCossecant(_) => 4160,
+
+            Expression::RealPart(_) => 5010,
+            Expression::ImaginaryPart(_) => 5020,
+            Expression::Conjugate(_) => 5030,
+            Expression::Absolute(_) => 5040,
+            Expression::Argument(_) => 5050,
 
             Expression::Multiplication(_) => 6010,
             Expression::Addition(_) => 6020,
@@ -37,6 +56,23 @@ impl Ord for Expression {
             /* Trigonometrics */
             (Expression::Sine(a1), Expression::Sine(a2)) => return a1.cmp(&a2),
             (Expression::Cossine(a1), Expression::Cossine(a2)) => return a1.cmp(&a2),
+            (Expression::Tangent(a1), Expression::Tangent(a2)) => return a1.cmp(&a2),
+            (Expression::Cotangent(a1), Expression::Cotangent(a2)) => return a1.cmp(&a2),
+            (Expression::Secant(a1), Expression::Secant(a2)) => return a1.cmp(&a2),
+            (Expression::Cossecant(a1), Expression::Cossecant(a2)) => return a1.cmp(&a2),
+            (Expression::ArcSine(a1), Expression::ArcSine(a2)) => return a1.cmp(&a2),
+            (Expression::ArcCossine(a1), Expression::ArcCossine(a2)) => return a1.cmp(&a2),
+            (Expression::ArcTangent(a1), Expression::ArcTangent(a2)) => return a1.cmp(&a2),
+            (Expression::ArcCotangent(a1), Expression::ArcCotangent(a2)) => return a1.cmp(&a2),
+            (Expression::ArcSecant(a1), Expression::ArcSecant(a2)) => return a1.cmp(&a2),
+            (Expression::ArcCossecant(a1), Expression::ArcCossecant(a2)) => return a1.cmp(&a2),
+
+            /* Complex */
+            (Expression::RealPart(c1), Expression::RealPart(c2)) => return c1.cmp(&c2),
+            (Expression::ImaginaryPart(c1), Expression::ImaginaryPart(c2)) => return c1.cmp(&c2),
+            (Expression::Conjugate(c1), Expression::Conjugate(c2)) => return c1.cmp(&c2),
+            (Expression::Absolute(c1), Expression::Absolute(c2)) => return c1.cmp(&c2),
+            (Expression::Argument(c1), Expression::Argument(c2)) => return c1.cmp(&c2),
 
             /* Commutative Associations */
             (Expression::Multiplication(m1), Expression::Multiplication(m2)) => return m1.cmp(&m2),
@@ -45,6 +81,8 @@ impl Ord for Expression {
             /* Symbols */
             (Expression::Variable(s1), Expression::Variable(s2)) => return s1.cmp(&s2),
             (Expression::Integer(s1), Expression::Integer(s2)) => return s1.cmp(&s2),
+            (Expression::Rational(s1), Expression::Rational(s2)) => return s1.cmp(&s2),
+            (Expression::Constant(s1), Expression::Constant(s2)) => return s1.cmp(&s2),
             (Expression::Real(s1), Expression::Real(s2)) => return s1.cmp(&s2),
 
             _ => self.id().cmp(&other.id()),
@@ -1,273 +1,381 @@
-use crate::base::expression::Expression;
-use crate::symbols::variable::Variable;
-
-pub struct Match {
-    /* test expression: variable marks pattern matching ocurence */
-    pub expression: Expression,
-
-    /*
-        Variable is build as Expression,
-        but it should match Indentity::Variable to facilitate latter substitution
-    */
-    pub variable: Expression,
+use crate::base::{expression::Expression, symbol::Symbol};
+use crate::parsing::parser::ParseError;
+use std::collections::HashMap;
+
+/**
+ * Largest count of items a wildcard splits among itself and later wildcards
+ *  - splits grow as 2^n, so larger associations do not match such patterns
+ */
+pub const SPLIT_LIMIT: usize = 16;
+
+/**
+ * Values captured by the wildcards of a successful match, keyed by wildcard label
+ */
+pub type Bindings = HashMap<String, Expression>;
+
+/**
+ * Restrictions on what a wildcard may capture
+ */
+#[derive(Debug, Clone, PartialEq)]
+pub enum Constraint {
+    Integer,
+    Rational,
+    Real,
+    FreeOf(Symbol<String>),
 }
 
-pub trait PatternMatchable {
-    /**
-     *  Implementation should return the equivalent expression
-     *  with a variable placed where pattern match happens
-     */
-    fn matches(&self, pattern: &Expression, variable: &String) -> Option<Match>;
-}
-
-
-// =================================== //
-//      Recursion on Expression        //
-// =================================== //
-impl PatternMatchable for Expression {
-    fn matches(&self, pattern: &Expression, label: &String) -> Option<Match> {
-        if self == pattern {
-            return Some(Match {
-                expression: self.clone(),
-                variable: Variable::new(String::from("P")),
-            });
-        }
-
+impl Constraint {
+    pub fn accepts(&self, expression: &Expression) -> bool {
         match self {
-            Expression::Symbol(_) => return None, /* not meant to occur */
-            Expression::Operation(op) => return op.matches(pattern, label),
-            Expression::Association(a) => return a.matches(pattern, label),
-            Expression::AssociativeOperation(op) => return op.matches(pattern, label),
-            Expression::CommutativeAssociation(a) => return a.matches(pattern, label),
+            Constraint::Integer => matches!(expression, Expression::Integer(_)),
+            Constraint::Rational => {
+                matches!(expression, Expression::Integer(_) | Expression::Rational(_))
+            }
+            Constraint::Real => matches!(expression, Expression::Real(_)),
+            Constraint::FreeOf(variable) => expression.is_free_of(variable),
         }
     }
 }
 
-use crate::base::commutative_association::CommutativeAssociation;
-use crate::manipulation::identifiable::{Identifiable, Identity};
-
-
-// ====================== //
-//       Arithmetics      //
-// ====================== //
-use crate::arithmetics::addition::Addition;
-impl PatternMatchable for Addition {
-    fn matches(&self, pattern: &Expression, label: &String) -> Option<Match> {
-        if pattern.id() == Identity::Addition {
-            if let Expression::CommutativeAssociation(additive_pattern) = pattern {
-                let mut pattern_addends = additive_pattern.items();
-                let mut match_addends: Vec<Expression> = Vec::new();
-
-                for self_addend in self.items().iter() {
-                    if let Some(index) = pattern_addends.iter().position(|item| item == self_addend)
-                    {
-                        pattern_addends.remove(index);
-                    } else if let Some(matching) = self_addend.matches(pattern, label) {
-                        match_addends.push(matching.expression);
-                    } else {
-                        match_addends.push(self_addend.clone());
-                    }
-                }
+/**
+ * Expression template where variables labeled with a leading underscore (`_a`) are wildcards
+ *  - additions and multiplications match up to reordering of their items
+ *  - wildcard items of an association split the unmatched items among themselves: each takes
+ *    a non empty sub-multiset, the last one takes the rest, or the neutral element
+ *  - splits among several wildcards are limited to `SPLIT_LIMIT` unmatched items
+ *  - the template is not normalised again: build it with raw variants when constructors would
+ *    collapse it, as in `_b ^ log(_x, _b)`
+ */
+#[derive(Debug, Clone)]
+pub struct Pattern {
+    template: Expression,
+    constraints: HashMap<String, Vec<Constraint>>,
+}
 
-                if pattern_addends.is_empty() {
-                    match_addends.push(Variable::new(label.clone()));
-                    return Some(Match {
-                        expression: Addition::new(match_addends),
-                        variable: Variable::new(label.clone()),
-                    });
-                }
-            }
+impl Pattern {
+    pub fn new(template: Expression) -> Self {
+        Self {
+            template,
+            constraints: HashMap::new(),
         }
+    }
 
-        let mut found_match: bool = false;
-        let mut addends: Vec<Expression> = Vec::new();
+    pub fn parse(template: &str) -> Result<Self, ParseError> {
+        Ok(Self::new(Expression::parse(template)?))
+    }
 
-        for addend in self.items().iter() {
-            if let Some(matching) = addend.matches(pattern, label) {
-                found_match = true;
-                addends.push(matching.expression);
-            } else {
-                addends.push(addend.clone());
-            }
-        }
+    /**
+     * Adds a constraint to the wildcard with given label (`"_a"`)
+     */
+    pub fn constrain(mut self, wildcard: &str, constraint: Constraint) -> Self {
+        self.constraints
+            .entry(String::from(wildcard))
+            .or_default()
+            .push(constraint);
+        self
+    }
 
-        if found_match {
-            return Some(Match {
-                expression: Addition::new(addends),
-                variable: Variable::new(label.clone()),
-            });
-        }
+    pub fn template(&self) -> Expression {
+        self.template.clone()
+    }
 
-        return None;
+    /**
+     * All distinct binding sets that turn the template into the expression
+     *  - `_a + _b` against `x + y + z` yields every split, as `_a: x + y, _b: z`
+     *  - no split is tried past `SPLIT_LIMIT` unmatched items
+     */
+    pub fn matches(&self, expression: &Expression) -> Vec<Bindings> {
+        self.match_node(&self.template, expression, &Bindings::new())
     }
-}
 
-use crate::arithmetics::multiplication::Multiplication;
-impl PatternMatchable for Multiplication {
-    fn matches(&self, pattern: &Expression, label: &String) -> Option<Match> {
-        if pattern.id() == Identity::Multiplication {
-            if let Expression::CommutativeAssociation(multiplicative_patern) = pattern {
-                let mut pattern_factors = multiplicative_patern.items();
-                let mut match_factors: Vec<Expression> = Vec::new();
-
-                for self_factor in self.items().iter() {
-                    if let Some(index) = pattern_factors.iter().position(|item| item == self_factor)
-                    {
-                        pattern_factors.remove(index);
-                    } else if let Some(matching) = self_factor.matches(pattern, label) {
-                        match_factors.push(matching.expression);
-                    } else {
-                        match_factors.push(self_factor.clone());
-                    }
-                }
+    pub fn is_match(&self, expression: &Expression) -> bool {
+        !self.matches(expression).is_empty()
+    }
 
-                if pattern_factors.is_empty() {
-                    match_factors.push(Variable::new(label.clone()));
-                    return Some(Match {
-                        expression: Addition::new(match_factors),
-                        variable: Variable::new(label.clone()),
-                    });
-                }
-            }
-        }
+    /**
+     * Instantiates the replacement with the first binding set matching the expression
+     */
+    pub fn rewrite(&self, expression: &Expression, replacement: &Expression) -> Option<Expression> {
+        self.matches(expression)
+            .first()
+            .map(|bindings| Pattern::instantiate(replacement, bindings))
+    }
 
-        let mut found_match: bool = false;
-        let mut factors: Vec<Expression> = Vec::new();
+    /**
+     * Replaces the wildcards of a template by their bound values
+     */
+    pub fn instantiate(template: &Expression, bindings: &Bindings) -> Expression {
+        template.substitute_all(
+            &bindings
+                .iter()
+                .map(|(label, value)| (Symbol::variable(label).expr(), value.clone()))
+                .collect(),
+        )
+    }
+
+    fn wildcard(expression: &Expression) -> Option<String> {
+        match expression {
+            Expression::Variable(v) if v.data.starts_with('_') => Some(v.label()),
+            _ => None,
+        }
+    }
 
-        for factor in self.items().iter() {
-            if let Some(matching) = factor.matches(pattern, label) {
-                found_match = true;
-                factors.push(matching.expression);
+    fn bind(&self, label: &str, value: Expression, bindings: &Bindings) -> Vec<Bindings> {
+        if let Some(bound) = bindings.get(label) {
+            return if bound == &value {
+                vec![bindings.clone()]
             } else {
-                factors.push(factor.clone());
-            }
+                vec![]
+            };
         }
 
-        if found_match {
-            return Some(Match {
-                expression: Addition::new(factors),
-                variable: Variable::new(label.clone()),
-            });
+        if let Some(constraints) = self.constraints.get(label) {
+            if !constraints
+                .iter()
+                .all(|constraint| constraint.accepts(&value))
+            {
+                return vec![];
+            }
         }
 
-        return None;
+        let mut extended = bindings.clone();
+        extended.insert(String::from(label), value);
+        vec![extended]
     }
-}
 
+    fn match_node(
+        &self,
+        pattern: &Expression,
+        expression: &Expression,
+        bindings: &Bindings,
+    ) -> Vec<Bindings> {
+        if let Some(label) = Self::wildcard(pattern) {
+            return self.bind(&label, expression.clone(), bindings);
+        }
 
-// ====================== //
-//       Exponential      //
-// ====================== //
-use crate::base::associative_operation::AssociativeOperation;
-use crate::exponential::power::Power;
-impl PatternMatchable for Power {
-    fn matches(&self, pattern: &Expression, label: &String) -> Option<Match> {
-        let mut found_match: bool = false;
-        let base: Expression = match self.argument().matches(pattern, label) {
-            Some(_) => {
-                found_match = true;
-                Variable::new(label.clone())
+        match (pattern, expression) {
+            (Expression::Addition(pattern_items), Expression::Addition(items))
+            | (Expression::Multiplication(pattern_items), Expression::Multiplication(items)) => {
+                self.match_items(pattern, &pattern_items.items(), &items.items(), bindings)
             }
-            None => self.argument().as_ref().clone(),
-        };
-
-        let exponent: Expression = match self.modifier().matches(pattern, label) {
-            Some(_) => {
-                found_match = true;
-                Variable::new(label.clone())
+            /* the expression may be the association with a single non neutral item */
+            (Expression::Addition(pattern_items), _)
+            | (Expression::Multiplication(pattern_items), _) => self.match_items(
+                pattern,
+                &pattern_items.items(),
+                std::slice::from_ref(expression),
+                bindings,
+            ),
+
+            (Expression::Power(pattern_operation), Expression::Power(operation))
+            | (Expression::Logarithm(pattern_operation), Expression::Logarithm(operation)) => self
+                .match_node(
+                    &pattern_operation.argument(),
+                    &operation.argument(),
+                    bindings,
+                )
+                .iter()
+                .flat_map(|partial| {
+                    self.match_node(
+                        &pattern_operation.modifier(),
+                        &operation.modifier(),
+                        partial,
+                    )
+                })
+                .collect(),
+
+            (Expression::Sine(pattern_operation), Expression::Sine(operation))
+            | (Expression::Cossine(pattern_operation), Expression::Cossine(operation))
+            | (Expression::Tangent(pattern_operation), Expression::Tangent(operation))
+            | (Expression::Cotangent(pattern_operation), Expression::Cotangent(operation))
+            | (Expression::Secant(pattern_operation), Expression::Secant(operation))
+            | (Expression::Cossecant(pattern_operation), Expression::Cossecant(operation))
+            | (Expression::ArcSine(pattern_operation), Expression::ArcSine(operation))
+            | (Expression::ArcCossine(pattern_operation), Expression::ArcCossine(operation))
+            | (Expression::ArcTangent(pattern_operation), Expression::ArcTangent(operation))
+            | (Expression::ArcCotangent(pattern_operation), Expression::ArcCotangent(operation))
+            | (Expression::ArcSecant(pattern_operation), Expression::ArcSecant(operation))
+            | (Expression::ArcCossecant(pattern_operation), Expression::ArcCossecant(operation))
+            | (Expression::RealPart(pattern_operation), Expression::RealPart(operation))
+            | (
+                Expression::ImaginaryPart(pattern_operation),
+                Expression::ImaginaryPart(operation),
+            )
+            | (Expression::Conjugate(pattern_operation), Expression::Conjugate(operation))
+            | (Expression::Absolute(pattern_operation), Expression::Absolute(operation))
+            | (Expression::Argument(pattern_operation), Expression::Argument(operation)) => self
+                .match_node(
+                    &pattern_operation.argument(),
+                    &operation.argument(),
+                    bindings,
+                ),
+
+            _ => {
+                if pattern == expression {
+                    vec![bindings.clone()]
+                } else {
+                    vec![]
+                }
             }
-            None => self.modifier().as_ref().clone(),
-        };
-
-        if found_match {
-            return Some(Match {
-                expression: Power::new(base, exponent),
-                variable: Variable::new(label.clone()),
-            });
         }
-
-        return None;
     }
-}
-use crate::exponential::logarithm::Log;
-impl PatternMatchable for Log {
-    fn matches(&self, pattern: &Expression, label: &String) -> Option<Match> {
-        let mut found_match: bool = false;
-        let argument: Expression = match self.argument().matches(pattern, label) {
-            Some(_) => {
-                found_match = true;
-                Variable::new(label.clone())
-            }
-            None => self.argument().as_ref().clone(),
-        };
 
-        let base: Expression = match self.modifier().matches(pattern, label) {
-            Some(_) => {
-                found_match = true;
-                Variable::new(label.clone())
-            }
-            None => self.modifier().as_ref().clone(),
-        };
-
-        if found_match {
-            return Some(Match {
-                expression: Log::new(argument, base),
-                variable: Variable::new(label.clone()),
-            });
+    /**
+     * Commutative matching of pattern items against expression items
+     *  - concrete items are tried first, wildcards last
+     *  - wildcards take sub-multisets, smallest first, and the last one absorbs the rest
+     *  - fails early when the concrete items outnumber the expression items
+     */
+    fn match_items(
+        &self,
+        association: &Expression,
+        pattern_items: &[Expression],
+        items: &[Expression],
+        bindings: &Bindings,
+    ) -> Vec<Bindings> {
+        let concrete = pattern_items
+            .iter()
+            .filter(|item| Self::wildcard(item).is_none())
+            .count();
+        if concrete > items.len() || (concrete == pattern_items.len() && concrete != items.len()) {
+            return vec![];
         }
 
-        return None;
+        let mut ordered: Vec<Expression> = pattern_items.to_vec();
+        ordered.sort_by_key(|item| match Self::wildcard(item) {
+            None => 0,
+            Some(label) if self.constraints.contains_key(&label) => 1,
+            Some(_) => 2,
+        });
+
+        let mut results: Vec<Bindings> = Vec::new();
+        for bindings in self.match_ordered_items(association, &ordered, items, bindings) {
+            if !results.contains(&bindings) {
+                results.push(bindings);
+            }
+        }
+        results
     }
-}
-
 
-// ============================== //
-//         Trigonometrics         //
-// ============================== //
-use crate::base::operation::Operation;
-
-use crate::trigonometrics::sine::Sin;
-impl PatternMatchable for Sin {
-    fn matches(&self, pattern: &Expression, label: &String) -> Option<Match> {
-        let mut found_match: bool = false;
-        let argument: Expression = match self.argument().matches(pattern, label) {
-            Some(_) => {
-                found_match = true;
-                Variable::new(label.clone())
+    fn match_ordered_items(
+        &self,
+        association: &Expression,
+        pattern_items: &[Expression],
+        items: &[Expression],
+        bindings: &Bindings,
+    ) -> Vec<Bindings> {
+        let (pattern, remaining_patterns) = match pattern_items.split_first() {
+            Some(split) => split,
+            None => {
+                return if items.is_empty() {
+                    vec![bindings.clone()]
+                } else {
+                    vec![]
+                }
             }
-            None => self.argument().as_ref().clone(),
         };
 
-        if found_match {
-            return Some(Match {
-                expression: Sin::new(argument),
-                variable: Variable::new(label.clone()),
-            });
+        if let Some(label) = Self::wildcard(pattern) {
+            let absorb = |items: Vec<Expression>| match association {
+                Expression::Addition(_) => Expression::addition(items),
+                _ => Expression::multiplication(items),
+            };
+            if remaining_patterns.is_empty() {
+                return self.bind(&label, absorb(items.to_vec()), bindings);
+            }
+            /* wildcards come last, and each one but the last takes at least one item */
+            let largest = match (items.len() + 1).checked_sub(remaining_patterns.len()) {
+                Some(largest) if largest > 0 && items.len() <= SPLIT_LIMIT => largest,
+                _ => return vec![],
+            };
+
+            let mut results: Vec<Bindings> = Vec::new();
+            for (taken, remaining_items) in sub_multisets(items, largest) {
+                for partial in self.bind(&label, absorb(taken), bindings).iter() {
+                    results.append(&mut self.match_ordered_items(
+                        association,
+                        remaining_patterns,
+                        &remaining_items,
+                        partial,
+                    ));
+                }
+            }
+            return results;
         }
 
-        return None;
+        let mut results: Vec<Bindings> = Vec::new();
+        for (index, item) in items.iter().enumerate() {
+            /* identical items lead to the same bindings */
+            if items[..index].contains(item) {
+                continue;
+            }
+            let mut remaining_items: Vec<Expression> = items.to_vec();
+            remaining_items.remove(index);
+
+            for partial in self.match_node(pattern, item, bindings).iter() {
+                results.append(&mut self.match_ordered_items(
+                    association,
+                    remaining_patterns,
+                    &remaining_items,
+                    partial,
+                ));
+            }
+        }
+        results
     }
 }
 
-use crate::trigonometrics::cossine::Cos;
-impl PatternMatchable for Cos {
-    fn matches(&self, pattern: &Expression, label: &String) -> Option<Match> {
-        let mut found_match: bool = false;
-        let argument: Expression = match self.argument().matches(pattern, label) {
-            Some(_) => {
-                found_match = true;
-                Variable::new(label.clone())
-            }
-            None => self.argument().as_ref().clone(),
-        };
-
-        if found_match {
-            return Some(Match {
-                expression: Cos::new(argument),
-                variable: Variable::new(label.clone()),
-            });
+/**
+ * Non empty sub-multisets of the items up to a size, with their complements, smallest first
+ *  - identical items give each sub-multiset once
+ *  - built one size at a time, as the caller consumes them
+ */
+fn sub_multisets(
+    items: &[Expression],
+    largest: usize,
+) -> impl Iterator<Item = (Vec<Expression>, Vec<Expression>)> {
+    let mut groups: Vec<(Expression, usize)> = Vec::new();
+    for item in items {
+        match groups.iter_mut().find(|(seen, _)| seen == item) {
+            Some((_, count)) => *count += 1,
+            None => groups.push((item.clone(), 1)),
         }
-
-        return None;
     }
+
+    (1..=largest.min(items.len())).flat_map(move |size| {
+        let groups = groups.clone();
+        counts_with_sum(&groups, size)
+            .into_iter()
+            .map(move |counts| {
+                let (mut taken, mut remaining) = (Vec::new(), Vec::new());
+                for ((item, multiplicity), count) in groups.iter().zip(counts) {
+                    taken.extend(std::iter::repeat_n(item.clone(), count));
+                    remaining.extend(std::iter::repeat_n(item.clone(), multiplicity - count));
+                }
+                (taken, remaining)
+            })
+    })
+}
+
+/**
+ * Ways to take `size` items from groups of identical items, earlier groups first
+ */
+fn counts_with_sum(groups: &[(Expression, usize)], size: usize) -> Vec<Vec<usize>> {
+    let ((_, multiplicity), rest) = match groups.split_first() {
+        Some(split) => split,
+        None => return if size == 0 { vec![vec![]] } else { vec![] },
+    };
+    let available: usize = rest.iter().map(|(_, count)| count).sum();
+
+    (size.saturating_sub(available)..=size.min(*multiplicity))
+        .rev()
+        .flat_map(|count| {
+            counts_with_sum(rest, size - count)
+                .into_iter()
+                .map(move |mut counts| {
+                    counts.insert(0, count);
+                    counts
+                })
+        })
+        .collect()
 }
@@ -0,0 +1,219 @@
+#[cfg(test)]
+mod matches {
+    use crate::base::{expression::Expression, symbol::Symbol};
+    use crate::manipulation::pattern_matchable::{Bindings, Constraint, Pattern};
+
+    fn bindings(pairs: &[(&str, Expression)]) -> Bindings {
+        pairs
+            .iter()
+            .map(|(label, value)| (String::from(*label), value.clone()))
+            .collect()
+    }
+
+    #[test]
+    fn binds_wildcards() {
+        let pattern = Pattern::parse("sin(_u)^_n").unwrap();
+        let expression = Expression::parse("sin(x + 1)^3").unwrap();
+
+        assert_eq!(
+            pattern.matches(&expression),
+            vec![bindings(&[
+                ("_u", Expression::parse("x + 1").unwrap()),
+                ("_n", Symbol::integer(3).expr()),
+            ])]
+        );
+    }
+
+    #[test]
+    fn repeated_wildcards_must_agree() {
+        let pattern = Pattern::parse("sin(_u) * cos(_u)").unwrap();
+
+        assert!(pattern.is_match(&Expression::parse("cos(x) * sin(x)").unwrap()));
+        assert!(!pattern.is_match(&Expression::parse("cos(y) * sin(x)").unwrap()));
+    }
+
+    #[test]
+    fn matches_up_to_reordering() {
+        let pattern = Pattern::parse("_k * sin(_u)^2 + _k * cos(_u)^2 + _r").unwrap();
+        let expression = Expression::parse("3 * cos(x)^2 + y + 3 * sin(x)^2").unwrap();
+
+        assert_eq!(
+            pattern.matches(&expression),
+            vec![bindings(&[
+                ("_k", Symbol::integer(3).expr()),
+                ("_u", Symbol::variable("x").expr()),
+                ("_r", Symbol::variable("y").expr()),
+            ])]
+        );
+    }
+
+    #[test]
+    fn absorbs_neutral_element() {
+        let pattern = Pattern::parse("_k * sin(_u)^2 + _k * cos(_u)^2 + _r").unwrap();
+        let expression = Expression::parse("sin(x)^2 + cos(x)^2").unwrap();
+
+        assert_eq!(
+            pattern.matches(&expression),
+            vec![bindings(&[
+                ("_k", Symbol::integer(1).expr()),
+                ("_u", Symbol::variable("x").expr()),
+                ("_r", Symbol::integer(0).expr()),
+            ])]
+        );
+    }
+
+    #[test]
+    fn returns_all_binding_sets() {
+        let pattern = Pattern::parse("_a * _b").unwrap();
+        let expression = Expression::parse("x * y").unwrap();
+
+        let results = pattern.matches(&expression);
+        assert_eq!(results.len(), 3);
+        assert!(results.contains(&bindings(&[
+            ("_a", Symbol::variable("x").expr()),
+            ("_b", Symbol::variable("y").expr()),
+        ])));
+        assert!(results.contains(&bindings(&[
+            ("_a", Symbol::variable("y").expr()),
+            ("_b", Symbol::variable("x").expr()),
+        ])));
+        /* the last wildcard takes the neutral element when nothing is left */
+        assert!(results.contains(&bindings(&[
+            ("_a", expression.clone()),
+            ("_b", Symbol::integer(1).expr()),
+        ])));
+    }
+
+    #[test]
+    fn splits_items_among_wildcards() {
+        let pattern = Pattern::parse("_a + _b").unwrap();
+        let expression = Expression::parse("x + y + z").unwrap();
+        let split = |a: &str, b: &str| {
+            bindings(&[
+                ("_a", Expression::parse(a).unwrap()),
+                ("_b", Expression::parse(b).unwrap()),
+            ])
+        };
+
+        let results = pattern.matches(&expression);
+        assert_eq!(results.len(), 7);
+        assert_eq!(results[0], split("x", "y + z"));
+        assert!(results.contains(&split("x + y", "z")));
+        assert!(results.contains(&split("y + z", "x")));
+        assert!(results.contains(&split("x + y + z", "0")));
+    }
+
+    #[test]
+    fn splits_repeated_items_once() {
+        let pattern = Pattern::parse("_a * _b").unwrap();
+        let expression = Expression::multiplication(vec![
+            Symbol::variable("x").expr(),
+            Symbol::variable("x").expr(),
+            Symbol::variable("y").expr(),
+        ]);
+
+        assert_eq!(pattern.matches(&expression).len(), 5);
+    }
+
+    #[test]
+    fn limits_splits_of_large_associations() {
+        let expression = Expression::addition(
+            (0..70)
+                .map(|index| Symbol::variable(&format!("x{}", index)).expr())
+                .collect(),
+        );
+
+        assert!(!Pattern::parse("_a + _b").unwrap().is_match(&expression));
+        assert_eq!(
+            Pattern::parse("_a").unwrap().matches(&expression),
+            vec![bindings(&[("_a", expression.clone())])]
+        );
+        assert_eq!(
+            Pattern::parse("x7 + _a")
+                .unwrap()
+                .matches(&expression)
+                .len(),
+            1
+        );
+        assert!(!Pattern::parse("x7 + x70 + _a")
+            .unwrap()
+            .is_match(&expression));
+    }
+
+    #[test]
+    fn applies_constraints() {
+        let x = Symbol::variable("x");
+        let pattern = Pattern::parse("_a * x^_n")
+            .unwrap()
+            .constrain("_a", Constraint::FreeOf(x.clone()))
+            .constrain("_n", Constraint::Integer);
+
+        assert_eq!(
+            pattern.matches(&Expression::parse("2 * y * x^3").unwrap()),
+            vec![bindings(&[
+                ("_a", Expression::parse("2 * y").unwrap()),
+                ("_n", Symbol::integer(3).expr()),
+            ])]
+        );
+        assert!(!pattern.is_match(&Expression::parse("x * x^3").unwrap()));
+        assert!(!pattern.is_match(&Expression::parse("2 * x^1.5").unwrap()));
+    }
+
+    #[test]
+    fn rational_constraint() {
+        let pattern = Pattern::parse("x^_q")
+            .unwrap()
+            .constrain("_q", Constraint::Rational);
+
+        assert!(pattern.is_match(&Expression::parse("x^(1/2)").unwrap()));
+        assert!(pattern.is_match(&Expression::parse("x^3").unwrap()));
+        assert!(!pattern.is_match(&Expression::parse("x^0.5").unwrap()));
+        assert!(!pattern.is_match(&Expression::parse("x^y").unwrap()));
+    }
+
+    #[test]
+    fn real_constraint() {
+        let pattern = Pattern::parse("_r + _s")
+            .unwrap()
+            .constrain("_r", Constraint::Real);
+
+        assert_eq!(
+            pattern.matches(&Expression::parse("x + 1.5").unwrap()),
+            vec![bindings(&[
+                ("_r", Symbol::real(1.5).expr()),
+                ("_s", Symbol::variable("x").expr()),
+            ])]
+        );
+    }
+}
+
+#[cfg(test)]
+mod rewrite {
+    use crate::base::{expression::Expression, symbol::Symbol};
+    use crate::manipulation::pattern_matchable::Pattern;
+
+    #[test]
+    fn instantiates_replacement() {
+        let pattern = Pattern::parse("_k * sin(_u)^2 + _k * cos(_u)^2 + _r").unwrap();
+        let replacement = Expression::parse("_k + _r").unwrap();
+        let expression = Expression::parse("2 * sin(a)^2 + 2 * cos(a)^2 + b").unwrap();
+
+        assert_eq!(
+            pattern.rewrite(&expression, &replacement),
+            Some(Symbol::integer(2).expr() + Symbol::variable("b").expr())
+        );
+    }
+
+    #[test]
+    fn no_match() {
+        let pattern = Pattern::parse("sin(_u)").unwrap();
+
+        assert_eq!(
+            pattern.rewrite(
+                &Expression::parse("cos(x)").unwrap(),
+                &Symbol::integer(0).expr()
+            ),
+            None
+        );
+    }
+}
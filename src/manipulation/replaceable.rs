@@ -1,5 +1,95 @@
-use crate::base::expression::Symbol;
+// =================================== //
+//      Recursion on Expression        //
+// =================================== //
+use crate::base::{commutative_association::CommutativeAssociation, expression::Expression};
+use std::collections::HashMap;
 
-pub trait Replaceable {
-    fn substitute(&mut self, variable: &dyn Symbol, value: f64);
+impl Expression {
+    /**
+     * Replaces every occurrence of target by replacement
+     *  - an additive (multiplicative) target also matches a sub-multiset of addends (factors)
+     *  - the tree is rebuilt through the normalising constructors
+     */
+    pub fn substitute(&self, target: &Expression, replacement: &Expression) -> Expression {
+        self.substitute_all(
+            &[(target.clone(), replacement.clone())]
+                .iter()
+                .cloned()
+                .collect(),
+        )
+    }
+
+    /**
+     * Replaces all targets at once: replacements are not searched again
+     *  - larger associations are tried first when targets overlap
+     */
+    pub fn substitute_all(&self, replacements: &HashMap<Expression, Expression>) -> Expression {
+        let mut rules: Vec<(&Expression, &Expression)> = replacements.iter().collect();
+        rules.sort_by(|(target_1, _), (target_2, _)| {
+            association_size(target_2)
+                .cmp(&association_size(target_1))
+                .then(target_1.cmp(target_2))
+        });
+        replace(self, &rules)
+    }
+}
+
+fn association_size(expression: &Expression) -> usize {
+    match expression {
+        Expression::Addition(items) | Expression::Multiplication(items) => items.items().len(),
+        _ => 1,
+    }
+}
+
+/**
+ * Removes the target items from the item list if all of them are present
+ */
+fn remove_sub_multiset(items: &mut Vec<Expression>, target: &CommutativeAssociation) -> bool {
+    let mut remaining: Vec<Expression> = items.clone();
+    for target_item in target.items().iter() {
+        match remaining.iter().position(|item| item == target_item) {
+            Some(index) => {
+                remaining.remove(index);
+            }
+            None => return false,
+        }
+    }
+    *items = remaining;
+    true
+}
+
+fn replace(expression: &Expression, rules: &[(&Expression, &Expression)]) -> Expression {
+    if let Some((_, replacement)) = rules.iter().find(|(target, _)| *target == expression) {
+        return (*replacement).clone();
+    }
+
+    match expression {
+        Expression::Addition(association) | Expression::Multiplication(association) => {
+            let mut items: Vec<Expression> = association.items();
+            let mut replaced: Vec<Expression> = Vec::new();
+
+            for (target, replacement) in rules.iter() {
+                let target_items = match (expression, target) {
+                    (Expression::Addition(_), Expression::Addition(target_items)) => target_items,
+                    (Expression::Multiplication(_), Expression::Multiplication(target_items)) => {
+                        target_items
+                    }
+                    _ => continue,
+                };
+                while remove_sub_multiset(&mut items, target_items) {
+                    replaced.push((*replacement).clone());
+                }
+            }
+
+            let mut new_items: Vec<Expression> =
+                items.iter().map(|item| replace(item, rules)).collect();
+            new_items.append(&mut replaced);
+
+            match expression {
+                Expression::Addition(_) => Expression::addition(new_items),
+                _ => Expression::multiplication(new_items),
+            }
+        }
+        _ => expression.map_children(&|child| replace(child, rules)),
+    }
 }
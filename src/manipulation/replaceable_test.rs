@@ -0,0 +1,98 @@
+#[cfg(test)]
+mod substitute {
+    use crate::base::{expression::Expression, symbol::Symbol};
+
+    #[test]
+    fn replaces_variables() {
+        let x = &Symbol::variable("x").expr();
+        let y = &Symbol::variable("y").expr();
+        let fx = Expression::parse("x^2 + sin(x)").unwrap();
+
+        assert_eq!(
+            fx.substitute(x, y),
+            y.clone().pow(Symbol::integer(2).expr()) + Expression::sin(y.clone())
+        );
+    }
+
+    #[test]
+    fn resimplifies_at_construction() {
+        let x = &Symbol::variable("x").expr();
+        let fx = Expression::parse("x^2 + 3*x + 1").unwrap();
+
+        assert_eq!(
+            fx.substitute(x, &Symbol::integer(2).expr()),
+            Symbol::integer(11).expr()
+        );
+        assert_eq!(
+            Expression::parse("x^y")
+                .unwrap()
+                .substitute(x, &Symbol::integer(1).expr()),
+            Symbol::integer(1).expr()
+        );
+    }
+
+    #[test]
+    fn replaces_subtrees() {
+        let u = &Symbol::variable("u").expr();
+        let fx = Expression::parse("sin(x^2) + x^2").unwrap();
+        let target = Expression::parse("x^2").unwrap();
+
+        assert_eq!(fx.substitute(&target, u), Expression::sin(u.clone()) + u);
+    }
+
+    #[test]
+    fn replaces_sub_multisets() {
+        let u = &Symbol::variable("u").expr();
+        let c = &Symbol::variable("c").expr();
+
+        let sum = Expression::parse("a + b + c").unwrap();
+        let target = Expression::parse("a + c").unwrap();
+        assert_eq!(sum.substitute(&target, u), u + Symbol::variable("b").expr());
+
+        let product = Expression::parse("2 * a * b * c").unwrap();
+        let target = Expression::parse("a * b").unwrap();
+        assert_eq!(
+            product.substitute(&target, u),
+            Symbol::integer(2).expr() * u * c
+        );
+    }
+
+    #[test]
+    fn ignores_partial_sub_multisets() {
+        let sum = Expression::parse("a + b").unwrap();
+        let target = Expression::parse("a + c").unwrap();
+
+        assert_eq!(sum.substitute(&target, &Symbol::variable("u").expr()), sum);
+    }
+}
+
+#[cfg(test)]
+mod substitute_all {
+    use crate::base::{expression::Expression, symbol::Symbol};
+    use std::collections::HashMap;
+
+    #[test]
+    fn is_simultaneous() {
+        let x = Symbol::variable("x").expr();
+        let y = Symbol::variable("y").expr();
+        let mut replacements: HashMap<Expression, Expression> = HashMap::new();
+        replacements.insert(x.clone(), y.clone());
+        replacements.insert(y.clone(), x.clone());
+
+        let fx = Expression::parse("x - 2*y").unwrap();
+        assert_eq!(
+            fx.substitute_all(&replacements),
+            Expression::parse("y - 2*x").unwrap()
+        );
+    }
+
+    #[test]
+    fn evaluates_numeric_bindings() {
+        let mut replacements: HashMap<Expression, Expression> = HashMap::new();
+        replacements.insert(Symbol::variable("x").expr(), Symbol::integer(3).expr());
+        replacements.insert(Symbol::variable("y").expr(), Symbol::integer(4).expr());
+
+        let fx = Expression::parse("x^2 + y^2").unwrap();
+        assert_eq!(fx.substitute_all(&replacements), Symbol::integer(25).expr());
+    }
+}
@@ -0,0 +1,106 @@
+// =================================== //
+//      Recursion on Expression        //
+// =================================== //
+use crate::base::{expression::Expression, symbol::Symbol};
+
+impl Expression {
+    /**
+     * Rewrites trigonometric ratios as complex exponentials through Euler's formula
+     *  - sin(u) == (e^(iu) - e^(-iu)) / 2i
+     *  - cos(u) == (e^(iu) + e^(-iu)) / 2
+     *  - the other ratios are quotients of those
+     */
+    pub fn rewrite_as_exp(self) -> Expression {
+        let node = self.map_children(&|child| child.clone().rewrite_as_exp());
+        match &node {
+            Expression::Sine(sine) => euler_sine(sine.argument()),
+            Expression::Cossine(cossine) => euler_cossine(cossine.argument()),
+            Expression::Tangent(tangent) => {
+                euler_sine(tangent.argument()) / euler_cossine(tangent.argument())
+            }
+            Expression::Cotangent(cotangent) => {
+                euler_cossine(cotangent.argument()) / euler_sine(cotangent.argument())
+            }
+            Expression::Secant(secant) => {
+                Symbol::integer(1).expr() / euler_cossine(secant.argument())
+            }
+            Expression::Cossecant(cossecant) => {
+                Symbol::integer(1).expr() / euler_sine(cossecant.argument())
+            }
+            _ => node,
+        }
+    }
+
+    /**
+     * Rewrites exponentials of imaginary exponents as sines and cossines
+     *  - e^(a + iu) == e^a * (cos(u) + i * sin(u))
+     */
+    pub fn rewrite_as_trig(self) -> Expression {
+        let node = self.map_children(&|child| child.clone().rewrite_as_trig());
+        let power = match &node {
+            Expression::Power(power) if power.argument() == Symbol::e().expr() => power,
+            _ => return node,
+        };
+
+        let addends = match power.modifier().expand() {
+            Expression::Addition(addends) => addends.items(),
+            exponent => vec![exponent],
+        };
+        let mut real: Vec<Expression> = Vec::new();
+        let mut imaginary: Vec<Expression> = Vec::new();
+        for addend in addends.into_iter() {
+            match imaginary_part(&addend) {
+                Some(part) => imaginary.push(part),
+                None => real.push(addend),
+            }
+        }
+        if imaginary.is_empty() {
+            return node;
+        }
+
+        let angle = Expression::addition(imaginary);
+        Expression::exp(Expression::addition(real))
+            * (Expression::cos(angle.clone()) + Symbol::i().expr() * Expression::sin(angle))
+    }
+}
+
+/**
+ * e^(iu), and e^(-iu)
+ */
+fn euler_pair(angle: Expression) -> (Expression, Expression) {
+    let exponent = Symbol::i().expr() * angle;
+    (
+        Expression::exp(exponent.clone()),
+        Expression::exp(-exponent),
+    )
+}
+
+fn euler_sine(angle: Expression) -> Expression {
+    let (positive, negative) = euler_pair(angle);
+    Symbol::rational(-1, 2).expr() * Symbol::i().expr() * (positive - negative)
+}
+
+fn euler_cossine(angle: Expression) -> Expression {
+    let (positive, negative) = euler_pair(angle);
+    Symbol::rational(1, 2).expr() * (positive + negative)
+}
+
+/**
+ * Real factor u of an imaginary addend i * u
+ */
+fn imaginary_part(addend: &Expression) -> Option<Expression> {
+    let i = Symbol::i().expr();
+    match addend {
+        Expression::Constant(_) if addend == &i => Some(Symbol::integer(1).expr()),
+        Expression::Multiplication(factors) => {
+            let mut factors = factors.items();
+            let index = factors.iter().position(|factor| factor == &i)?;
+            factors.remove(index);
+            if factors.contains(&i) {
+                return None;
+            }
+            Some(Expression::multiplication(factors))
+        }
+        _ => None,
+    }
+}
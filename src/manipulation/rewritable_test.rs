@@ -0,0 +1,69 @@
+#[cfg(test)]
+mod exponential_form {
+    use crate::parsing::parser::parse;
+
+    #[test]
+    fn rewrites_sine_and_cossine() {
+        assert_eq!(
+            parse("sin(x)").rewrite_as_exp(),
+            parse("-i * (e^(i * x) - e^(-i * x)) / 2")
+        );
+        assert_eq!(
+            parse("cos(x)").rewrite_as_exp(),
+            parse("(e^(i * x) + e^(-i * x)) / 2")
+        );
+    }
+
+    #[test]
+    fn rewrites_nested_ratios() {
+        assert_eq!(
+            parse("x * sec(y) + 1").rewrite_as_exp(),
+            parse("x / ((e^(i * y) + e^(-i * y)) / 2) + 1")
+        );
+    }
+}
+
+#[cfg(test)]
+mod trigonometric_form {
+    use crate::parsing::parser::parse;
+
+    #[test]
+    fn splits_real_and_imaginary_exponents() {
+        assert_eq!(
+            parse("e^(2 + i * x)").rewrite_as_trig(),
+            parse("e^2 * (cos(x) + i * sin(x))")
+        );
+        assert_eq!(parse("e^i").rewrite_as_trig(), parse("cos(1) + i * sin(1)"));
+        assert_eq!(parse("e^x").rewrite_as_trig(), parse("e^x"));
+    }
+
+    #[test]
+    fn round_trips() {
+        for input in ["sin(x)", "cos(2 * x)", "tan(y)"].iter() {
+            let trial = parse(input).rewrite_as_exp().simplify().rewrite_as_trig();
+            assert_eq!(trial.simplify(), parse(input));
+        }
+    }
+}
+
+#[cfg(test)]
+mod identities {
+    use crate::base::expression::Expression;
+
+    fn prove(left: &str, right: &str) {
+        let rewrite = |input: &str| {
+            Expression::parse(input)
+                .unwrap()
+                .rewrite_as_exp()
+                .simplify()
+        };
+        assert_eq!(rewrite(left), rewrite(right));
+    }
+
+    #[test]
+    fn proves_through_exponential_form() {
+        prove("cos(x)^2 - sin(x)^2", "cos(2 * x)");
+        prove("2 * sin(x) * cos(x)", "sin(2 * x)");
+        prove("sin(x)^2 + cos(x)^2", "1");
+    }
+}
@@ -158,3 +158,40 @@ mod integer_rational {
         assert_eq!(trial.simplify(), expected);
     }
 }
+
+#[cfg(test)]
+mod trigonometric_identities {
+    use crate::base::{expression::Expression, symbol::Symbol};
+
+    fn simplify(input: &str) -> Expression {
+        Expression::parse(input).unwrap().simplify()
+    }
+
+    #[test]
+    fn simplifies_readme_sample() {
+        let x = &Symbol::variable("x").expr();
+        let expected = x + Symbol::integer(1).expr() / x;
+
+        assert_eq!(
+            simplify("(x^2 + 1) / (x * (sin(x)^2 + cos(x)^2))"),
+            expected
+        );
+    }
+
+    #[test]
+    fn simplifies_pythagorean_forms() {
+        assert_eq!(
+            simplify("3 * sin(y)^2 + 3 * cos(y)^2"),
+            Symbol::integer(3).expr()
+        );
+        assert_eq!(simplify("1 - cos(x)^2"), simplify("sin(x)^2"));
+        assert_eq!(simplify("(1 - sin(x)^2) * a"), simplify("a * cos(x)^2"));
+    }
+
+    #[test]
+    fn simplifies_quotients_and_double_angles() {
+        assert_eq!(simplify("sin(x) / cos(x)"), simplify("tan(x)"));
+        assert_eq!(simplify("4 * sin(x) * cos(x)"), simplify("2 * sin(2 * x)"));
+        assert_eq!(simplify("cos(x)^2 - sin(x)^2"), simplify("cos(2 * x)"));
+    }
+}
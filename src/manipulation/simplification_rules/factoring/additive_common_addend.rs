@@ -2,6 +2,7 @@ use crate::base::expression::Expression;
 use crate::base::symbol::Symbol;
 use crate::manipulation::simplification_rules::rule::Rule;
 
+use num::rational::BigRational;
 use std::collections::HashMap;
 
 pub struct AdditiveCommonAddend {}
@@ -9,12 +10,13 @@ impl Rule for AdditiveCommonAddend {
     fn apply(expression: &Expression) -> Expression {
         match expression {
             Expression::Addition(addends) => {
-                let mut addend_count_list: HashMap<Expression, isize> = HashMap::new();
+                let mut addend_count_list: HashMap<Expression, Symbol<BigRational>> =
+                    HashMap::new();
 
                 fn update_addend_list(
-                    mapping: &mut HashMap<Expression, isize>,
+                    mapping: &mut HashMap<Expression, Symbol<BigRational>>,
                     addend: &Expression,
-                    counter: isize,
+                    counter: Symbol<BigRational>,
                 ) {
                     let opposite = &-addend;
 
@@ -32,39 +34,45 @@ impl Rule for AdditiveCommonAddend {
                 for addend in addends.items().iter() {
                     match addend {
                         Expression::Multiplication(factors) => {
-                            match factors.get_one(&|factor| match factor {
-                                Expression::Integer(_) => true,
-                                _ => false,
-                            }) {
-                                /* Integer factor in multiplication */
-                                Some(Expression::Integer(integer)) => {
-                                    let remaining_factors = factors.get(&|factor| match factor {
-                                        Expression::Integer(_) => false,
-                                        _ => true,
-                                    });
+                            match factors.get_rationals().pop() {
+                                /* Exact factor in multiplication */
+                                Some(coefficient) => {
+                                    let counter = match coefficient {
+                                        Expression::Integer(integer) => Symbol::from(integer),
+                                        Expression::Rational(rational) => rational,
+                                        _ => unreachable!(),
+                                    };
                                     update_addend_list(
                                         &mut addend_count_list,
-                                        &Expression::multiplication(remaining_factors),
-                                        integer.value().unwrap(),
+                                        &Expression::multiplication(factors.get_non_rationals()),
+                                        counter,
                                     );
                                 }
 
-                                /* No Integer factor in multiplication */
-                                _ => {
-                                    update_addend_list(&mut addend_count_list, addend, 1);
+                                /* No exact factor in multiplication */
+                                None => {
+                                    update_addend_list(
+                                        &mut addend_count_list,
+                                        addend,
+                                        Symbol::rational(1, 1),
+                                    );
                                 }
                             };
                         }
                         /* Not multiplication */
                         _ => {
-                            update_addend_list(&mut addend_count_list, addend, 1);
+                            update_addend_list(
+                                &mut addend_count_list,
+                                addend,
+                                Symbol::rational(1, 1),
+                            );
                         }
                     }
                 }
                 let addends: Vec<Expression> = addend_count_list
                     .iter()
-                    .filter(|&(_, counter)| counter != &0)
-                    .map(|(expression, counter)| Symbol::integer(*counter).expr() * expression)
+                    .filter(|&(_, counter)| !counter.is_zero())
+                    .map(|(expression, counter)| counter.clone().expr() * expression)
                     .collect();
 
                 return Expression::addition(addends);
@@ -137,6 +145,20 @@ mod simplify {
         assert_eq!(factored, expected);
     }
 
+    #[test]
+    fn simplifies_rational_multiplied_addends() {
+        let a = &Symbol::variable("a").expr();
+        let expression = Expression::addition(vec![
+            a / Symbol::integer(2).expr(),
+            a / Symbol::integer(3).expr(),
+            Symbol::variable("b").expr(),
+        ]);
+
+        let expected = Symbol::rational(5, 6).expr() * a + Symbol::variable("b").expr();
+
+        assert_eq!(AdditiveCommonAddend::apply(&expression), expected);
+    }
+
     #[test]
     fn subtracts_opposite() {
         let expression = Expression::addition(vec![
@@ -0,0 +1,57 @@
+use crate::base::{expression::Expression, symbol::Symbol};
+use crate::manipulation::simplification_rules::rule::Rule;
+
+/**
+ * Rewrites logarithms to natural logarithms: log(a, b) == ln(a) / ln(b)
+ *  - not part of the built-in simplification, add it to a RuleSet to opt in
+ */
+pub struct ChangeOfBase {}
+impl Rule for ChangeOfBase {
+    fn apply(expression: &Expression) -> Expression {
+        if let Expression::Logarithm(log) = expression {
+            let base = log.modifier();
+            if base != Symbol::e().expr() {
+                return Expression::ln(log.argument()) / Expression::ln(base);
+            }
+        }
+
+        expression.clone()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::manipulation::simplification_rules::rule_set::RuleSet;
+
+    #[test]
+    fn rewrites_to_natural_logarithms() {
+        let a = Symbol::variable("a").expr();
+        let b = Symbol::variable("b").expr();
+        let expression = Expression::logarithm(a.clone(), b.clone());
+
+        let expected = Expression::ln(a) / Expression::ln(b);
+
+        assert_eq!(ChangeOfBase::apply(&expression), expected);
+    }
+
+    #[test]
+    fn keeps_natural_logarithms() {
+        let expression = Expression::ln(Symbol::variable("a").expr());
+
+        assert_eq!(ChangeOfBase::apply(&expression), expression);
+    }
+
+    #[test]
+    fn is_opt_in() {
+        let expression = Expression::parse("log(x, 2)").unwrap();
+        let mut rule_set = RuleSet::simplification();
+        rule_set.add(ChangeOfBase {});
+
+        assert_eq!(expression.clone().simplify(), expression);
+        assert_eq!(
+            expression.simplify_with(&rule_set),
+            Expression::parse("ln(x) / ln(2)").unwrap()
+        );
+    }
+}
@@ -0,0 +1,165 @@
+use crate::base::{expression::Expression, symbol::Symbol};
+use crate::manipulation::simplification_rules::{
+    identities::trigonometric_term::{find_other, TrigonometricTerm},
+    rule::Rule,
+};
+use crate::trigonometrics::trigonometric::Trigonometric;
+
+/**
+ * Double angle contractions:
+ *      sin(u)^n * cos(u)^n == sin(2u)^n / 2^n
+ *      k * cos(u)^2 - k * sin(u)^2 == k * cos(2u)
+ *      k * cos(u)^2 - k / 2 == k / 2 * cos(2u)
+ *      k * sin(u)^2 - k / 2 == -k / 2 * cos(2u)
+ */
+pub struct DoubleAngle {}
+
+impl Rule for DoubleAngle {
+    fn apply(expression: &Expression) -> Expression {
+        match expression {
+            Expression::Multiplication(factors) => {
+                let mut remaining: Vec<Option<Expression>> =
+                    factors.items().into_iter().map(Some).collect();
+                let mut contracted: Vec<Expression> = Vec::new();
+
+                for index in 0..remaining.len() {
+                    let (ratio, angle, exponent) =
+                        match remaining[index].as_ref().and_then(TrigonometricTerm::power) {
+                            Some((Trigonometric::Sine, angle, exponent)) if exponent > 0 => {
+                                (Trigonometric::Sine, angle, exponent)
+                            }
+                            _ => continue,
+                        };
+
+                    let cossine =
+                        find_other(&remaining, index, &|factor| match TrigonometricTerm::power(
+                            factor,
+                        ) {
+                            Some((other_ratio, other_angle, other_exponent)) => {
+                                other_ratio != ratio
+                                    && other_angle == angle
+                                    && other_exponent == exponent
+                            }
+                            None => false,
+                        });
+                    if let Some(other_index) = cossine {
+                        remaining[index] = None;
+                        remaining[other_index] = None;
+                        let exponent = Symbol::integer(exponent).expr();
+                        contracted.push(Expression::power(
+                            Symbol::rational(1, 2).expr(),
+                            exponent.clone(),
+                        ));
+                        contracted
+                            .push(Expression::power(Expression::sin(double(&angle)), exponent));
+                    }
+                }
+
+                if !contracted.is_empty() {
+                    contracted.extend(remaining.into_iter().flatten());
+                    return Expression::multiplication(contracted);
+                }
+            }
+            Expression::Addition(addends) => {
+                let mut remaining: Vec<Option<Expression>> =
+                    addends.items().into_iter().map(Some).collect();
+                let mut contracted: Vec<Expression> = Vec::new();
+
+                for index in 0..remaining.len() {
+                    let term = match remaining[index]
+                        .as_ref()
+                        .and_then(TrigonometricTerm::squared)
+                    {
+                        Some(term) => term,
+                        None => continue,
+                    };
+                    let double_cossine = Expression::cos(double(&term.angle));
+
+                    if term.ratio == Trigonometric::Cossine {
+                        let opposite = -term.coefficient.clone();
+                        let sine = find_other(&remaining, index, &|addend| {
+                            TrigonometricTerm::squared(addend)
+                                .filter(|other| {
+                                    other.pairs_with(&term) && other.coefficient == opposite
+                                })
+                                .is_some()
+                        });
+                        if let Some(other_index) = sine {
+                            remaining[index] = None;
+                            remaining[other_index] = None;
+                            contracted.push(term.coefficient * double_cossine);
+                            continue;
+                        }
+                    }
+
+                    let half = term.coefficient.clone() / Symbol::integer(2).expr();
+                    let opposite_half = -half.clone();
+                    if let Some(other_index) =
+                        find_other(&remaining, index, &|addend| addend == &opposite_half)
+                    {
+                        remaining[index] = None;
+                        remaining[other_index] = None;
+                        contracted.push(match term.ratio {
+                            Trigonometric::Cossine => half * double_cossine,
+                            _ => opposite_half * double_cossine,
+                        });
+                    }
+                }
+
+                if !contracted.is_empty() {
+                    contracted.extend(remaining.into_iter().flatten());
+                    return Expression::addition(contracted);
+                }
+            }
+            _ => {}
+        }
+
+        expression.clone()
+    }
+}
+
+fn double(angle: &Expression) -> Expression {
+    Symbol::integer(2).expr() * angle.clone()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::parsing::parser::parse;
+
+    #[test]
+    fn contracts_sine_cossine_products() {
+        assert_eq!(
+            DoubleAngle::apply(&parse("2 * sin(x) * cos(x)")),
+            parse("sin(2 * x)")
+        );
+        assert_eq!(
+            DoubleAngle::apply(&parse("a * sin(x)^2 * cos(x)^2")),
+            parse("a * sin(2 * x)^2 / 4")
+        );
+        assert_eq!(
+            DoubleAngle::apply(&parse("sin(x) * cos(y)")),
+            parse("sin(x) * cos(y)")
+        );
+    }
+
+    #[test]
+    fn contracts_square_differences() {
+        assert_eq!(
+            DoubleAngle::apply(&parse("cos(x)^2 - sin(x)^2")),
+            parse("cos(2 * x)")
+        );
+        assert_eq!(
+            DoubleAngle::apply(&parse("1 - 2 * sin(x)^2")),
+            parse("cos(2 * x)")
+        );
+        assert_eq!(
+            DoubleAngle::apply(&parse("2 * cos(x)^2 - 1")),
+            parse("cos(2 * x)")
+        );
+        assert_eq!(
+            DoubleAngle::apply(&parse("a * cos(y)^2 - a * sin(y)^2 + b")),
+            parse("a * cos(2 * y) + b")
+        );
+    }
+}
@@ -1,45 +1,33 @@
-use crate::base::expression::Expression;
+use crate::base::{
+    associative_operation::AssociativeOperation, expression::Expression, symbol::Symbol,
+};
 
+use crate::manipulation::pattern_matchable::Pattern;
 use crate::manipulation::simplification_rules::rule::Rule;
 
 pub struct InversePowerLog {}
 impl Rule for InversePowerLog {
     fn apply(expression: &Expression) -> Expression {
-        match expression {
-            Expression::Power(power) => {
-                /* Outer operator is Power */
-                match power.modifier() {
-                    Expression::Logarithm(log) => {
-                        /* Inner operator is Logarithm */
-                        let exponential_base: Expression = power.argument();
-                        let logarithmic_base: Expression = log.modifier();
-                        if exponential_base == logarithmic_base {
-                            let logarithmic_argument: Expression = log.argument();
-                            return logarithmic_argument.clone();
-                        }
-                    }
-                    _ => {}
-                }
-            }
-            Expression::Logarithm(log) => {
-                /* Outer operator is Logarithm */
-                match log.argument() {
-                    Expression::Power(power) => {
-                        /* Inner operator is Power */
-                        let logarithmic_base: Expression = log.modifier();
-                        let exponential_base: Expression = power.argument();
-                        if exponential_base == logarithmic_base {
-                            let exponential_exponent: Expression = power.modifier();
-                            return exponential_exponent.clone();
-                        }
-                    }
-                    _ => {}
-                }
+        let b = || Symbol::variable("_b").expr();
+        let x = || Symbol::variable("_x").expr();
+
+        /* raw variants: the constructors would already collapse the templates */
+        let power_of_log = Pattern::new(Expression::Power(AssociativeOperation::new(
+            b(),
+            Expression::Logarithm(AssociativeOperation::new(x(), b())),
+        )));
+        let log_of_power = Pattern::new(Expression::Logarithm(AssociativeOperation::new(
+            Expression::Power(AssociativeOperation::new(b(), x())),
+            b(),
+        )));
+
+        for pattern in [power_of_log, log_of_power].iter() {
+            if let Some(rewritten) = pattern.rewrite(expression, &x()) {
+                return rewritten;
             }
-            _ => {}
-        };
+        }
 
-        return expression.clone();
+        expression.clone()
     }
 }
 
@@ -1,159 +1,108 @@
-use crate::arithmetics::addition::Addition;
 use crate::base::expression::Expression;
-use crate::exponential::power::Power;
-use crate::manipulation::{
-    identifiable::{Identifiable, Identity},
-    simplification_rules::rule::Rule,
+use crate::manipulation::simplification_rules::{
+    identities::trigonometric_term::{find_other, TrigonometricTerm},
+    rule::Rule,
 };
-use crate::symbols::number::Number;
-use crate::trigonometrics::{cossine::Cos, sine::Sin};
-
-use std::collections::HashMap;
 
+/**
+ * Pythagorean identity sin(u)^2 + cos(u)^2 == 1 on addends sharing a coefficient:
+ *      k * sin(u)^2 + k * cos(u)^2 == k
+ *      k - k * sin(u)^2 == k * cos(u)^2
+ */
 pub struct PitagoricIdentity {}
 
 impl Rule for PitagoricIdentity {
-    fn apply(expression: &Expression) -> Vec<Expression> {
-        let mut alternative_list: Vec<Expression> = Vec::new();
-
-        match expression {
-            Expression::CommutativeAssociation(association) => match association.id() {
-                Identity::Addition => {
-                    let mut pitagorean_counter: HashMap<Expression, isize> = HashMap::new();
-                    let mut other_addend_list: Vec<Expression> = Vec::new();
-                    for addend in association.items().iter() {
-                        if let Some(complement) = complement(addend) {
-                            if pitagorean_counter.contains_key(addend) {
-                                let counter = pitagorean_counter.remove(addend).unwrap();
-                                pitagorean_counter.insert(addend.clone(), counter + 1);
-                            } else if pitagorean_counter.contains_key(&complement) {
-                                let counter = pitagorean_counter.remove(&complement).unwrap();
-                                pitagorean_counter.insert(complement.clone(), counter - 1);
-                                other_addend_list.push(Number::new(1.0));
-                            } else {
-                                pitagorean_counter.insert(addend.clone(), 1);
-                            }
-                        } else {
-                            other_addend_list.push(addend.clone());
-                        }
-                    }
-
-                    let mut addends: Vec<Expression> = pitagorean_counter
-                        .iter()
-                        .filter(|&(_, counter)| counter != &0)
-                        .map(|(expression, counter)| *counter as isize * expression)
-                        .collect();
+    fn apply(expression: &Expression) -> Expression {
+        if let Expression::Addition(addends) = expression {
+            let mut remaining: Vec<Option<Expression>> =
+                addends.items().into_iter().map(Some).collect();
+            let mut contracted: Vec<Expression> = Vec::new();
+
+            for index in 0..remaining.len() {
+                let term = match remaining[index]
+                    .as_ref()
+                    .and_then(TrigonometricTerm::squared)
+                {
+                    Some(term) => term,
+                    None => continue,
+                };
 
-                    addends.append(&mut other_addend_list);
+                let complement = find_other(&remaining, index, &|addend| {
+                    TrigonometricTerm::squared(addend)
+                        .filter(|other| {
+                            other.pairs_with(&term) && other.coefficient == term.coefficient
+                        })
+                        .is_some()
+                });
+                if let Some(other_index) = complement {
+                    remaining[index] = None;
+                    remaining[other_index] = None;
+                    contracted.push(term.coefficient);
+                    continue;
+                }
 
-                    alternative_list.push(Addition::new(addends));
+                let opposite = -term.coefficient.clone();
+                if let Some(other_index) =
+                    find_other(&remaining, index, &|addend| addend == &opposite)
+                {
+                    remaining[index] = None;
+                    remaining[other_index] = None;
+                    contracted.push(opposite * term.complement());
                 }
-                _ => {}
-            },
-            _ => {}
-        }
+            }
 
-        if alternative_list.is_empty() {
-            return vec![expression.clone()];
+            if !contracted.is_empty() {
+                contracted.extend(remaining.into_iter().flatten());
+                return Expression::addition(contracted);
+            }
         }
 
-        return alternative_list;
+        expression.clone()
     }
 }
 
-/**
- * Complement pitagorean expression is the addend . Eg:
- *      complement(sin^2(a + b + c)) =  cos^2(a + b + c)
- *      complement(cos^2(cos(theta))) = sin^2(cos(theta))
- */
-fn complement(expression: &Expression) -> Option<Expression> {
-    match expression {
-        Expression::AssociativeOperation(op) => match op.id() {
-            Identity::Power => {
-                let base = op.argument();
-                let exponent = op.modifier();
-
-                let is_trigonometric: bool = match base.id() {
-                    Identity::Sin => true,
-                    Identity::Cos => true,
-                    _ => false,
-                };
-
-                let is_squared: bool = match exponent.as_ref() {
-                    Expression::Symbol(s) => match exponent.id() {
-                        Identity::Number => s.value() == Some(2.0),
-                        _ => false,
-                    },
-                    _ => false,
-                };
-
-                if is_trigonometric && is_squared {
-                    if let Expression::Operation(op) = base.as_ref() {
-                        match base.id() {
-                            Identity::Cos => {
-                                return Some(Power::new(
-                                    Sin::new(op.argument().as_ref().clone()),
-                                    Number::new(2.0),
-                                ));
-                            }
-                            Identity::Sin => {
-                                return Some(Power::new(
-                                    Cos::new(op.argument().as_ref().clone()),
-                                    Number::new(2.0),
-                                ));
-                            }
-                            _ => {}
-                        }
-                    }
-                }
-            }
-            _ => {}
-        },
-        _ => {}
-    };
-
-    return None;
-}
-
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::base::symbol::Symbol;
+    use crate::parsing::parser::parse;
 
     #[test]
     fn pitagorean_complement() {
-        let angle = &Number::new(1.0);
-        let cos_square = Power::new(Cos::new(angle.clone()), Number::new(2.0));
-        let sin_square = Power::new(Sin::new(angle.clone()), Number::new(2.0));
-        assert_eq!(complement(&cos_square.clone()), Some(sin_square.clone()));
-        assert_eq!(complement(&sin_square.clone()), Some(cos_square.clone()));
-
-        let angle = &Cos::new(Number::new(1.0));
-        let cos_square = Power::new(Cos::new(angle.clone()), Number::new(2.0));
-        let sin_square = Power::new(Sin::new(angle.clone()), Number::new(2.0));
-        assert_eq!(complement(&cos_square.clone()), Some(sin_square.clone()));
-        assert_eq!(complement(&sin_square.clone()), Some(cos_square.clone()));
+        let angle = parse("x + 1");
+        let term = TrigonometricTerm::squared(&parse("3 * cos(x + 1)^2")).unwrap();
+        assert_eq!(term.coefficient, Symbol::integer(3).expr());
+        assert_eq!(
+            term.complement(),
+            Expression::sin(angle).pow(Symbol::integer(2).expr())
+        );
     }
 
     #[test]
     fn rule_apply() {
-        let angle = &Number::new(1.0);
-        let cos_square = Power::new(Cos::new(angle.clone()), Number::new(2.0));
-        let sin_square = Power::new(Sin::new(angle.clone()), Number::new(2.0));
         assert_eq!(
-            PitagoricIdentity::apply(&(cos_square + sin_square))
-                .pop()
-                .unwrap(),
-            Number::new(1.0)
+            PitagoricIdentity::apply(&parse("sin(x)^2 + cos(x)^2")),
+            Symbol::integer(1).expr()
         );
+        assert_eq!(
+            PitagoricIdentity::apply(&parse("a * sin(cos(y))^2 + a * cos(cos(y))^2 + b")),
+            parse("a + b")
+        );
+        assert_eq!(
+            PitagoricIdentity::apply(&parse("2 * sin(x)^2 + cos(x)^2")),
+            parse("2 * sin(x)^2 + cos(x)^2")
+        );
+    }
 
-        let angle = &Cos::new(Number::new(1.0));
-        let cos_square = Power::new(Cos::new(angle.clone()), Number::new(2.0));
-        let sin_square = Power::new(Sin::new(angle.clone()), Number::new(2.0));
+    #[test]
+    fn complements_unit_differences() {
+        assert_eq!(
+            PitagoricIdentity::apply(&parse("1 - sin(x)^2")),
+            parse("cos(x)^2")
+        );
         assert_eq!(
-            PitagoricIdentity::apply(&(cos_square + sin_square))
-                .pop()
-                .unwrap(),
-            Number::new(1.0)
+            PitagoricIdentity::apply(&parse("a * cos(x)^2 - a")),
+            parse("-a * sin(x)^2")
         );
     }
 }
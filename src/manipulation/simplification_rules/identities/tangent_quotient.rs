@@ -0,0 +1,115 @@
+use crate::base::{expression::Expression, symbol::Symbol};
+use crate::manipulation::simplification_rules::{
+    identities::trigonometric_term::{find_other, TrigonometricTerm},
+    rule::Rule,
+};
+use crate::trigonometrics::trigonometric::Trigonometric;
+
+/**
+ * Quotients of sine and cossine over the same angle:
+ *      sin(u)^n / cos(u)^n == tan(u)^n
+ *      cos(u)^n / sin(u)^n == cot(u)^n
+ *  - unbalanced exponents keep the remaining power: sin(u)^3 / cos(u) == tan(u) * sin(u)^2
+ */
+pub struct TangentQuotient {}
+
+impl Rule for TangentQuotient {
+    fn apply(expression: &Expression) -> Expression {
+        if let Expression::Multiplication(factors) = expression {
+            let mut remaining: Vec<Option<Expression>> =
+                factors.items().into_iter().map(Some).collect();
+            let mut contracted: Vec<Expression> = Vec::new();
+
+            for index in 0..remaining.len() {
+                let (angle, sine_exponent) =
+                    match remaining[index].as_ref().and_then(TrigonometricTerm::power) {
+                        Some((Trigonometric::Sine, angle, exponent)) => (angle, exponent),
+                        _ => continue,
+                    };
+
+                let cossine = find_other(
+                    &remaining,
+                    index,
+                    &|factor| match TrigonometricTerm::power(factor) {
+                        Some((Trigonometric::Cossine, other_angle, other_exponent)) => {
+                            other_angle == angle && other_exponent * sine_exponent < 0
+                        }
+                        _ => false,
+                    },
+                );
+                let other_index = match cossine {
+                    Some(other_index) => other_index,
+                    None => continue,
+                };
+                let cossine_exponent = match remaining[other_index]
+                    .as_ref()
+                    .and_then(TrigonometricTerm::power)
+                {
+                    Some((_, _, exponent)) => exponent,
+                    None => continue,
+                };
+
+                /* quotient power and the ratio it contracts to */
+                let shared = sine_exponent.abs().min(cossine_exponent.abs());
+                let (quotient, sine_left, cossine_left) = if sine_exponent > 0 {
+                    (
+                        Expression::tan(angle.clone()),
+                        sine_exponent - shared,
+                        cossine_exponent + shared,
+                    )
+                } else {
+                    (
+                        Expression::cot(angle.clone()),
+                        sine_exponent + shared,
+                        cossine_exponent - shared,
+                    )
+                };
+
+                remaining[index] = None;
+                remaining[other_index] = None;
+                contracted.push(Expression::power(quotient, Symbol::integer(shared).expr()));
+                contracted.push(Expression::power(
+                    Expression::sin(angle.clone()),
+                    Symbol::integer(sine_left).expr(),
+                ));
+                contracted.push(Expression::power(
+                    Expression::cos(angle),
+                    Symbol::integer(cossine_left).expr(),
+                ));
+            }
+
+            if !contracted.is_empty() {
+                contracted.extend(remaining.into_iter().flatten());
+                return Expression::multiplication(contracted);
+            }
+        }
+
+        expression.clone()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::parsing::parser::parse;
+
+    #[test]
+    fn contracts_quotients() {
+        assert_eq!(
+            TangentQuotient::apply(&parse("sin(x) / cos(x)")),
+            parse("tan(x)")
+        );
+        assert_eq!(
+            TangentQuotient::apply(&parse("a * cos(x)^2 / sin(x)^2")),
+            parse("a * cot(x)^2")
+        );
+        assert_eq!(
+            TangentQuotient::apply(&parse("sin(x)^3 / cos(x)")),
+            parse("tan(x) * sin(x)^2")
+        );
+        assert_eq!(
+            TangentQuotient::apply(&parse("sin(x) * cos(x)")),
+            parse("sin(x) * cos(x)")
+        );
+    }
+}
@@ -0,0 +1,121 @@
+use crate::base::{expression::Expression, symbol::Symbol};
+use crate::trigonometrics::trigonometric::Trigonometric;
+
+/**
+ * Addend made of a coefficient and an integer power of sine or cossine: k * sin(u)^n
+ */
+#[derive(Debug, Clone, PartialEq)]
+pub struct TrigonometricTerm {
+    pub coefficient: Expression,
+    pub ratio: Trigonometric,
+    pub angle: Expression,
+    pub exponent: isize,
+}
+
+impl TrigonometricTerm {
+    /**
+     * Integer power of sine or cossine, a bare ratio has exponent 1
+     */
+    pub fn power(factor: &Expression) -> Option<(Trigonometric, Expression, isize)> {
+        match factor {
+            Expression::Sine(sine) => Some((Trigonometric::Sine, sine.argument(), 1)),
+            Expression::Cossine(cossine) => Some((Trigonometric::Cossine, cossine.argument(), 1)),
+            Expression::Power(power) => match power.modifier() {
+                Expression::Integer(n) => match Self::power(&power.argument()) {
+                    Some((ratio, angle, 1)) => Some((ratio, angle, n.value()?)),
+                    _ => None,
+                },
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    /**
+     * Splits an addend into its coefficient and its single sine or cossine power
+     */
+    pub fn from_addend(addend: &Expression) -> Option<Self> {
+        if let Some((ratio, angle, exponent)) = Self::power(addend) {
+            return Some(Self {
+                coefficient: Symbol::integer(1).expr(),
+                ratio,
+                angle,
+                exponent,
+            });
+        }
+
+        match addend {
+            Expression::Multiplication(factors) => {
+                let factors = factors.items();
+                let mut powers = factors
+                    .iter()
+                    .enumerate()
+                    .filter_map(|(index, factor)| Some((index, Self::power(factor)?)));
+
+                let (index, (ratio, angle, exponent)) = powers.next()?;
+                if powers.next().is_some() {
+                    return None;
+                }
+
+                let coefficient = factors
+                    .iter()
+                    .enumerate()
+                    .filter(|&(other_index, _)| other_index != index)
+                    .map(|(_, factor)| factor.clone())
+                    .collect();
+
+                Some(Self {
+                    coefficient: Expression::multiplication(coefficient),
+                    ratio,
+                    angle,
+                    exponent,
+                })
+            }
+            _ => None,
+        }
+    }
+
+    /**
+     * Squared addend, k * sin(u)^2 or k * cos(u)^2
+     */
+    pub fn squared(addend: &Expression) -> Option<Self> {
+        Self::from_addend(addend).filter(|term| term.exponent == 2)
+    }
+
+    /**
+     * Same power of the other ratio: sin(u)^n <-> cos(u)^n
+     */
+    pub fn complement(&self) -> Expression {
+        let ratio = match self.ratio {
+            Trigonometric::Sine => Trigonometric::Cossine,
+            _ => Trigonometric::Sine,
+        };
+        Expression::power(
+            ratio.build(self.angle.clone()),
+            Symbol::integer(self.exponent).expr(),
+        )
+    }
+
+    /**
+     * Whether both terms are powers of the two different ratios over the same angle
+     */
+    pub fn pairs_with(&self, other: &Self) -> bool {
+        self.ratio != other.ratio && self.angle == other.angle
+    }
+}
+
+/**
+ * Position of another remaining item satisfying the predicate
+ */
+pub fn find_other(
+    remaining: &[Option<Expression>],
+    index: usize,
+    predicate: &dyn Fn(&Expression) -> bool,
+) -> Option<usize> {
+    remaining
+        .iter()
+        .enumerate()
+        .position(|(other_index, item)| {
+            other_index != index && item.as_ref().filter(|item| predicate(item)).is_some()
+        })
+}
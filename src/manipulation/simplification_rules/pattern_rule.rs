@@ -0,0 +1,50 @@
+use crate::base::expression::Expression;
+use crate::manipulation::pattern_matchable::Pattern;
+use crate::manipulation::simplification_rules::rule::RewriteRule;
+
+/**
+ * Declarative rule: rewrites nodes matching the pattern into the instantiated replacement
+ */
+pub struct PatternRule {
+    pattern: Pattern,
+    replacement: Expression,
+}
+
+impl PatternRule {
+    pub fn new(pattern: Pattern, replacement: Expression) -> Self {
+        Self {
+            pattern,
+            replacement,
+        }
+    }
+}
+
+impl RewriteRule for PatternRule {
+    fn rewrite(&self, expression: &Expression) -> Expression {
+        match self.pattern.rewrite(expression, &self.replacement) {
+            Some(rewritten) => rewritten,
+            None => expression.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod rewrite {
+    use super::*;
+    use crate::base::symbol::Symbol;
+
+    #[test]
+    fn rewrites_matching_node() {
+        let rule = PatternRule::new(
+            Pattern::parse("_a * _b + _a * _c").unwrap(),
+            Expression::parse("_a * (_b + _c)").unwrap(),
+        );
+
+        let a = &Symbol::variable("a").expr();
+        let x = &Symbol::variable("x").expr();
+        let y = &Symbol::variable("y").expr();
+
+        assert_eq!(rule.rewrite(&(a * x + a * y)), a * (x + y));
+        assert_eq!(rule.rewrite(&(x + y)), x + y);
+    }
+}
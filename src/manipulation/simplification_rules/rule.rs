@@ -9,3 +9,18 @@ use crate::base::expression::Expression;
 pub trait Rule {
     fn apply(expression: &Expression) -> Expression;
 }
+
+/**
+ *  Object safe Transformation Rule, so rules can be assembled at runtime in a RuleSet
+ *      - same contract as Rule: it won't search further than 1 Expression Node deep
+ *      - every Rule is also a RewriteRule
+ */
+pub trait RewriteRule {
+    fn rewrite(&self, expression: &Expression) -> Expression;
+}
+
+impl<R: Rule> RewriteRule for R {
+    fn rewrite(&self, expression: &Expression) -> Expression {
+        R::apply(expression)
+    }
+}
@@ -0,0 +1,104 @@
+use crate::base::expression::Expression;
+use crate::manipulation::simplification_rules::{
+    factoring::{
+        additive_common_addend::AdditiveCommonAddend,
+        multiplicative_common_factor::MultiplicativeCommonFactor,
+    },
+    identities::{
+        double_angle::DoubleAngle, inverse_power_log::InversePowerLog,
+        pitagorean_identity::PitagoricIdentity, tangent_quotient::TangentQuotient,
+    },
+    rule::RewriteRule,
+};
+
+/**
+ * Ordered collection of rules applied bottom-up until a fixed point is reached
+ *  - each pass rewrites the children first, then applies every rule on the node
+ *  - passes stop when the expression no longer changes or the iteration limit is hit
+ */
+pub struct RuleSet {
+    rules: Vec<Box<dyn RewriteRule>>,
+    iteration_limit: usize,
+}
+
+impl RuleSet {
+    pub const DEFAULT_ITERATION_LIMIT: usize = 16;
+
+    /**
+     * Empty rule set
+     */
+    pub fn new() -> Self {
+        Self {
+            rules: Vec::new(),
+            iteration_limit: Self::DEFAULT_ITERATION_LIMIT,
+        }
+    }
+
+    /**
+     * Built-in rules used by `Expression::simplify`
+     */
+    pub fn simplification() -> Self {
+        let mut rule_set = Self::new();
+        rule_set
+            .add(MultiplicativeCommonFactor {})
+            .add(AdditiveCommonAddend {})
+            .add(InversePowerLog {})
+            .add(PitagoricIdentity {})
+            .add(TangentQuotient {})
+            .add(DoubleAngle {});
+        rule_set
+    }
+
+    /**
+     * Appends a rule, applied after the existing ones
+     */
+    pub fn add<R: RewriteRule + 'static>(&mut self, rule: R) -> &mut Self {
+        self.rules.push(Box::new(rule));
+        self
+    }
+
+    pub fn set_iteration_limit(&mut self, iteration_limit: usize) -> &mut Self {
+        self.iteration_limit = iteration_limit;
+        self
+    }
+
+    pub fn iteration_limit(&self) -> usize {
+        self.iteration_limit
+    }
+
+    pub fn len(&self) -> usize {
+        self.rules.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rules.is_empty()
+    }
+
+    /**
+     * Rewrites the expression up to a fixed point
+     */
+    pub fn apply(&self, expression: &Expression) -> Expression {
+        let mut current = expression.clone();
+        for _ in 0..self.iteration_limit {
+            let rewritten = self.bottom_up(&current);
+            if rewritten == current {
+                break;
+            }
+            current = rewritten;
+        }
+        current
+    }
+
+    fn bottom_up(&self, expression: &Expression) -> Expression {
+        let rewritten_children = expression.map_children(&|child| self.bottom_up(child));
+        self.rules
+            .iter()
+            .fold(rewritten_children, |node, rule| rule.rewrite(&node))
+    }
+}
+
+impl Default for RuleSet {
+    fn default() -> Self {
+        Self::new()
+    }
+}
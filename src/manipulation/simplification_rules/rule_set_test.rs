@@ -0,0 +1,118 @@
+#[cfg(test)]
+mod apply {
+    use crate::base::{expression::Expression, symbol::Symbol};
+    use crate::manipulation::pattern_matchable::Pattern;
+    use crate::manipulation::simplification_rules::{
+        factoring::additive_common_addend::AdditiveCommonAddend, pattern_rule::PatternRule,
+        rule::RewriteRule, rule_set::RuleSet,
+    };
+
+    /* Custom rule with state: renames a variable */
+    struct Rename {
+        from: String,
+        to: String,
+    }
+    impl RewriteRule for Rename {
+        fn rewrite(&self, expression: &Expression) -> Expression {
+            match expression {
+                Expression::Variable(v) if v.data == self.from => Symbol::variable(&self.to).expr(),
+                _ => expression.clone(),
+            }
+        }
+    }
+
+    #[test]
+    fn empty_rule_set_is_identity() {
+        let expression = Expression::parse("a + a").unwrap();
+        assert!(RuleSet::new().is_empty());
+        assert_eq!(RuleSet::new().apply(&expression), expression);
+    }
+
+    #[test]
+    fn applies_bottom_up() {
+        let mut rule_set = RuleSet::new();
+        rule_set.add(Rename {
+            from: String::from("a"),
+            to: String::from("b"),
+        });
+
+        let expression = Expression::parse("sin(a^2) + a").unwrap();
+        assert_eq!(
+            rule_set.apply(&expression),
+            Expression::parse("sin(b^2) + b").unwrap()
+        );
+    }
+
+    #[test]
+    fn mixes_builtin_and_declarative_rules() {
+        let mut rule_set = RuleSet::new();
+        rule_set.add(AdditiveCommonAddend {}).add(PatternRule::new(
+            Pattern::parse("sin(_u)^2 + cos(_u)^2 + _r").unwrap(),
+            Expression::parse("1 + _r").unwrap(),
+        ));
+
+        let expression = Expression::parse("x + sin(y)^2 + x + cos(y)^2").unwrap();
+        assert_eq!(
+            rule_set.apply(&expression),
+            Symbol::integer(2).expr() * Symbol::variable("x").expr() + Symbol::integer(1).expr()
+        );
+    }
+
+    #[test]
+    fn reaches_fixed_point() {
+        let mut rule_set = RuleSet::new();
+        rule_set
+            .add(Rename {
+                from: String::from("a"),
+                to: String::from("b"),
+            })
+            .add(Rename {
+                from: String::from("c"),
+                to: String::from("a"),
+            });
+
+        /* c -> a needs a second pass to become b */
+        assert_eq!(
+            rule_set.apply(&Symbol::variable("c").expr()),
+            Symbol::variable("b").expr()
+        );
+    }
+
+    #[test]
+    fn respects_iteration_limit() {
+        let mut rule_set = RuleSet::new();
+        rule_set
+            .add(Rename {
+                from: String::from("a"),
+                to: String::from("b"),
+            })
+            .add(Rename {
+                from: String::from("c"),
+                to: String::from("a"),
+            })
+            .set_iteration_limit(1);
+
+        assert_eq!(rule_set.iteration_limit(), 1);
+        assert_eq!(
+            rule_set.apply(&Symbol::variable("c").expr()),
+            Symbol::variable("a").expr()
+        );
+    }
+
+    #[test]
+    fn simplifies_with_custom_rule_set() {
+        let mut rule_set = RuleSet::simplification();
+        rule_set.add(PatternRule::new(
+            Pattern::parse("_k * sin(_u)^2 + _k * cos(_u)^2 + _r").unwrap(),
+            Expression::parse("_k + _r").unwrap(),
+        ));
+
+        let x = &Symbol::variable("x").expr();
+        let fx = Expression::parse("(x^2 + 1) / (x * (sin(x)^2 + cos(x)^2))").unwrap();
+
+        assert_eq!(
+            fx.simplify_with(&rule_set),
+            x + Symbol::integer(1).expr() / x
+        );
+    }
+}
@@ -0,0 +1,85 @@
+// =================================== //
+//      Recursion on Expression        //
+// =================================== //
+use crate::base::{expression::Expression, symbol::Symbol};
+
+impl Expression {
+    /**
+     * Checks whether the variable occurs anywhere in the expression tree
+     */
+    pub fn is_free_of(&self, variable: &Symbol<String>) -> bool {
+        match self {
+            Expression::Variable(v) => v != variable,
+            Expression::Integer(_)
+            | Expression::Rational(_)
+            | Expression::Real(_)
+            | Expression::Constant(_) => true,
+            Expression::Addition(items) | Expression::Multiplication(items) => {
+                items.items().iter().all(|item| item.is_free_of(variable))
+            }
+            Expression::Power(op) | Expression::Logarithm(op) => {
+                op.argument().is_free_of(variable) && op.modifier().is_free_of(variable)
+            }
+            Expression::Sine(op)
+            | Expression::Cossine(op)
+            | Expression::Tangent(op)
+            | Expression::Cotangent(op)
+            | Expression::Secant(op)
+            | Expression::Cossecant(op)
+            | Expression::ArcSine(op)
+            | Expression::ArcCossine(op)
+            | Expression::ArcTangent(op)
+            | Expression::ArcCotangent(op)
+            | Expression::ArcSecant(op)
+            | Expression::ArcCossecant(op)
+            | Expression::RealPart(op)
+            | Expression::ImaginaryPart(op)
+            | Expression::Conjugate(op)
+            | Expression::Absolute(op)
+            | Expression::Argument(op) => op.argument().is_free_of(variable),
+        }
+    }
+
+    /**
+     * Variables occurring in the expression tree, sorted and without repetition
+     */
+    pub fn variables(&self) -> Vec<Symbol<String>> {
+        let mut variables: Vec<Symbol<String>> = match self {
+            Expression::Variable(v) => vec![v.clone()],
+            Expression::Integer(_)
+            | Expression::Rational(_)
+            | Expression::Real(_)
+            | Expression::Constant(_) => vec![],
+            Expression::Addition(items) | Expression::Multiplication(items) => items
+                .items()
+                .iter()
+                .flat_map(|item| item.variables())
+                .collect(),
+            Expression::Power(op) | Expression::Logarithm(op) => {
+                let mut variables = op.argument().variables();
+                variables.append(&mut op.modifier().variables());
+                variables
+            }
+            Expression::Sine(op)
+            | Expression::Cossine(op)
+            | Expression::Tangent(op)
+            | Expression::Cotangent(op)
+            | Expression::Secant(op)
+            | Expression::Cossecant(op)
+            | Expression::ArcSine(op)
+            | Expression::ArcCossine(op)
+            | Expression::ArcTangent(op)
+            | Expression::ArcCotangent(op)
+            | Expression::ArcSecant(op)
+            | Expression::ArcCossecant(op)
+            | Expression::RealPart(op)
+            | Expression::ImaginaryPart(op)
+            | Expression::Conjugate(op)
+            | Expression::Absolute(op)
+            | Expression::Argument(op) => op.argument().variables(),
+        };
+        variables.sort();
+        variables.dedup();
+        variables
+    }
+}
@@ -0,0 +1,26 @@
+#[cfg(test)]
+mod variables {
+    use crate::base::symbol::Symbol;
+    use crate::parsing::parser::parse;
+
+    #[test]
+    fn collects_sorted_variables() {
+        assert_eq!(
+            parse("y * sin(x) + x^z").variables(),
+            vec![
+                Symbol::variable("x"),
+                Symbol::variable("y"),
+                Symbol::variable("z")
+            ]
+        );
+        assert_eq!(parse("2 * pi + i").variables(), vec![]);
+    }
+
+    #[test]
+    fn checks_free_variables() {
+        let x = &Symbol::variable("x");
+        assert!(parse("y * sin(z) + 2^y").is_free_of(x));
+        assert!(!parse("y * ln(2 + cos(x))").is_free_of(x));
+        assert!(!parse("y^x").is_free_of(x));
+    }
+}
@@ -0,0 +1,334 @@
+use crate::base::{expression::Expression, symbol::Symbol};
+use crate::parsing::tokenizer::{tokenize, Token, TokenKind};
+
+/**
+ * Reasons for rejecting an infix expression
+ */
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParseErrorKind {
+    UnexpectedCharacter(char),
+    InvalidNumber(String),
+    UnexpectedToken(String),
+    UnexpectedEnd,
+    UnknownFunction(String),
+    WrongArgumentCount {
+        function: String,
+        expected: usize,
+        found: usize,
+    },
+}
+
+/**
+ * Parsing failure with the character position where it was detected
+ */
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParseError {
+    pub kind: ParseErrorKind,
+    pub position: usize,
+}
+
+impl ParseError {
+    pub fn new(kind: ParseErrorKind, position: usize) -> Self {
+        Self { kind, position }
+    }
+}
+
+impl std::fmt::Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.kind {
+            ParseErrorKind::UnexpectedCharacter(c) => {
+                write!(f, "unexpected character '{}' at {}", c, self.position)
+            }
+            ParseErrorKind::InvalidNumber(literal) => {
+                write!(f, "invalid number '{}' at {}", literal, self.position)
+            }
+            ParseErrorKind::UnexpectedToken(token) => {
+                write!(f, "unexpected token '{}' at {}", token, self.position)
+            }
+            ParseErrorKind::UnexpectedEnd => {
+                write!(f, "unexpected end of input at {}", self.position)
+            }
+            ParseErrorKind::UnknownFunction(name) => {
+                write!(f, "unknown function '{}' at {}", name, self.position)
+            }
+            ParseErrorKind::WrongArgumentCount {
+                function,
+                expected,
+                found,
+            } => write!(
+                f,
+                "function '{}' expects {} argument(s) but got {} at {}",
+                function, expected, found, self.position
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/* Binding powers: unary minus binds tighter than products, looser than powers */
+const ADDITIVE_PRECEDENCE: usize = 1;
+const MULTIPLICATIVE_PRECEDENCE: usize = 2;
+const UNARY_PRECEDENCE: usize = 3;
+const POWER_PRECEDENCE: usize = 4;
+
+/**
+ * Precedence climbing parser over a token list
+ */
+struct Parser {
+    tokens: Vec<Token>,
+    cursor: usize,
+    end_position: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.cursor)
+    }
+
+    fn next(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.cursor).cloned();
+        self.cursor += 1;
+        token
+    }
+
+    fn unexpected(token: &Token) -> ParseError {
+        ParseError::new(
+            ParseErrorKind::UnexpectedToken(format!("{}", token.kind)),
+            token.position,
+        )
+    }
+
+    fn expect(&mut self, kind: TokenKind) -> Result<Token, ParseError> {
+        match self.next() {
+            Some(token) if token.kind == kind => Ok(token),
+            Some(token) => Err(Self::unexpected(&token)),
+            None => Err(ParseError::new(
+                ParseErrorKind::UnexpectedEnd,
+                self.end_position,
+            )),
+        }
+    }
+
+    /**
+     * Binary operators as (precedence, right associativity)
+     */
+    fn binary_operator(kind: &TokenKind) -> Option<(usize, bool)> {
+        match kind {
+            TokenKind::Plus | TokenKind::Minus => Some((ADDITIVE_PRECEDENCE, false)),
+            TokenKind::Star | TokenKind::Slash => Some((MULTIPLICATIVE_PRECEDENCE, false)),
+            TokenKind::Caret => Some((POWER_PRECEDENCE, true)),
+            _ => None,
+        }
+    }
+
+    fn expression(&mut self, minimum_precedence: usize) -> Result<Expression, ParseError> {
+        let mut left_hand_side = self.prefix()?;
+
+        while let Some(token) = self.peek() {
+            let operator = token.kind.clone();
+            let (precedence, right_associative) = match Self::binary_operator(&operator) {
+                Some(binding) => binding,
+                None => break,
+            };
+            if precedence < minimum_precedence {
+                break;
+            }
+            self.next();
+
+            let next_precedence = if right_associative {
+                precedence
+            } else {
+                precedence + 1
+            };
+            let right_hand_side = self.expression(next_precedence)?;
+
+            left_hand_side = match operator {
+                TokenKind::Plus => left_hand_side + right_hand_side,
+                TokenKind::Minus => left_hand_side - right_hand_side,
+                TokenKind::Star => left_hand_side * right_hand_side,
+                TokenKind::Slash => left_hand_side / right_hand_side,
+                TokenKind::Caret => Expression::power(left_hand_side, right_hand_side),
+                _ => unreachable!(),
+            };
+        }
+
+        Ok(left_hand_side)
+    }
+
+    fn prefix(&mut self) -> Result<Expression, ParseError> {
+        match self.peek().map(|token| token.kind.clone()) {
+            Some(TokenKind::Minus) => {
+                self.next();
+                Ok(-self.expression(UNARY_PRECEDENCE)?)
+            }
+            Some(TokenKind::Plus) => {
+                self.next();
+                self.expression(UNARY_PRECEDENCE)
+            }
+            _ => self.primary(),
+        }
+    }
+
+    fn primary(&mut self) -> Result<Expression, ParseError> {
+        let token = match self.next() {
+            Some(token) => token,
+            None => {
+                return Err(ParseError::new(
+                    ParseErrorKind::UnexpectedEnd,
+                    self.end_position,
+                ))
+            }
+        };
+
+        match &token.kind {
+            TokenKind::Integer(n) => Ok(Expression::Integer(Symbol { data: n.clone() })),
+            TokenKind::Real(r) => Ok(Symbol::real(*r).expr()),
+            TokenKind::LeftParenthesis => {
+                let inner = self.expression(ADDITIVE_PRECEDENCE)?;
+                self.expect(TokenKind::RightParenthesis)?;
+                Ok(inner)
+            }
+            TokenKind::Identifier(name) => match self.peek() {
+                Some(Token {
+                    kind: TokenKind::LeftParenthesis,
+                    ..
+                }) => {
+                    let arguments = self.arguments()?;
+                    Self::function(name, arguments, token.position)
+                }
+                _ => match Symbol::from_label(name) {
+                    Some(constant) => Ok(constant.expr()),
+                    None => Ok(Symbol::variable(name).expr()),
+                },
+            },
+            _ => Err(Self::unexpected(&token)),
+        }
+    }
+
+    /**
+     * Comma separated expressions between parentheses
+     */
+    fn arguments(&mut self) -> Result<Vec<Expression>, ParseError> {
+        self.expect(TokenKind::LeftParenthesis)?;
+        let mut arguments: Vec<Expression> = vec![self.expression(ADDITIVE_PRECEDENCE)?];
+
+        loop {
+            match self.next() {
+                Some(Token {
+                    kind: TokenKind::Comma,
+                    ..
+                }) => arguments.push(self.expression(ADDITIVE_PRECEDENCE)?),
+                Some(Token {
+                    kind: TokenKind::RightParenthesis,
+                    ..
+                }) => return Ok(arguments),
+                Some(token) => return Err(Self::unexpected(&token)),
+                None => {
+                    return Err(ParseError::new(
+                        ParseErrorKind::UnexpectedEnd,
+                        self.end_position,
+                    ))
+                }
+            }
+        }
+    }
+
+    fn function(
+        name: &str,
+        mut arguments: Vec<Expression>,
+        position: usize,
+    ) -> Result<Expression, ParseError> {
+        let expected = match name {
+            "sin" | "cos" | "tan" | "cot" | "sec" | "csc" => 1,
+            "asin" | "acos" | "atan" | "acot" | "asec" | "acsc" => 1,
+            "ln" | "exp" => 1,
+            "re" | "im" | "conj" | "abs" | "arg" => 1,
+            "log" => 2,
+            _ => {
+                return Err(ParseError::new(
+                    ParseErrorKind::UnknownFunction(String::from(name)),
+                    position,
+                ))
+            }
+        };
+
+        if arguments.len() != expected {
+            return Err(ParseError::new(
+                ParseErrorKind::WrongArgumentCount {
+                    function: String::from(name),
+                    expected,
+                    found: arguments.len(),
+                },
+                position,
+            ));
+        }
+
+        let argument = arguments.remove(0);
+        match name {
+            "sin" => Ok(Expression::sin(argument)),
+            "cos" => Ok(Expression::cos(argument)),
+            "tan" => Ok(Expression::tan(argument)),
+            "cot" => Ok(Expression::cot(argument)),
+            "sec" => Ok(Expression::sec(argument)),
+            "csc" => Ok(Expression::csc(argument)),
+            "asin" => Ok(Expression::asin(argument)),
+            "acos" => Ok(Expression::acos(argument)),
+            "atan" => Ok(Expression::atan(argument)),
+            "acot" => Ok(Expression::acot(argument)),
+            "asec" => Ok(Expression::asec(argument)),
+            "acsc" => Ok(Expression::acsc(argument)),
+            "ln" => Ok(Expression::ln(argument)),
+            "exp" => Ok(Expression::exp(argument)),
+            "re" => Ok(Expression::re(argument)),
+            "im" => Ok(Expression::im(argument)),
+            "conj" => Ok(Expression::conjugate(argument)),
+            "abs" => Ok(Expression::abs(argument)),
+            "arg" => Ok(Expression::arg(argument)),
+            "log" => Ok(Expression::logarithm(argument, arguments.remove(0))),
+            _ => unreachable!(),
+        }
+    }
+}
+
+impl Expression {
+    /**
+     * Builds an expression from infix notation
+     *  - integers are parsed as BigInt, numbers with decimal point as reals
+     *  - supports `+ - * / ^`, unary minus, parentheses
+     *  - functions: `sin(x)`, `cos(x)`, `tan(x)`, `cot(x)`, `sec(x)`, `csc(x)`, their inverses
+     *    `asin(x)` through `acsc(x)`, `ln(x)`, `exp(x)`, `log(argument, base)`
+     *  - complex functions: `re(z)`, `im(z)`, `conj(z)`, `abs(z)`, `arg(z)`
+     *  - constants: `pi`, `e`, `i`, `phi` (golden ratio), `gamma` (Euler-Mascheroni)
+     *  - any other identifier is a variable
+     */
+    pub fn parse(input: &str) -> Result<Expression, ParseError> {
+        let mut parser = Parser {
+            tokens: tokenize(input)?,
+            cursor: 0,
+            end_position: input.chars().count(),
+        };
+
+        let expression = parser.expression(ADDITIVE_PRECEDENCE)?;
+
+        match parser.next() {
+            Some(token) => Err(Parser::unexpected(&token)),
+            None => Ok(expression),
+        }
+    }
+}
+
+impl std::str::FromStr for Expression {
+    type Err = ParseError;
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        Expression::parse(input)
+    }
+}
+
+/**
+ * Parses a test fixture, panicking on malformed input
+ */
+#[cfg(test)]
+pub(crate) fn parse(input: &str) -> Expression {
+    Expression::parse(input).unwrap()
+}
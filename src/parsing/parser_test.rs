@@ -0,0 +1,218 @@
+#[cfg(test)]
+mod parse {
+    use crate::base::{expression::Expression, operation::Operation, symbol::Symbol};
+
+    #[test]
+    fn parses_symbols() {
+        assert_eq!(Expression::parse("42"), Ok(Symbol::integer(42).expr()));
+        assert_eq!(Expression::parse("4.5"), Ok(Symbol::real(4.5).expr()));
+        assert_eq!(Expression::parse("1e3"), Ok(Symbol::real(1000.0).expr()));
+        assert_eq!(Expression::parse("x_1"), Ok(Symbol::variable("x_1").expr()));
+    }
+
+    #[test]
+    fn parses_big_integers() {
+        let parsed = Expression::parse("123456789012345678901234567890").unwrap();
+        match parsed {
+            Expression::Integer(n) => {
+                assert_eq!(n.label(), String::from("123456789012345678901234567890"))
+            }
+            _ => panic!(),
+        }
+    }
+
+    #[test]
+    fn respects_precedence() {
+        let a = &Symbol::variable("a").expr();
+        let b = &Symbol::variable("b").expr();
+        let c = &Symbol::variable("c").expr();
+
+        assert_eq!(Expression::parse("a + b * c"), Ok(a + b * c));
+        assert_eq!(Expression::parse("(a + b) * c"), Ok((a + b) * c));
+        assert_eq!(Expression::parse("a - b - c"), Ok((a - b) - c));
+        assert_eq!(Expression::parse("a / b / c"), Ok((a / b) / c));
+    }
+
+    #[test]
+    fn power_is_right_associative() {
+        let a = &Symbol::variable("a").expr();
+        let b = &Symbol::variable("b").expr();
+        let c = &Symbol::variable("c").expr();
+
+        assert_eq!(
+            Expression::parse("a ^ b ^ c"),
+            Ok(a.clone().pow(b.clone().pow(c.clone())))
+        );
+    }
+
+    #[test]
+    fn unary_minus() {
+        let x = &Symbol::variable("x").expr();
+        let two = &Symbol::integer(2).expr();
+
+        assert_eq!(Expression::parse("-3"), Ok(Symbol::integer(-3).expr()));
+        assert_eq!(Expression::parse("-x^2"), Ok(-(x.clone().pow(two.clone()))));
+        assert_eq!(Expression::parse("x^-2"), Ok(x.clone().pow(-two)));
+        assert_eq!(Expression::parse("2 * -x"), Ok(two * -x));
+    }
+
+    #[test]
+    fn parses_functions() {
+        let x = &Symbol::variable("x").expr();
+        let b = &Symbol::variable("b").expr();
+
+        assert_eq!(
+            Expression::parse("sin(x)"),
+            Ok(Expression::Sine(Operation::new(x.clone())))
+        );
+        assert_eq!(
+            Expression::parse("cos(x + b)"),
+            Ok(Expression::Cossine(Operation::new(x + b)))
+        );
+        assert_eq!(
+            Expression::parse("log(x, b)"),
+            Ok(Expression::logarithm(x.clone(), b.clone()))
+        );
+        assert_eq!(
+            Expression::parse("tan(x)"),
+            Ok(Expression::Tangent(Operation::new(x.clone())))
+        );
+        assert_eq!(
+            Expression::parse("acsc(b)"),
+            Ok(Expression::ArcCossecant(Operation::new(b.clone())))
+        );
+    }
+
+    #[test]
+    fn parses_constants() {
+        assert_eq!(Expression::parse("pi"), Ok(Symbol::pi().expr()));
+        assert_eq!(Expression::parse("e"), Ok(Symbol::e().expr()));
+        assert_eq!(Expression::parse("i"), Ok(Symbol::i().expr()));
+        assert_eq!(Expression::parse("phi"), Ok(Symbol::golden_ratio().expr()));
+        assert_eq!(Expression::parse("gamma"), Ok(Symbol::euler_gamma().expr()));
+        assert_eq!(
+            Expression::parse("2 * pi"),
+            Ok(Symbol::integer(2).expr() * Symbol::pi().expr())
+        );
+    }
+
+    #[test]
+    fn parses_readme_sample() {
+        let x = &Symbol::variable("x").expr();
+        let one = &Symbol::integer(1).expr();
+        let two = &Symbol::integer(2).expr();
+        let sin = Expression::Sine(Operation::new(x.clone()));
+        let cos = Expression::Cossine(Operation::new(x.clone()));
+
+        let expected = (x.clone().pow(two.clone()) + one)
+            / (x * (sin.pow(two.clone()) + cos.pow(two.clone())));
+
+        assert_eq!(
+            Expression::parse("(x^2+1)/(x*(sin(x)^2+cos(x)^2))"),
+            Ok(expected)
+        );
+    }
+
+    #[test]
+    fn implements_from_str() {
+        let parsed: Expression = "a * b".parse().unwrap();
+        assert_eq!(
+            parsed,
+            Symbol::variable("a").expr() * Symbol::variable("b").expr()
+        );
+    }
+}
+
+#[cfg(test)]
+mod errors {
+    use crate::base::expression::Expression;
+    use crate::parsing::parser::{ParseError, ParseErrorKind};
+
+    #[test]
+    fn unexpected_character() {
+        assert_eq!(
+            Expression::parse("a + $"),
+            Err(ParseError::new(ParseErrorKind::UnexpectedCharacter('$'), 4))
+        );
+    }
+
+    #[test]
+    fn unexpected_end() {
+        assert_eq!(
+            Expression::parse("a *"),
+            Err(ParseError::new(ParseErrorKind::UnexpectedEnd, 3))
+        );
+        assert_eq!(
+            Expression::parse("(a + b"),
+            Err(ParseError::new(ParseErrorKind::UnexpectedEnd, 6))
+        );
+    }
+
+    #[test]
+    fn unexpected_token() {
+        assert_eq!(
+            Expression::parse("a b"),
+            Err(ParseError::new(
+                ParseErrorKind::UnexpectedToken(String::from("b")),
+                2
+            ))
+        );
+        assert_eq!(
+            Expression::parse("a + )"),
+            Err(ParseError::new(
+                ParseErrorKind::UnexpectedToken(String::from(")")),
+                4
+            ))
+        );
+    }
+
+    #[test]
+    fn invalid_number() {
+        assert_eq!(
+            Expression::parse("1.2.3"),
+            Err(ParseError::new(
+                ParseErrorKind::UnexpectedToken(String::from("0.3")),
+                3
+            ))
+        );
+        assert_eq!(
+            Expression::parse("."),
+            Err(ParseError::new(
+                ParseErrorKind::InvalidNumber(String::from(".")),
+                0
+            ))
+        );
+    }
+
+    #[test]
+    fn unknown_function() {
+        assert_eq!(
+            Expression::parse("2 * foo(x)"),
+            Err(ParseError::new(
+                ParseErrorKind::UnknownFunction(String::from("foo")),
+                4
+            ))
+        );
+    }
+
+    #[test]
+    fn wrong_argument_count() {
+        assert_eq!(
+            Expression::parse("log(x)"),
+            Err(ParseError::new(
+                ParseErrorKind::WrongArgumentCount {
+                    function: String::from("log"),
+                    expected: 2,
+                    found: 1,
+                },
+                0
+            ))
+        );
+    }
+
+    #[test]
+    fn displays_position() {
+        let error = Expression::parse("a + $").unwrap_err();
+        assert_eq!(format!("{}", error), "unexpected character '$' at 4");
+    }
+}
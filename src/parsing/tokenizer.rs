@@ -0,0 +1,165 @@
+use crate::parsing::parser::{ParseError, ParseErrorKind};
+use num::bigint::BigInt;
+
+/**
+ * Lexical units of an infix expression
+ */
+#[derive(Debug, Clone, PartialEq)]
+pub enum TokenKind {
+    /* Literals */
+    Integer(BigInt),
+    Real(f64),
+    Identifier(String),
+
+    /* Operators */
+    Plus,
+    Minus,
+    Star,
+    Slash,
+    Caret,
+
+    /* Delimiters */
+    LeftParenthesis,
+    RightParenthesis,
+    Comma,
+}
+
+/**
+ * Token tagged with the character position where it starts
+ */
+#[derive(Debug, Clone, PartialEq)]
+pub struct Token {
+    pub kind: TokenKind,
+    pub position: usize,
+}
+
+impl std::fmt::Display for TokenKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TokenKind::Integer(n) => write!(f, "{}", n),
+            TokenKind::Real(r) => write!(f, "{}", r),
+            TokenKind::Identifier(name) => write!(f, "{}", name),
+            TokenKind::Plus => write!(f, "+"),
+            TokenKind::Minus => write!(f, "-"),
+            TokenKind::Star => write!(f, "*"),
+            TokenKind::Slash => write!(f, "/"),
+            TokenKind::Caret => write!(f, "^"),
+            TokenKind::LeftParenthesis => write!(f, "("),
+            TokenKind::RightParenthesis => write!(f, ")"),
+            TokenKind::Comma => write!(f, ","),
+        }
+    }
+}
+
+/**
+ * Splits an infix expression into tokens
+ *  - whitespaces are ignored
+ *  - numbers with a decimal point or an exponent are reals, otherwise integers
+ */
+pub fn tokenize(input: &str) -> Result<Vec<Token>, ParseError> {
+    let characters: Vec<char> = input.chars().collect();
+    let mut tokens: Vec<Token> = Vec::new();
+    let mut position: usize = 0;
+
+    while position < characters.len() {
+        let character = characters[position];
+
+        if character.is_whitespace() {
+            position += 1;
+            continue;
+        }
+
+        let single_character_kind = match character {
+            '+' => Some(TokenKind::Plus),
+            '-' => Some(TokenKind::Minus),
+            '*' => Some(TokenKind::Star),
+            '/' => Some(TokenKind::Slash),
+            '^' => Some(TokenKind::Caret),
+            '(' => Some(TokenKind::LeftParenthesis),
+            ')' => Some(TokenKind::RightParenthesis),
+            ',' => Some(TokenKind::Comma),
+            _ => None,
+        };
+
+        if let Some(kind) = single_character_kind {
+            tokens.push(Token { kind, position });
+            position += 1;
+        } else if character.is_ascii_digit() || character == '.' {
+            let (kind, length) = read_number(&characters[position..], position)?;
+            tokens.push(Token { kind, position });
+            position += length;
+        } else if character.is_alphabetic() || character == '_' {
+            let length = characters[position..]
+                .iter()
+                .take_while(|c| c.is_alphanumeric() || **c == '_')
+                .count();
+            let name: String = characters[position..position + length].iter().collect();
+            tokens.push(Token {
+                kind: TokenKind::Identifier(name),
+                position,
+            });
+            position += length;
+        } else {
+            return Err(ParseError::new(
+                ParseErrorKind::UnexpectedCharacter(character),
+                position,
+            ));
+        }
+    }
+
+    Ok(tokens)
+}
+
+/**
+ * Reads the longest numeric literal at the start of the slice
+ *  - returns the token kind and the amount of characters consumed
+ */
+fn read_number(characters: &[char], position: usize) -> Result<(TokenKind, usize), ParseError> {
+    let mut length = characters.iter().take_while(|c| c.is_ascii_digit()).count();
+    let mut is_real = false;
+
+    /* fractional part */
+    if length < characters.len() && characters[length] == '.' {
+        is_real = true;
+        length += 1;
+        length += characters[length..]
+            .iter()
+            .take_while(|c| c.is_ascii_digit())
+            .count();
+    }
+
+    /* scientific notation exponent: only consumed if digits follow */
+    if length < characters.len() && (characters[length] == 'e' || characters[length] == 'E') {
+        let mut exponent_length = 1;
+        if length + exponent_length < characters.len()
+            && (characters[length + exponent_length] == '+'
+                || characters[length + exponent_length] == '-')
+        {
+            exponent_length += 1;
+        }
+        let exponent_digits = characters[length + exponent_length..]
+            .iter()
+            .take_while(|c| c.is_ascii_digit())
+            .count();
+        if exponent_digits > 0 {
+            is_real = true;
+            length += exponent_length + exponent_digits;
+        }
+    }
+
+    let literal: String = characters[..length].iter().collect();
+    let invalid_number =
+        || ParseError::new(ParseErrorKind::InvalidNumber(literal.clone()), position);
+
+    if is_real {
+        match literal.parse::<f64>() {
+            Ok(value) => Ok((TokenKind::Real(value), length)),
+            Err(_) => Err(invalid_number()),
+        }
+    } else {
+        match literal.parse::<BigInt>() {
+            Ok(value) => Ok((TokenKind::Integer(value), length)),
+            Err(_) => Err(invalid_number()),
+        }
+    }
+}
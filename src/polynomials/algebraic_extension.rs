@@ -0,0 +1,82 @@
+use crate::base::{expression::Expression, symbol::Symbol};
+use crate::polynomials::polynomial::Polynomial;
+use num::rational::BigRational;
+
+/**
+ * Algebraic number field Q(a), for a generator a given by its minimal polynomial
+ *  - elements are polynomials in the generator of degree below the minimal polynomial
+ */
+#[derive(Debug, Clone, PartialEq)]
+pub struct AlgebraicExtension {
+    generator: Symbol<String>,
+    minimal: Polynomial,
+}
+
+impl AlgebraicExtension {
+    /**
+     * `None` unless the minimal polynomial is irreducible over the rationals
+     *  - Q(sqrt(2)) from a^2 - 2 in the generator a
+     */
+    pub fn new(generator: Symbol<String>, minimal: &Expression) -> Option<Self> {
+        let minimal = minimal.as_polynomial(&generator)?;
+        match minimal.factor_integer().1.as_slice() {
+            [(_, 1)] => {}
+            _ => return None,
+        }
+        let minimal = minimal.scale(&minimal.leading_coefficient().inverse()?);
+        Some(Self { generator, minimal })
+    }
+
+    pub fn generator(&self) -> &Symbol<String> {
+        &self.generator
+    }
+
+    /**
+     * Monic minimal polynomial of the generator
+     */
+    pub fn minimal(&self) -> &Polynomial {
+        &self.minimal
+    }
+
+    pub fn degree(&self) -> usize {
+        self.minimal.degree().unwrap_or(0)
+    }
+
+    pub fn element(&self, value: &Polynomial) -> Polynomial {
+        match value.div_rem(&self.minimal) {
+            Some((_, remainder)) => remainder,
+            None => value.clone(),
+        }
+    }
+
+    pub fn constant(&self, value: Symbol<BigRational>) -> Polynomial {
+        Polynomial::constant(self.generator.clone(), value)
+    }
+
+    pub fn mul(&self, left: &Polynomial, right: &Polynomial) -> Polynomial {
+        self.element(&(left * right))
+    }
+
+    /**
+     * Multiplicative inverse by the extended Euclidean algorithm, `None` for zero
+     */
+    pub fn inverse(&self, value: &Polynomial) -> Option<Polynomial> {
+        let value = self.element(value);
+        if value.is_zero() {
+            return None;
+        }
+        let (mut r0, mut r1) = (self.minimal.clone(), value);
+        let (mut t0, mut t1) = (
+            Polynomial::zero(self.generator.clone()),
+            self.constant(Symbol::rational(1, 1)),
+        );
+        while let Some((quotient, remainder)) = r0.div_rem(&r1) {
+            let t = &t0 - &(&quotient * &t1);
+            r0 = std::mem::replace(&mut r1, remainder);
+            t0 = std::mem::replace(&mut t1, t);
+        }
+
+        /* the minimal polynomial is irreducible, so r0 is a non zero constant */
+        Some(self.element(&t0.scale(&r0.leading_coefficient().inverse()?)))
+    }
+}
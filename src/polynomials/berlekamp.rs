@@ -0,0 +1,149 @@
+use crate::polynomials::{
+    finite_field::FiniteField,
+    finite_field_polynomial::{sort_factors, FiniteFieldPolynomial},
+};
+use num::bigint::BigInt;
+use num::{One, Zero};
+
+impl FiniteFieldPolynomial {
+    /**
+     * Factorisation into monic irreducible factors by Berlekamp's algorithm
+     *  - self == leading coefficient * product of factors^multiplicity
+     *  - splitting tries every element of the field, so the cost grows linearly with p
+     *  - the zero polynomial has no factors
+     */
+    pub fn factor_berlekamp(&self) -> (BigInt, Vec<(FiniteFieldPolynomial, usize)>) {
+        if self.is_zero() {
+            return (BigInt::zero(), vec![]);
+        }
+        let leading = self.leading_coefficient();
+        let mut factors: Vec<(FiniteFieldPolynomial, usize)> = self
+            .monic()
+            .squarefree_decomposition()
+            .iter()
+            .flat_map(|(factor, multiplicity)| {
+                berlekamp(factor)
+                    .into_iter()
+                    .map(move |irreducible| (irreducible, *multiplicity))
+            })
+            .collect();
+        sort_factors(&mut factors);
+        (leading, factors)
+    }
+}
+
+/**
+ * Splits a monic square-free polynomial with the null space of Q - I
+ *  - row i of Q holds x^(i p) mod f, and each null vector v gives v(x)^p == v(x) mod f
+ *  - the dimension of the null space is the number of irreducible factors
+ */
+fn berlekamp(polynomial: &FiniteFieldPolynomial) -> Vec<FiniteFieldPolynomial> {
+    let field = polynomial.field().clone();
+    let degree = polynomial.degree().unwrap_or(0);
+    if degree <= 1 {
+        return vec![polynomial.clone()];
+    }
+
+    /* transposed Q - I, whose null space holds the coefficients of v */
+    let x = FiniteFieldPolynomial::monomial(field.clone(), BigInt::one(), 1);
+    let frobenius = x.pow_mod(field.modulus(), polynomial);
+    let mut row = FiniteFieldPolynomial::one(field.clone());
+    let mut matrix = vec![vec![BigInt::zero(); degree]; degree];
+    for i in 0..degree {
+        for (j, line) in matrix.iter_mut().enumerate() {
+            line[i] = row.coefficient(j);
+        }
+        matrix[i][i] = field.sub(&matrix[i][i], &BigInt::one());
+        row = (&row * &frobenius).div_rem(polynomial).unwrap().1;
+    }
+
+    let basis = null_space(&field, matrix);
+    let mut factors = vec![polynomial.clone()];
+    for vector in basis.iter() {
+        if factors.len() == basis.len() {
+            break;
+        }
+        let v = FiniteFieldPolynomial::new(field.clone(), vector.clone());
+        if v.degree() < Some(1) {
+            continue;
+        }
+        factors = factors
+            .iter()
+            .flat_map(|factor| split(factor, &v))
+            .collect();
+    }
+    factors
+}
+
+/**
+ * Non trivial gcd(factor, v - s) for every s in GF(p)
+ */
+fn split(factor: &FiniteFieldPolynomial, v: &FiniteFieldPolynomial) -> Vec<FiniteFieldPolynomial> {
+    let field = factor.field().clone();
+    let degree = factor.degree().unwrap_or(0);
+    if degree <= 1 {
+        return vec![factor.clone()];
+    }
+
+    let mut parts = vec![];
+    let mut found = 0;
+    let mut shift = BigInt::zero();
+    while found < degree && &shift < field.modulus() {
+        let constant = FiniteFieldPolynomial::new(field.clone(), vec![shift.clone()]);
+        let part = factor.gcd(&(v - &constant));
+        if part.degree() > Some(0) {
+            found += part.degree().unwrap();
+            parts.push(part);
+        }
+        shift += 1;
+    }
+    parts
+}
+
+/**
+ * Basis of the null space by reduction to row echelon form
+ */
+fn null_space(field: &FiniteField, matrix: Vec<Vec<BigInt>>) -> Vec<Vec<BigInt>> {
+    let mut matrix = matrix;
+    let columns = matrix.first().map(|row| row.len()).unwrap_or(0);
+    let mut pivots: Vec<usize> = vec![];
+
+    for column in 0..columns {
+        let rank = pivots.len();
+        let pivot = (rank..matrix.len()).find(|&row| !matrix[row][column].is_zero());
+        let pivot = match pivot {
+            Some(pivot) => pivot,
+            None => continue,
+        };
+        matrix.swap(rank, pivot);
+
+        let inverse = field.inverse(&matrix[rank][column]).unwrap();
+        matrix[rank] = matrix[rank]
+            .iter()
+            .map(|entry| field.mul(entry, &inverse))
+            .collect();
+        for row in 0..matrix.len() {
+            if row != rank && !matrix[row][column].is_zero() {
+                let factor = matrix[row][column].clone();
+                matrix[row] = matrix[row]
+                    .iter()
+                    .zip(matrix[rank].iter())
+                    .map(|(entry, pivot)| field.sub(entry, &field.mul(&factor, pivot)))
+                    .collect();
+            }
+        }
+        pivots.push(column);
+    }
+
+    (0..columns)
+        .filter(|column| !pivots.contains(column))
+        .map(|free| {
+            let mut vector = vec![BigInt::zero(); columns];
+            vector[free] = BigInt::one();
+            for (row, pivot) in pivots.iter().enumerate() {
+                vector[*pivot] = field.neg(&matrix[row][free]);
+            }
+            vector
+        })
+        .collect()
+}
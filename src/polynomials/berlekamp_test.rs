@@ -0,0 +1,99 @@
+#[cfg(test)]
+mod factorisation {
+    use crate::polynomials::{
+        finite_field::FiniteField,
+        finite_field_polynomial::{polynomial, FiniteFieldPolynomial},
+    };
+    use num::bigint::BigInt;
+
+    fn product(
+        leading: &BigInt,
+        factors: &[(FiniteFieldPolynomial, usize)],
+    ) -> FiniteFieldPolynomial {
+        let field = factors[0].0.field().clone();
+        factors.iter().fold(
+            FiniteFieldPolynomial::new(field, vec![leading.clone()]),
+            |product, (factor, multiplicity)| {
+                (0..*multiplicity).fold(product, |product, _| &product * factor)
+            },
+        )
+    }
+
+    #[test]
+    fn splits_into_irreducibles() {
+        let modulus = BigInt::from(3);
+        /* x^4 + 1 == (x^2 + x + 2) * (x^2 + 2x + 2) over GF(3) */
+        let (leading, factors) = polynomial(&modulus, &[1, 0, 0, 0, 1]).factor_berlekamp();
+
+        assert_eq!(leading, BigInt::from(1));
+        assert_eq!(
+            factors,
+            vec![
+                (polynomial(&modulus, &[2, 1, 1]), 1),
+                (polynomial(&modulus, &[2, 2, 1]), 1)
+            ]
+        );
+    }
+
+    #[test]
+    fn splits_into_linear_factors() {
+        let modulus = BigInt::from(5);
+        /* x^5 - x over GF(5) vanishes on the whole field */
+        let (_, factors) = polynomial(&modulus, &[0, -1, 0, 0, 0, 1]).factor_berlekamp();
+
+        assert_eq!(
+            factors,
+            (0..5)
+                .map(|shift| (polynomial(&modulus, &[shift, 1]), 1))
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn keeps_irreducibles() {
+        let modulus = BigInt::from(2);
+        let p = polynomial(&modulus, &[1, 1, 0, 1]);
+
+        assert_eq!(p.factor_berlekamp(), (BigInt::from(1), vec![(p, 1)]));
+    }
+
+    #[test]
+    fn recovers_multiplicities_and_leading_coefficient() {
+        let modulus = BigInt::from(5);
+        /* 3 * (x + 1)^5 * (x^2 + 1) over GF(5), where x^2 + 1 == (x + 2) * (x + 3) */
+        let linear = polynomial(&modulus, &[1, 1]);
+        let p = (0..5).fold(polynomial(&modulus, &[3, 0, 3]), |p, _| &p * &linear);
+        let (leading, factors) = p.factor_berlekamp();
+
+        assert_eq!(leading, BigInt::from(3));
+        assert_eq!(
+            factors,
+            vec![
+                (polynomial(&modulus, &[1, 1]), 5),
+                (polynomial(&modulus, &[2, 1]), 1),
+                (polynomial(&modulus, &[3, 1]), 1)
+            ]
+        );
+        assert_eq!(product(&leading, &factors), p);
+    }
+
+    #[test]
+    fn leaves_zero_unfactored() {
+        let zero = FiniteFieldPolynomial::zero(FiniteField::new(BigInt::from(5)).unwrap());
+
+        assert_eq!(zero.factor_berlekamp(), (BigInt::from(0), vec![]));
+    }
+
+    #[test]
+    fn large_product() {
+        let modulus = BigInt::from(101);
+        let p = &(&polynomial(&modulus, &[3, 0, 1, 7]) * &polynomial(&modulus, &[5, 1, 0, 0, 1]))
+            * &polynomial(&modulus, &[-1, 1]);
+        let (leading, factors) = p.factor_berlekamp();
+
+        assert_eq!(product(&leading, &factors), p);
+        assert!(factors
+            .iter()
+            .all(|(factor, _)| factor.factor_berlekamp().1.len() == 1));
+    }
+}
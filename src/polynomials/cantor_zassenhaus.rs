@@ -0,0 +1,121 @@
+use crate::polynomials::{
+    finite_field_polynomial::{sort_factors, FiniteFieldPolynomial},
+    random::RandomSource,
+};
+use num::bigint::BigInt;
+use num::{One, Zero};
+
+impl FiniteFieldPolynomial {
+    /**
+     * Factorisation into monic irreducible factors by the Cantor-Zassenhaus algorithm
+     *  - self == leading coefficient * product of factors^multiplicity
+     *  - the random source only chooses the splitting polynomials, never the result
+     *  - the zero polynomial has no factors
+     */
+    pub fn factor_cantor_zassenhaus<R: RandomSource>(
+        &self,
+        random: &mut R,
+    ) -> (BigInt, Vec<(FiniteFieldPolynomial, usize)>) {
+        if self.is_zero() {
+            return (BigInt::zero(), vec![]);
+        }
+        let leading = self.leading_coefficient();
+        let mut factors = vec![];
+        for (squarefree, multiplicity) in self.monic().squarefree_decomposition() {
+            for (product, degree) in squarefree.distinct_degree_factors() {
+                for factor in product.equal_degree_factors(degree, random) {
+                    factors.push((factor, multiplicity));
+                }
+            }
+        }
+        sort_factors(&mut factors);
+        (leading, factors)
+    }
+
+    /**
+     * Splits a monic square-free polynomial into products of its irreducible factors of
+     * each degree
+     *  - x^(p^i) - x is the product of the irreducible polynomials of degree dividing i
+     */
+    pub fn distinct_degree_factors(&self) -> Vec<(FiniteFieldPolynomial, usize)> {
+        let field = self.field().clone();
+        let x = FiniteFieldPolynomial::monomial(field.clone(), BigInt::one(), 1);
+
+        let mut factors = vec![];
+        let mut remaining = self.clone();
+        let mut frobenius = x.clone();
+        let mut degree = 1;
+        while remaining.degree().unwrap_or(0) >= 2 * degree {
+            frobenius = frobenius.pow_mod(field.modulus(), &remaining);
+            let product = remaining.gcd(&(&frobenius - &x));
+            if !product.is_one() {
+                remaining = remaining.div_rem(&product).unwrap().0;
+                frobenius = frobenius.div_rem(&remaining).unwrap().1;
+                factors.push((product, degree));
+            }
+            degree += 1;
+        }
+        if let Some(last) = remaining.degree().filter(|&last| last > 0) {
+            factors.push((remaining, last));
+        }
+        factors
+    }
+
+    /**
+     * Splits a monic product of distinct irreducible polynomials of the same degree
+     *  - odd p: gcd(a^((p^d - 1) / 2) - 1, f) for random a
+     *  - p == 2: gcd(a + a^2 + a^4 + ... + a^(2^(d - 1)), f), the trace of a random a
+     */
+    pub fn equal_degree_factors<R: RandomSource>(
+        &self,
+        degree: usize,
+        random: &mut R,
+    ) -> Vec<FiniteFieldPolynomial> {
+        let total = match self.degree() {
+            Some(total) if total > degree => total,
+            _ => return vec![self.clone()],
+        };
+        let field = self.field().clone();
+        let one = FiniteFieldPolynomial::one(field.clone());
+        let two = BigInt::from(2);
+
+        loop {
+            let candidate = FiniteFieldPolynomial::new(
+                field.clone(),
+                (0..total).map(|_| random.below(field.modulus())).collect(),
+            );
+            if candidate.degree() < Some(1) {
+                continue;
+            }
+
+            let mut split = self.gcd(&candidate);
+            if split.is_one() {
+                split = match field.modulus() == &two {
+                    true => {
+                        let mut square = candidate.clone();
+                        let mut trace = candidate.clone();
+                        for _ in 1..degree {
+                            square = square.pow_mod(&two, self);
+                            trace = &trace + &square;
+                        }
+                        self.gcd(&trace)
+                    }
+                    false => {
+                        let exponent: BigInt = (field.modulus().pow(degree as u32) - 1u8) / 2u8;
+                        self.gcd(&(&candidate.pow_mod(&exponent, self) - &one))
+                    }
+                };
+            }
+
+            match split.degree() {
+                Some(found) if found > 0 && found < total => {
+                    let rest = self.div_rem(&split).unwrap().0;
+                    let mut factors = split.equal_degree_factors(degree, random);
+                    factors.append(&mut rest.equal_degree_factors(degree, random));
+                    return factors;
+                }
+                _ => continue,
+            }
+        }
+    }
+}
@@ -0,0 +1,113 @@
+#[cfg(test)]
+mod distinct_degree {
+    use crate::polynomials::finite_field_polynomial::polynomial;
+    use num::bigint::BigInt;
+
+    #[test]
+    fn groups_factors_by_degree() {
+        let modulus = BigInt::from(3);
+        /* (x + 1) * (x + 2) * (x^2 + 2) * (x^3 + 2x + 1) over GF(3) */
+        let linear = &polynomial(&modulus, &[1, 1]) * &polynomial(&modulus, &[2, 1]);
+        let quadratic = polynomial(&modulus, &[2, 0, 1]);
+        let cubic = polynomial(&modulus, &[1, 2, 0, 1]);
+        let p = &(&linear * &quadratic) * &cubic;
+
+        assert_eq!(
+            p.distinct_degree_factors(),
+            vec![(linear, 1), (quadratic, 2), (cubic, 3)]
+        );
+    }
+}
+
+#[cfg(test)]
+mod factorisation {
+    use crate::polynomials::{
+        finite_field::FiniteField,
+        finite_field_polynomial::{polynomial, FiniteFieldPolynomial},
+        random::SplitMix64,
+    };
+    use num::bigint::BigInt;
+
+    #[test]
+    fn agrees_with_berlekamp() {
+        let modulus = BigInt::from(3);
+        let p = polynomial(&modulus, &[1, 0, 0, 0, 1]);
+
+        assert_eq!(
+            p.factor_cantor_zassenhaus(&mut SplitMix64::new(7)),
+            p.factor_berlekamp()
+        );
+
+        let modulus = BigInt::from(5);
+        let linear = polynomial(&modulus, &[1, 1]);
+        let p = (0..5).fold(polynomial(&modulus, &[3, 0, 3]), |p, _| &p * &linear);
+
+        assert_eq!(
+            p.factor_cantor_zassenhaus(&mut SplitMix64::new(7)),
+            p.factor_berlekamp()
+        );
+    }
+
+    #[test]
+    fn splits_over_binary_field() {
+        /* x^8 + x == x * (x + 1) * (x^3 + x + 1) * (x^3 + x^2 + 1) over GF(2) */
+        let modulus = BigInt::from(2);
+        let p = polynomial(&modulus, &[0, 1, 0, 0, 0, 0, 0, 0, 1]);
+        let (_, factors) = p.factor_cantor_zassenhaus(&mut SplitMix64::new(1));
+
+        assert_eq!(
+            factors,
+            vec![
+                (polynomial(&modulus, &[0, 1]), 1),
+                (polynomial(&modulus, &[1, 1]), 1),
+                (polynomial(&modulus, &[1, 1, 0, 1]), 1),
+                (polynomial(&modulus, &[1, 0, 1, 1]), 1)
+            ]
+        );
+    }
+
+    #[test]
+    fn handles_large_primes() {
+        /* (x^2 + 1) * (x - 3) * (x + 5) modulo the Mersenne prime 2^127 - 1 */
+        let modulus = BigInt::from(2).pow(127u32) - 1u8;
+        let p = &(&polynomial(&modulus, &[1, 0, 1]) * &polynomial(&modulus, &[-3, 1]))
+            * &polynomial(&modulus, &[5, 1]);
+        let (leading, factors) = p.factor_cantor_zassenhaus(&mut SplitMix64::new(42));
+
+        assert_eq!(leading, BigInt::from(1));
+        assert_eq!(
+            factors,
+            vec![
+                (polynomial(&modulus, &[5, 1]), 1),
+                (polynomial(&modulus, &[-3, 1]), 1),
+                (polynomial(&modulus, &[1, 0, 1]), 1)
+            ]
+        );
+    }
+
+    #[test]
+    fn leaves_zero_unfactored() {
+        let zero = FiniteFieldPolynomial::zero(FiniteField::new(BigInt::from(5)).unwrap());
+
+        assert_eq!(
+            zero.factor_cantor_zassenhaus(&mut SplitMix64::new(1)),
+            (BigInt::from(0), vec![])
+        );
+    }
+
+    #[test]
+    fn reproducible_for_a_seed() {
+        let modulus = BigInt::from(101);
+        let p = &(&polynomial(&modulus, &[3, 0, 1, 7]) * &polynomial(&modulus, &[5, 1, 0, 0, 1]))
+            * &polynomial(&modulus, &[-1, 1]);
+
+        assert_eq!(
+            p.factor_cantor_zassenhaus(&mut SplitMix64::new(3)),
+            p.factor_cantor_zassenhaus(&mut SplitMix64::new(3))
+        );
+        assert_eq!(
+            p.factor_cantor_zassenhaus(&mut SplitMix64::new(3)),
+            p.factor_berlekamp()
+        );
+    }
+}
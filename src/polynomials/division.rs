@@ -0,0 +1,108 @@
+use crate::base::symbol::Symbol;
+use crate::polynomials::{
+    monomial_order::MonomialOrder, multi_polynomial::MultiPolynomial, polynomial::Polynomial,
+};
+
+impl Polynomial {
+    /**
+     * Euclidean division: self == quotient * divisor + remainder, deg(remainder) < deg(divisor)
+     *  - `None` when dividing by the zero polynomial
+     */
+    pub fn div_rem(&self, divisor: &Polynomial) -> Option<(Polynomial, Polynomial)> {
+        let divisor_degree = divisor.degree()?;
+        let leading = divisor.leading_coefficient();
+        let variable = self.variable().clone();
+
+        let mut quotient = Polynomial::zero(variable.clone());
+        let mut remainder = self.clone();
+        while let Some(degree) = remainder.degree() {
+            if degree < divisor_degree {
+                break;
+            }
+            let term = Polynomial::monomial(
+                variable.clone(),
+                remainder.leading_coefficient() / &leading,
+                degree - divisor_degree,
+            );
+            remainder = &remainder - &(&term * divisor);
+            quotient = &quotient + &term;
+        }
+        Some((quotient, remainder))
+    }
+
+    /**
+     * Pseudo-division: lc(divisor)^(m - n + 1) * self == quotient * divisor + remainder
+     *  - m and n are the degrees, integer coefficients give integer quotient and remainder
+     */
+    pub fn pseudo_div_rem(&self, divisor: &Polynomial) -> Option<(Polynomial, Polynomial)> {
+        let divisor_degree = divisor.degree()?;
+        let degree = match self.degree() {
+            Some(degree) if degree >= divisor_degree => degree,
+            _ => return Some((Polynomial::zero(self.variable().clone()), self.clone())),
+        };
+        let exponent = Symbol::integer((degree - divisor_degree + 1) as isize);
+        let factor = divisor.leading_coefficient().pow(&exponent)?;
+        self.scale(&factor).div_rem(divisor)
+    }
+}
+
+impl MultiPolynomial {
+    /**
+     * Division by a single polynomial in the monomial order
+     *  - terms of the remainder are not divisible by the leading monomial of the divisor
+     *  - `None` when dividing by the zero polynomial
+     */
+    pub fn div_rem(
+        &self,
+        divisor: &MultiPolynomial,
+        order: MonomialOrder,
+    ) -> Option<(MultiPolynomial, MultiPolynomial)> {
+        let (divisor_monomial, divisor_coefficient) = divisor.leading_term(order)?;
+        let variables = self.variables().to_vec();
+
+        let mut quotient = MultiPolynomial::zero(variables.clone());
+        let mut remainder = MultiPolynomial::zero(variables.clone());
+        let mut dividend = self.clone();
+        while let Some((monomial, coefficient)) = dividend.leading_term(order) {
+            let leading = match monomial_quotient(&monomial, &divisor_monomial) {
+                Some(exponents) => {
+                    let term = MultiPolynomial::new(
+                        variables.clone(),
+                        vec![(exponents, coefficient / &divisor_coefficient)],
+                    );
+                    quotient = &quotient + &term;
+                    &term * divisor
+                }
+                None => {
+                    let term =
+                        MultiPolynomial::new(variables.clone(), vec![(monomial, coefficient)]);
+                    remainder = &remainder + &term;
+                    term
+                }
+            };
+            dividend = &dividend - &leading;
+        }
+        Some((quotient, remainder))
+    }
+
+    /**
+     * Exact quotient, `None` when the divisor does not divide the polynomial
+     */
+    pub fn divide(&self, divisor: &MultiPolynomial) -> Option<MultiPolynomial> {
+        match self.div_rem(divisor, MonomialOrder::Lex)? {
+            (quotient, remainder) if remainder.is_zero() => Some(quotient),
+            _ => None,
+        }
+    }
+}
+
+/**
+ * Exponents of the monomial quotient, when the divisor monomial divides the other
+ */
+fn monomial_quotient(monomial: &[usize], divisor: &[usize]) -> Option<Vec<usize>> {
+    monomial
+        .iter()
+        .zip(divisor.iter())
+        .map(|(exponent, divisor)| exponent.checked_sub(*divisor))
+        .collect()
+}
@@ -0,0 +1,65 @@
+#[cfg(test)]
+mod univariate {
+    use crate::polynomials::polynomial::polynomial;
+
+    #[test]
+    fn divides_with_remainder() {
+        let (quotient, remainder) = polynomial("x^3 - 2*x + 5")
+            .div_rem(&polynomial("2*x - 1"))
+            .unwrap();
+
+        assert_eq!(quotient, polynomial("x^2/2 + x/4 - 7/8"));
+        assert_eq!(remainder, polynomial("33/8"));
+    }
+
+    #[test]
+    fn divides_exactly() {
+        let (quotient, remainder) = polynomial("x^2 - 1").div_rem(&polynomial("x + 1")).unwrap();
+
+        assert_eq!(quotient, polynomial("x - 1"));
+        assert!(remainder.is_zero());
+    }
+
+    #[test]
+    fn pseudo_divides_in_the_integers() {
+        /* 2^3 * (x^3 - 2x + 5) == (4x^2 + 2x - 7) * (2x - 1) + 33 */
+        let (quotient, remainder) = polynomial("x^3 - 2*x + 5")
+            .pseudo_div_rem(&polynomial("2*x - 1"))
+            .unwrap();
+
+        assert_eq!(quotient, polynomial("4*x^2 + 2*x - 7"));
+        assert_eq!(remainder, polynomial("33"));
+    }
+
+    #[test]
+    fn rejects_zero_divisor() {
+        assert_eq!(polynomial("x + 1").div_rem(&polynomial("0")), None);
+        assert_eq!(polynomial("x + 1").pseudo_div_rem(&polynomial("0")), None);
+    }
+}
+
+#[cfg(test)]
+mod multivariate {
+    use crate::polynomials::{monomial_order::MonomialOrder, multi_polynomial::polynomial};
+
+    #[test]
+    fn divides_with_remainder() {
+        let dividend = polynomial("x^2*y + x*y^2 + y^2");
+        let divisor = polynomial("x*y - 1");
+        let (quotient, remainder) = dividend.div_rem(&divisor, MonomialOrder::Lex).unwrap();
+
+        assert_eq!(quotient, polynomial("x + y"));
+        assert_eq!(remainder, polynomial("x + y^2 + y"));
+        assert_eq!(&(&quotient * &divisor) + &remainder, dividend);
+    }
+
+    #[test]
+    fn divides_exactly() {
+        assert_eq!(
+            polynomial("x^2 - y^2").divide(&polynomial("x + y")),
+            Some(polynomial("x - y"))
+        );
+        assert_eq!(polynomial("x^2 + y^2").divide(&polynomial("x + y")), None);
+        assert_eq!(polynomial("x").divide(&polynomial("0")), None);
+    }
+}
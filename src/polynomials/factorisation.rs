@@ -0,0 +1,234 @@
+use crate::base::{expression::Expression, symbol::Symbol};
+use crate::polynomials::{
+    algebraic_extension::AlgebraicExtension,
+    finite_field::FiniteField,
+    finite_field_polynomial::FiniteFieldPolynomial,
+    hensel::{hensel_lift, symmetric_modulo},
+    polynomial::Polynomial,
+    squarefree::product_of_powers,
+};
+use num::bigint::BigInt;
+use num::rational::BigRational;
+use num::Signed;
+
+/**
+ * Good primes tried before choosing the one with fewest modular factors
+ */
+const PRIME_TRIALS: usize = 3;
+
+impl Polynomial {
+    /**
+     * Factorisation into irreducible polynomials over the integers, by Zassenhaus' algorithm
+     *  - self == constant * product of factors^multiplicity
+     *  - the factors are primitive with positive leading coefficient
+     */
+    pub fn factor_integer(&self) -> (Symbol<BigRational>, Vec<(Polynomial, usize)>) {
+        let (constant, squarefree) = self.squarefree_decomposition();
+        let mut factors: Vec<(Polynomial, usize)> = squarefree
+            .iter()
+            .flat_map(|(factor, multiplicity)| {
+                zassenhaus(factor)
+                    .into_iter()
+                    .map(move |irreducible| (irreducible, *multiplicity))
+            })
+            .collect();
+        factors.sort_by(|(left, _), (right, _)| {
+            left.degree().cmp(&right.degree()).then_with(|| {
+                let coefficients = |polynomial: &Polynomial| -> Vec<BigRational> {
+                    polynomial
+                        .coefficients()
+                        .iter()
+                        .rev()
+                        .map(|coefficient| coefficient.data.clone())
+                        .collect()
+                };
+                coefficients(left).cmp(&coefficients(right))
+            })
+        });
+        (constant, factors)
+    }
+}
+
+impl Expression {
+    /**
+     * Factorisation over the integers of a polynomial in a single variable
+     *  - (x^4 - 1) == (x - 1) * (x + 1) * (x^2 + 1)
+     *  - other expressions are left unchanged
+     */
+    pub fn factor(&self) -> Expression {
+        self.factor_with(None)
+    }
+
+    /**
+     * Factorisation over the integers, or over an algebraic extension when one is given
+     *  - (x^2 - 2) == (x - a) * (x + a) over Q(a) with a^2 - 2 == 0
+     *  - other expressions are left unchanged
+     */
+    pub fn factor_with(&self, extension: Option<&AlgebraicExtension>) -> Expression {
+        if let Some(extension) = extension {
+            return self.factor_over(extension);
+        }
+
+        let variables = self.variables();
+        let variable = match variables.as_slice() {
+            [variable] => variable,
+            _ => return self.clone(),
+        };
+        match self.as_polynomial(variable) {
+            Some(polynomial) if !polynomial.is_zero() => {
+                let (constant, factors) = polynomial.factor_integer();
+                product_of_powers(&constant, &factors)
+            }
+            _ => self.clone(),
+        }
+    }
+}
+
+/**
+ * Irreducible factors of a primitive square-free polynomial with positive leading coefficient
+ *  - factor modulo a good prime, lift the factors beyond the coefficient bound and
+ *    recombine them by trial division
+ */
+fn zassenhaus(polynomial: &Polynomial) -> Vec<Polynomial> {
+    let degree = polynomial.degree().unwrap_or(0);
+    if degree <= 1 {
+        return vec![polynomial.clone()];
+    }
+
+    let modular = modular_factors(polynomial);
+    if modular.len() == 1 {
+        return vec![polynomial.clone()];
+    }
+
+    /* coefficients of lc * g, for any factor g, are below lc * 2^n * |f| */
+    let leading = polynomial.leading_coefficient().numerator().data;
+    let norm: BigInt = polynomial
+        .coefficients()
+        .iter()
+        .map(|coefficient| coefficient.numerator().data.pow(2u32))
+        .sum();
+    let bound = (norm.sqrt() + 1u8) * leading.abs() * BigInt::from(2).pow(degree as u32 + 1);
+
+    let prime = modular[0].field().modulus().clone();
+    let mut exponent = 1;
+    let mut modulus = prime.clone();
+    while modulus <= bound {
+        modulus *= &prime;
+        exponent += 1;
+    }
+
+    recombine(
+        polynomial,
+        hensel_lift(polynomial, &modular, exponent),
+        &modulus,
+    )
+}
+
+/**
+ * Monic factors modulo the prime, among the first good ones, with fewest factors
+ *  - a good prime does not divide the leading coefficient and keeps the polynomial
+ *    square-free
+ */
+fn modular_factors(polynomial: &Polynomial) -> Vec<FiniteFieldPolynomial> {
+    let mut best: Option<Vec<FiniteFieldPolynomial>> = None;
+    let mut trials = 0;
+    let mut candidate = BigInt::from(2);
+    while trials < PRIME_TRIALS {
+        candidate += 1;
+        let field = match FiniteField::new(candidate.clone()) {
+            Some(field) => field,
+            None => continue,
+        };
+        let image = match polynomial.modulo(&field) {
+            Some(image) if image.degree() == polynomial.degree() => image,
+            _ => continue,
+        };
+        if !image.gcd(&image.derivative()).is_one() {
+            continue;
+        }
+
+        trials += 1;
+        let (_, factors) = image.factor_berlekamp();
+        let factors: Vec<FiniteFieldPolynomial> =
+            factors.into_iter().map(|(factor, _)| factor).collect();
+        let better = match &best {
+            Some(best) => factors.len() < best.len(),
+            None => true,
+        };
+        if better {
+            best = Some(factors);
+        }
+    }
+    best.unwrap()
+}
+
+/**
+ * True factors from products of lifted factors, smallest subsets first
+ */
+fn recombine(
+    polynomial: &Polynomial,
+    lifted: Vec<Polynomial>,
+    modulus: &BigInt,
+) -> Vec<Polynomial> {
+    let mut remaining = polynomial.clone();
+    let mut pool = lifted;
+    let mut factors = vec![];
+    let mut size = 1;
+
+    while 2 * size <= pool.len() {
+        let leading = remaining.leading_coefficient();
+        let found = combinations(pool.len(), size)
+            .into_iter()
+            .find_map(|subset| {
+                let product = subset.iter().fold(
+                    Polynomial::constant(remaining.variable().clone(), leading.clone()),
+                    |product, &index| &product * &pool[index],
+                );
+                let candidate = symmetric_modulo(&product, modulus).primitive_part();
+                match remaining.div_rem(&candidate) {
+                    Some((quotient, remainder)) if remainder.is_zero() => {
+                        Some((subset, candidate, quotient))
+                    }
+                    _ => None,
+                }
+            });
+
+        match found {
+            Some((subset, candidate, quotient)) => {
+                factors.push(candidate);
+                remaining = quotient;
+                pool = pool
+                    .into_iter()
+                    .enumerate()
+                    .filter(|(index, _)| !subset.contains(index))
+                    .map(|(_, factor)| factor)
+                    .collect();
+            }
+            None => size += 1,
+        }
+    }
+
+    if remaining.degree() > Some(0) {
+        factors.push(remaining.primitive_part());
+    }
+    factors
+}
+
+/**
+ * Index subsets of the given size, in lexicographic order
+ */
+fn combinations(length: usize, size: usize) -> Vec<Vec<usize>> {
+    if size == 0 {
+        return vec![vec![]];
+    }
+    (size - 1..length)
+        .flat_map(|last| {
+            combinations(last, size - 1)
+                .into_iter()
+                .map(move |mut subset| {
+                    subset.push(last);
+                    subset
+                })
+        })
+        .collect()
+}
@@ -0,0 +1,152 @@
+#[cfg(test)]
+mod lifting {
+    use crate::base::{expression::Expression, symbol::Symbol};
+    use crate::polynomials::{
+        finite_field::FiniteField, hensel::hensel_lift, polynomial::Polynomial,
+    };
+    use num::bigint::BigInt;
+
+    #[test]
+    fn lifts_modular_factors() {
+        let x = Symbol::variable("x");
+        let field = FiniteField::new(BigInt::from(5)).unwrap();
+        let p = Expression::parse("3*x^4 - 3")
+            .unwrap()
+            .as_polynomial(&x)
+            .unwrap();
+        let (_, factors) = p.modulo(&field).unwrap().factor_berlekamp();
+        let factors: Vec<_> = factors.into_iter().map(|(factor, _)| factor).collect();
+        let lifted = hensel_lift(&p, &factors, 4);
+
+        /* 3 * (x - 1) * (x + 1) * (x - 182) * (x + 182) == 3x^4 - 3 mod 5^4 */
+        let product = lifted.iter().fold(
+            Polynomial::constant(x.clone(), p.leading_coefficient()),
+            |product, factor| &product * factor,
+        );
+        let difference = &product - &p;
+        assert!(lifted
+            .iter()
+            .all(|factor| factor.leading_coefficient() == Symbol::rational(1, 1)));
+        assert!(difference
+            .coefficients()
+            .iter()
+            .all(|coefficient| (coefficient.numerator().data % 625u32) == BigInt::from(0)));
+    }
+}
+
+#[cfg(test)]
+mod integers {
+    use crate::base::symbol::Symbol;
+    use crate::polynomials::polynomial::{polynomial, Polynomial};
+
+    fn factors(input: &str) -> Vec<(Polynomial, usize)> {
+        polynomial(input).factor_integer().1
+    }
+
+    #[test]
+    fn splits_cyclotomic_products() {
+        assert_eq!(
+            factors("x^4 - 1"),
+            vec![
+                (polynomial("x - 1"), 1),
+                (polynomial("x + 1"), 1),
+                (polynomial("x^2 + 1"), 1)
+            ]
+        );
+    }
+
+    #[test]
+    fn keeps_irreducibles_split_modulo_every_prime() {
+        assert_eq!(factors("x^4 + 1"), vec![(polynomial("x^4 + 1"), 1)]);
+        assert_eq!(
+            factors("x^4 - 10*x^2 + 1"),
+            vec![(polynomial("x^4 - 10*x^2 + 1"), 1)]
+        );
+    }
+
+    #[test]
+    fn non_monic_factors() {
+        assert_eq!(
+            factors("6*x^2 + x - 2"),
+            vec![(polynomial("2*x - 1"), 1), (polynomial("3*x + 2"), 1)]
+        );
+    }
+
+    #[test]
+    fn multiplicities_and_content() {
+        let (constant, factors) = polynomial("-4*(x - 1)^2 * (x^2 + x + 1) * x").factor_integer();
+
+        assert_eq!(constant, Symbol::rational(-4, 1));
+        assert_eq!(
+            factors,
+            vec![
+                (polynomial("x - 1"), 2),
+                (polynomial("x"), 1),
+                (polynomial("x^2 + x + 1"), 1)
+            ]
+        );
+    }
+
+    #[test]
+    fn larger_product() {
+        assert_eq!(
+            factors("(x^3 - 2) * (x^5 + 3*x + 7) * (2*x^2 - 3) * (x^2 - 3*x + 11)"),
+            vec![
+                (polynomial("x^2 - 3*x + 11"), 1),
+                (polynomial("2*x^2 - 3"), 1),
+                (polynomial("x^3 - 2"), 1),
+                (polynomial("x^5 + 3*x + 7"), 1)
+            ]
+        );
+    }
+}
+
+#[cfg(test)]
+mod expressions {
+    use crate::base::{expression::Expression, symbol::Symbol};
+    use crate::polynomials::algebraic_extension::AlgebraicExtension;
+
+    fn factor(input: &str, expected: &str) {
+        let trial = Expression::parse(input).unwrap().factor();
+        let expected = Expression::parse(expected).unwrap();
+
+        assert_eq!(trial, expected);
+    }
+
+    #[test]
+    fn products_of_powers() {
+        factor("x^4 - 1", "(x - 1) * (x + 1) * (x^2 + 1)");
+        factor("2*x^3 - 2*x", "2 * x * (x - 1) * (x + 1)");
+        factor(
+            "x^5 - 2*x^4 + x^3 - x^2 + 2*x - 1",
+            "(x - 1)^3 * (x^2 + x + 1)",
+        );
+    }
+
+    #[test]
+    fn dispatches_on_extension() {
+        let input = Expression::parse("x^4 - 4").unwrap();
+        let extension = AlgebraicExtension::new(
+            Symbol::variable("a"),
+            &Expression::parse("a^2 - 2").unwrap(),
+        )
+        .unwrap();
+
+        assert_eq!(input.factor_with(None), input.factor());
+        assert_eq!(
+            input.factor_with(None),
+            Expression::parse("(x^2 - 2) * (x^2 + 2)").unwrap()
+        );
+        assert_eq!(
+            input.factor_with(Some(&extension)),
+            Expression::parse("(x - a) * (x + a) * (x^2 + 2)").unwrap()
+        );
+    }
+
+    #[test]
+    fn leaves_other_expressions() {
+        factor("x^2 - y^2", "x^2 - y^2");
+        factor("sin(x)^2 - 1", "sin(x)^2 - 1");
+        factor("7", "7");
+    }
+}
@@ -0,0 +1,114 @@
+use num::bigint::BigInt;
+use num::{Integer, One, Zero};
+
+/**
+ * Prime field GF(p), elements are represented by their residues in [0, p)
+ */
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FiniteField {
+    modulus: BigInt,
+}
+
+impl FiniteField {
+    /**
+     * `None` when the modulus is not a prime
+     *  - primality is decided by Miller-Rabin on the first twelve prime bases, which is
+     *    deterministic below 3.3 * 10^24
+     */
+    pub fn new(modulus: BigInt) -> Option<Self> {
+        match is_prime(&modulus) {
+            true => Some(Self { modulus }),
+            false => None,
+        }
+    }
+
+    pub fn modulus(&self) -> &BigInt {
+        &self.modulus
+    }
+
+    pub fn element(&self, value: &BigInt) -> BigInt {
+        value.mod_floor(&self.modulus)
+    }
+
+    pub fn add(&self, left: &BigInt, right: &BigInt) -> BigInt {
+        self.element(&(left + right))
+    }
+
+    pub fn sub(&self, left: &BigInt, right: &BigInt) -> BigInt {
+        self.element(&(left - right))
+    }
+
+    pub fn mul(&self, left: &BigInt, right: &BigInt) -> BigInt {
+        self.element(&(left * right))
+    }
+
+    pub fn neg(&self, value: &BigInt) -> BigInt {
+        self.element(&-value)
+    }
+
+    /**
+     * Multiplicative inverse by Fermat's little theorem, `None` for zero
+     */
+    pub fn inverse(&self, value: &BigInt) -> Option<BigInt> {
+        let value = self.element(value);
+        if value.is_zero() {
+            return None;
+        }
+        Some(value.modpow(&(&self.modulus - 2), &self.modulus))
+    }
+
+    pub fn pow(&self, base: &BigInt, exponent: &BigInt) -> BigInt {
+        self.element(base).modpow(exponent, &self.modulus)
+    }
+
+    /**
+     * Residue closest to zero, in (-p/2, p/2]
+     */
+    pub fn symmetric(&self, value: &BigInt) -> BigInt {
+        let value = self.element(value);
+        if &value * 2 > self.modulus {
+            value - &self.modulus
+        } else {
+            value
+        }
+    }
+}
+
+fn is_prime(candidate: &BigInt) -> bool {
+    if candidate < &BigInt::from(2) {
+        return false;
+    }
+    let bases = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+    for base in bases.iter() {
+        let base = BigInt::from(*base);
+        if candidate == &base {
+            return true;
+        }
+        if candidate.is_multiple_of(&base) {
+            return false;
+        }
+    }
+
+    /* candidate - 1 == odd * 2^twos */
+    let predecessor: BigInt = candidate - 1;
+    let mut odd = predecessor.clone();
+    let mut twos = 0;
+    while odd.is_even() {
+        odd /= 2;
+        twos += 1;
+    }
+
+    bases.iter().all(|base| {
+        let mut witness = BigInt::from(*base).modpow(&odd, candidate);
+        if witness.is_one() || witness == predecessor {
+            return true;
+        }
+        for _ in 1..twos {
+            witness = (&witness * &witness).mod_floor(candidate);
+            if witness == predecessor {
+                return true;
+            }
+        }
+        false
+    })
+}
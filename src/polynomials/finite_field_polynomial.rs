@@ -0,0 +1,334 @@
+use crate::base::symbol::Symbol;
+use crate::polynomials::{finite_field::FiniteField, polynomial::Polynomial};
+use num::bigint::BigInt;
+use num::{One, ToPrimitive, Zero};
+
+/**
+ * Dense univariate polynomial with coefficients in GF(p)
+ *  - coefficients from the constant term up, without vanishing leading coefficients
+ */
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FiniteFieldPolynomial {
+    field: FiniteField,
+    coefficients: Vec<BigInt>,
+}
+
+impl FiniteFieldPolynomial {
+    pub fn new(field: FiniteField, coefficients: Vec<BigInt>) -> Self {
+        let mut coefficients: Vec<BigInt> = coefficients
+            .iter()
+            .map(|coefficient| field.element(coefficient))
+            .collect();
+        while coefficients.last().filter(|last| last.is_zero()).is_some() {
+            coefficients.pop();
+        }
+        Self {
+            field,
+            coefficients,
+        }
+    }
+
+    pub fn zero(field: FiniteField) -> Self {
+        Self::new(field, vec![])
+    }
+
+    pub fn one(field: FiniteField) -> Self {
+        Self::new(field, vec![BigInt::one()])
+    }
+
+    pub fn monomial(field: FiniteField, coefficient: BigInt, degree: usize) -> Self {
+        let mut coefficients = vec![BigInt::zero(); degree];
+        coefficients.push(coefficient);
+        Self::new(field, coefficients)
+    }
+
+    pub fn field(&self) -> &FiniteField {
+        &self.field
+    }
+
+    pub fn coefficients(&self) -> &[BigInt] {
+        &self.coefficients
+    }
+
+    pub fn coefficient(&self, power: usize) -> BigInt {
+        self.coefficients
+            .get(power)
+            .cloned()
+            .unwrap_or_else(BigInt::zero)
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.coefficients.is_empty()
+    }
+
+    pub fn is_one(&self) -> bool {
+        self.coefficients.len() == 1 && self.coefficients[0].is_one()
+    }
+
+    /**
+     * `None` for the zero polynomial
+     */
+    pub fn degree(&self) -> Option<usize> {
+        self.coefficients.len().checked_sub(1)
+    }
+
+    pub fn leading_coefficient(&self) -> BigInt {
+        self.coefficients
+            .last()
+            .cloned()
+            .unwrap_or_else(BigInt::zero)
+    }
+
+    pub fn scale(&self, factor: &BigInt) -> Self {
+        Self::new(
+            self.field.clone(),
+            self.coefficients
+                .iter()
+                .map(|coefficient| self.field.mul(coefficient, factor))
+                .collect(),
+        )
+    }
+
+    /**
+     * Divides by the leading coefficient, the zero polynomial stays zero
+     */
+    pub fn monic(&self) -> Self {
+        match self.field.inverse(&self.leading_coefficient()) {
+            Some(inverse) => self.scale(&inverse),
+            None => self.clone(),
+        }
+    }
+
+    pub fn derivative(&self) -> Self {
+        Self::new(
+            self.field.clone(),
+            self.coefficients
+                .iter()
+                .enumerate()
+                .skip(1)
+                .map(|(power, coefficient)| coefficient * power)
+                .collect(),
+        )
+    }
+
+    /**
+     * Euclidean division: self == quotient * divisor + remainder, deg(remainder) < deg(divisor)
+     *  - `None` when dividing by the zero polynomial
+     */
+    pub fn div_rem(&self, divisor: &Self) -> Option<(Self, Self)> {
+        let divisor_degree = divisor.degree()?;
+        let inverse = self.field.inverse(&divisor.leading_coefficient())?;
+
+        let mut quotient = vec![BigInt::zero(); self.coefficients.len()];
+        let mut remainder = self.coefficients.clone();
+        while remainder.len() > divisor_degree {
+            let shift = remainder.len() - 1 - divisor_degree;
+            let coefficient = self.field.mul(&remainder[remainder.len() - 1], &inverse);
+            for (power, term) in divisor.coefficients.iter().enumerate() {
+                let product = self.field.mul(&coefficient, term);
+                remainder[power + shift] = self.field.sub(&remainder[power + shift], &product);
+            }
+            quotient[shift] = coefficient;
+            while remainder.last().filter(|last| last.is_zero()).is_some() {
+                remainder.pop();
+            }
+        }
+        Some((
+            Self::new(self.field.clone(), quotient),
+            Self::new(self.field.clone(), remainder),
+        ))
+    }
+
+    /**
+     * Monic greatest common divisor by the Euclidean algorithm
+     */
+    pub fn gcd(&self, other: &Self) -> Self {
+        let (mut a, mut b) = (self.clone(), other.clone());
+        while let Some((_, remainder)) = a.div_rem(&b) {
+            a = b;
+            b = remainder;
+        }
+        a.monic()
+    }
+
+    /**
+     * self^exponent mod modulus by repeated squaring
+     */
+    pub fn pow_mod(&self, exponent: &BigInt, modulus: &Self) -> Self {
+        let mut result = Self::one(self.field.clone());
+        let mut base = self.clone();
+        let mut exponent = exponent.clone();
+        while !exponent.is_zero() {
+            if (&exponent % 2u8).is_one() {
+                result = (&result * &base).div_rem(modulus).unwrap().1;
+            }
+            base = (&base * &base).div_rem(modulus).unwrap().1;
+            exponent /= 2u8;
+        }
+        result.div_rem(modulus).unwrap().1
+    }
+
+    /**
+     * Integer polynomial from the residues, either in [0, p) or in (-p/2, p/2]
+     */
+    pub fn to_polynomial(&self, variable: Symbol<String>, symmetric: bool) -> Polynomial {
+        Polynomial::new(
+            variable,
+            self.coefficients
+                .iter()
+                .map(|coefficient| {
+                    let residue = match symmetric {
+                        true => self.field.symmetric(coefficient),
+                        false => coefficient.clone(),
+                    };
+                    Symbol::from(Symbol { data: residue })
+                })
+                .collect(),
+        )
+    }
+
+    /**
+     * Square-free decomposition over GF(p) of a monic polynomial
+     *  - self == a1 * a2^2 * ... * ak^k, with each ai square-free and monic
+     *  - factors in x^p are p-th powers, from the Frobenius map
+     */
+    pub fn squarefree_decomposition(&self) -> Vec<(FiniteFieldPolynomial, usize)> {
+        let mut factors = vec![];
+        let mut common = self.gcd(&self.derivative());
+        let mut remaining = self.div_rem(&common).unwrap().0;
+        let mut multiplicity = 1;
+        while remaining.degree() > Some(0) {
+            let next = remaining.gcd(&common);
+            let factor = remaining.div_rem(&next).unwrap().0;
+            if factor.degree() > Some(0) {
+                factors.push((factor, multiplicity));
+            }
+            common = common.div_rem(&next).unwrap().0;
+            remaining = next;
+            multiplicity += 1;
+        }
+
+        if common.degree() > Some(0) {
+            /* the derivative of `common` vanishes, so the modulus is below its degree */
+            let step = self.field.modulus().to_usize().unwrap();
+            let root = FiniteFieldPolynomial::new(
+                self.field.clone(),
+                common.coefficients.iter().step_by(step).cloned().collect(),
+            );
+            for (factor, multiplicity) in root.squarefree_decomposition() {
+                factors.push((factor, multiplicity * step));
+            }
+        }
+        factors
+    }
+
+    fn assert_same_field(&self, other: &Self) {
+        assert_eq!(self.field, other.field, "polynomials over different fields");
+    }
+}
+
+impl Polynomial {
+    /**
+     * Image in GF(p)[x], `None` when a denominator is a multiple of p
+     */
+    pub fn modulo(&self, field: &FiniteField) -> Option<FiniteFieldPolynomial> {
+        let coefficients = self
+            .coefficients()
+            .iter()
+            .map(|coefficient| {
+                let numerator = field.element(&coefficient.numerator().data);
+                let denominator = field.inverse(&coefficient.denominator().data)?;
+                Some(field.mul(&numerator, &denominator))
+            })
+            .collect::<Option<Vec<BigInt>>>()?;
+        Some(FiniteFieldPolynomial::new(field.clone(), coefficients))
+    }
+}
+
+// ================================ //
+//      Arithmetic operations       //
+// ================================ //
+
+impl std::ops::Add for &FiniteFieldPolynomial {
+    type Output = FiniteFieldPolynomial;
+    fn add(self, other: Self) -> FiniteFieldPolynomial {
+        self.assert_same_field(other);
+        let length = self.coefficients.len().max(other.coefficients.len());
+        FiniteFieldPolynomial::new(
+            self.field.clone(),
+            (0..length)
+                .map(|power| {
+                    self.field
+                        .add(&self.coefficient(power), &other.coefficient(power))
+                })
+                .collect(),
+        )
+    }
+}
+
+impl std::ops::Sub for &FiniteFieldPolynomial {
+    type Output = FiniteFieldPolynomial;
+    fn sub(self, other: Self) -> FiniteFieldPolynomial {
+        self + &-other
+    }
+}
+
+impl std::ops::Mul for &FiniteFieldPolynomial {
+    type Output = FiniteFieldPolynomial;
+    fn mul(self, other: Self) -> FiniteFieldPolynomial {
+        self.assert_same_field(other);
+        if self.is_zero() || other.is_zero() {
+            return FiniteFieldPolynomial::zero(self.field.clone());
+        }
+        let mut coefficients =
+            vec![BigInt::zero(); self.coefficients.len() + other.coefficients.len() - 1];
+        for (i, left) in self.coefficients.iter().enumerate() {
+            for (j, right) in other.coefficients.iter().enumerate() {
+                coefficients[i + j] += left * right;
+            }
+        }
+        FiniteFieldPolynomial::new(self.field.clone(), coefficients)
+    }
+}
+
+impl std::ops::Neg for &FiniteFieldPolynomial {
+    type Output = FiniteFieldPolynomial;
+    fn neg(self) -> FiniteFieldPolynomial {
+        FiniteFieldPolynomial::new(
+            self.field.clone(),
+            self.coefficients
+                .iter()
+                .map(|coefficient| self.field.neg(coefficient))
+                .collect(),
+        )
+    }
+}
+
+/**
+ * Orders factors by degree, then by coefficients from the leading one
+ */
+pub(crate) fn sort_factors(factors: &mut [(FiniteFieldPolynomial, usize)]) {
+    factors.sort_by(|(left, left_multiplicity), (right, right_multiplicity)| {
+        left.degree()
+            .cmp(&right.degree())
+            .then_with(|| {
+                left.coefficients()
+                    .iter()
+                    .rev()
+                    .cmp(right.coefficients().iter().rev())
+            })
+            .then_with(|| left_multiplicity.cmp(right_multiplicity))
+    });
+}
+
+/**
+ * Builds a test fixture over GF(modulus) from small coefficients, panicking on a
+ * modulus that is not prime
+ */
+#[cfg(test)]
+pub(crate) fn polynomial(modulus: &BigInt, coefficients: &[i64]) -> FiniteFieldPolynomial {
+    FiniteFieldPolynomial::new(
+        FiniteField::new(modulus.clone()).unwrap(),
+        coefficients.iter().map(|c| BigInt::from(*c)).collect(),
+    )
+}
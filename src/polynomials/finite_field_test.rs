@@ -0,0 +1,159 @@
+#[cfg(test)]
+mod field {
+    use crate::polynomials::finite_field::FiniteField;
+    use num::bigint::BigInt;
+
+    fn gf(modulus: i64) -> FiniteField {
+        FiniteField::new(BigInt::from(modulus)).unwrap()
+    }
+
+    #[test]
+    fn accepts_prime_moduli() {
+        assert!(FiniteField::new(BigInt::from(2)).is_some());
+        assert!(FiniteField::new(BigInt::from(7919)).is_some());
+        assert!(FiniteField::new(BigInt::from(2147483647i64)).is_some());
+        assert!(FiniteField::new(BigInt::from(1)).is_none());
+        assert!(FiniteField::new(BigInt::from(561)).is_none());
+        assert!(FiniteField::new(BigInt::from(-7)).is_none());
+    }
+
+    #[test]
+    fn reduces_elements() {
+        let field = gf(7);
+
+        assert_eq!(field.element(&BigInt::from(-1)), BigInt::from(6));
+        assert_eq!(
+            field.mul(&BigInt::from(4), &BigInt::from(5)),
+            BigInt::from(6)
+        );
+        assert_eq!(field.symmetric(&BigInt::from(5)), BigInt::from(-2));
+        assert_eq!(field.symmetric(&BigInt::from(3)), BigInt::from(3));
+    }
+
+    #[test]
+    fn inverts_elements() {
+        let field = gf(13);
+
+        assert_eq!(field.inverse(&BigInt::from(5)), Some(BigInt::from(8)));
+        assert_eq!(field.inverse(&BigInt::from(26)), None);
+    }
+}
+
+#[cfg(test)]
+mod polynomial {
+    use crate::base::{expression::Expression, symbol::Symbol};
+    use crate::polynomials::{finite_field::FiniteField, finite_field_polynomial::polynomial};
+    use num::bigint::BigInt;
+
+    #[test]
+    fn reduces_coefficients() {
+        let modulus = BigInt::from(5);
+        let p = polynomial(&modulus, &[7, -1, 10]);
+
+        assert_eq!(p.coefficients(), &[BigInt::from(2), BigInt::from(4)][..]);
+        assert_eq!(p.degree(), Some(1));
+    }
+
+    #[test]
+    fn arithmetic() {
+        let modulus = BigInt::from(2);
+        /* (x + 1)^2 == x^2 + 1 over GF(2) */
+        let p = polynomial(&modulus, &[1, 1]);
+
+        assert_eq!(&p * &p, polynomial(&modulus, &[1, 0, 1]));
+        assert_eq!(&p + &p, polynomial(&modulus, &[]));
+        assert_eq!(
+            &p - &polynomial(&modulus, &[0, 1]),
+            polynomial(&modulus, &[1])
+        );
+    }
+
+    #[test]
+    fn divides_with_remainder() {
+        let modulus = BigInt::from(7);
+        /* x^3 + 2 == (x^2 + 2x + 4) * (x - 2) + 10 over GF(7) */
+        let (quotient, remainder) = polynomial(&modulus, &[2, 0, 0, 1])
+            .div_rem(&polynomial(&modulus, &[-2, 1]))
+            .unwrap();
+
+        assert_eq!(quotient, polynomial(&modulus, &[4, 2, 1]));
+        assert_eq!(remainder, polynomial(&modulus, &[3]));
+        assert_eq!(
+            polynomial(&modulus, &[1]).div_rem(&polynomial(&modulus, &[])),
+            None
+        );
+    }
+
+    #[test]
+    fn monic_gcd() {
+        let modulus = BigInt::from(5);
+        /* (x + 1) * (x + 2) and 3 * (x + 1) * (x + 4) over GF(5) */
+        let a = polynomial(&modulus, &[2, 3, 1]);
+        let b = polynomial(&modulus, &[12, 15, 3]);
+
+        assert_eq!(a.gcd(&b), polynomial(&modulus, &[1, 1]));
+    }
+
+    #[test]
+    fn modular_powers() {
+        let prime = BigInt::from(5);
+        /* x^5 == x mod x^2 + 1 over GF(5) */
+        let x = polynomial(&prime, &[0, 1]);
+        let modulus = polynomial(&prime, &[1, 0, 1]);
+
+        assert_eq!(x.pow_mod(&BigInt::from(5), &modulus), x);
+        assert_eq!(
+            x.pow_mod(&BigInt::from(2), &modulus),
+            polynomial(&prime, &[4])
+        );
+    }
+
+    #[test]
+    fn converts_integer_polynomials() {
+        let modulus = BigInt::from(5);
+        let x = Symbol::variable("x");
+        let field = FiniteField::new(modulus.clone()).unwrap();
+        let p = Expression::parse("3*x^2 - x + 1/2")
+            .unwrap()
+            .as_polynomial(&x)
+            .unwrap();
+        let image = p.modulo(&field).unwrap();
+
+        assert_eq!(image, polynomial(&modulus, &[3, 4, 3]));
+        assert_eq!(
+            image.to_polynomial(x.clone(), true),
+            Expression::parse("-2*x^2 - x - 2")
+                .unwrap()
+                .as_polynomial(&x)
+                .unwrap()
+        );
+        assert_eq!(p.scale(&Symbol::rational(1, 5)).modulo(&field), None);
+    }
+}
+
+#[cfg(test)]
+mod squarefree {
+    use crate::polynomials::finite_field_polynomial::polynomial;
+    use num::bigint::BigInt;
+
+    #[test]
+    fn separates_multiplicities() {
+        let modulus = BigInt::from(5);
+        /* (x + 1) * (x + 2)^2 over GF(5) */
+        let a = polynomial(&modulus, &[1, 1]);
+        let b = polynomial(&modulus, &[2, 1]);
+        let p = &a * &(&b * &b);
+
+        assert_eq!(p.squarefree_decomposition(), vec![(a, 1), (b, 2)]);
+    }
+
+    #[test]
+    fn extracts_frobenius_powers() {
+        let modulus = BigInt::from(2);
+        /* x^4 + 1 == (x + 1)^4 over GF(2) */
+        assert_eq!(
+            polynomial(&modulus, &[1, 0, 0, 0, 1]).squarefree_decomposition(),
+            vec![(polynomial(&modulus, &[1, 1]), 4)]
+        );
+    }
+}
@@ -0,0 +1,251 @@
+use crate::base::{expression::Expression, symbol::Symbol};
+use crate::polynomials::{
+    monomial_order::MonomialOrder, multi_polynomial::MultiPolynomial, polynomial::Polynomial,
+};
+use num::bigint::BigInt;
+use num::rational::BigRational;
+
+/**
+ * Greatest common divisors are normalised to integer coefficients without common factor
+ * and a positive leading coefficient, so they are unique
+ */
+impl Polynomial {
+    /**
+     * Rational factor leaving coprime integer coefficients and a positive leading coefficient
+     *  - zero for the zero polynomial
+     */
+    pub fn content(&self) -> Symbol<BigRational> {
+        signed_content(self.coefficients(), &self.leading_coefficient())
+    }
+
+    pub fn primitive_part(&self) -> Polynomial {
+        match self.content().inverse() {
+            Some(inverse) => self.scale(&inverse),
+            None => self.clone(),
+        }
+    }
+
+    /**
+     * Greatest common divisor by the Euclidean algorithm
+     */
+    pub fn gcd(&self, other: &Polynomial) -> Polynomial {
+        let (mut a, mut b) = (self.clone(), other.clone());
+        while let Some((_, remainder)) = a.div_rem(&b) {
+            a = b;
+            b = remainder;
+        }
+        a.primitive_part()
+    }
+}
+
+impl MultiPolynomial {
+    /**
+     * Rational factor leaving coprime integer coefficients and a positive leading coefficient
+     *  - the lexicographic leading coefficient gives the sign, zero for the zero polynomial
+     */
+    pub fn content(&self) -> Symbol<BigRational> {
+        let coefficients: Vec<Symbol<BigRational>> = self.terms().values().cloned().collect();
+        signed_content(&coefficients, &self.leading_coefficient(MonomialOrder::Lex))
+    }
+
+    pub fn primitive_part(&self) -> MultiPolynomial {
+        match self.content().inverse() {
+            Some(inverse) => self.scale(&inverse),
+            None => self.clone(),
+        }
+    }
+
+    /**
+     * Greatest common divisor by subresultant pseudo-remainder sequences
+     *  - recursive on the variables: the polynomials are seen in their first variable,
+     *    with coefficients in the remaining ones
+     */
+    pub fn gcd(&self, other: &MultiPolynomial) -> MultiPolynomial {
+        assert_eq!(
+            self.variables(),
+            other.variables(),
+            "polynomials in different variables"
+        );
+        if self.is_zero() {
+            return other.primitive_part();
+        }
+        if other.is_zero() {
+            return self.primitive_part();
+        }
+
+        let variables = self.variables().to_vec();
+        let main = (0..variables.len())
+            .find(|&index| self.degree(index) > Some(0) || other.degree(index) > Some(0));
+        let main = match main {
+            Some(main) => main,
+            None => return MultiPolynomial::constant(variables, Symbol::rational(1, 1)),
+        };
+
+        let (content_a, primitive_a) = split_content(&coefficients_in(self, main));
+        let (content_b, primitive_b) = split_content(&coefficients_in(other, main));
+        let (_, primitive) = split_content(&subresultant_gcd(primitive_a, primitive_b));
+        (&content_a.gcd(&content_b) * &from_coefficients_in(&primitive, main)).primitive_part()
+    }
+}
+
+impl Expression {
+    /**
+     * Greatest common divisor of two polynomial expressions in the variables
+     *  - `None` when any of them is not a polynomial with rational coefficients
+     */
+    pub fn polynomial_gcd(
+        a: &Expression,
+        b: &Expression,
+        variables: &[Symbol<String>],
+    ) -> Option<Expression> {
+        let a = a.as_multi_polynomial(variables)?;
+        let b = b.as_multi_polynomial(variables)?;
+        Some(a.gcd(&b).to_expression())
+    }
+}
+
+/**
+ * gcd of numerators over lcm of denominators, with the sign of the leading coefficient
+ */
+fn signed_content(
+    coefficients: &[Symbol<BigRational>],
+    leading: &Symbol<BigRational>,
+) -> Symbol<BigRational> {
+    let zero = Symbol {
+        data: BigInt::from(0),
+    };
+    let one = Symbol {
+        data: BigInt::from(1),
+    };
+    let numerator = coefficients.iter().fold(zero, |gcd, coefficient| {
+        Symbol::gcd(&gcd, &coefficient.numerator())
+    });
+    let denominator = coefficients.iter().fold(one, |lcm, coefficient| {
+        Symbol::lcm(&lcm, &coefficient.denominator())
+    });
+
+    let content = Symbol::fraction(numerator.abs(), denominator);
+    if leading.is_negative() {
+        content.opposite()
+    } else {
+        content
+    }
+}
+
+// ==================================================== //
+//      Polynomials in one variable over the others     //
+// ==================================================== //
+
+/**
+ * Coefficients of the powers of the main variable, from the constant one
+ */
+pub(crate) fn coefficients_in(polynomial: &MultiPolynomial, main: usize) -> Vec<MultiPolynomial> {
+    let variables = polynomial.variables().to_vec();
+    let degree = polynomial.degree(main).unwrap_or(0);
+    let mut coefficients: Vec<Vec<(Vec<usize>, Symbol<BigRational>)>> = vec![vec![]; degree + 1];
+    for (exponents, coefficient) in polynomial.terms().iter() {
+        let mut rest = exponents.clone();
+        rest[main] = 0;
+        coefficients[exponents[main]].push((rest, coefficient.clone()));
+    }
+    trimmed(
+        coefficients
+            .into_iter()
+            .map(|terms| MultiPolynomial::new(variables.clone(), terms))
+            .collect(),
+    )
+}
+
+fn from_coefficients_in(coefficients: &[MultiPolynomial], main: usize) -> MultiPolynomial {
+    let variables = coefficients[0].variables().to_vec();
+    let generator = MultiPolynomial::generator(variables.clone(), main);
+    coefficients.iter().enumerate().fold(
+        MultiPolynomial::zero(variables),
+        |sum, (power, coefficient)| &sum + &(&generator.pow(power) * coefficient),
+    )
+}
+
+/**
+ * Drops the vanishing leading coefficients
+ */
+fn trimmed(coefficients: Vec<MultiPolynomial>) -> Vec<MultiPolynomial> {
+    let mut coefficients = coefficients;
+    while coefficients.last().filter(|last| last.is_zero()).is_some() {
+        coefficients.pop();
+    }
+    coefficients
+}
+
+/**
+ * Content as the gcd of the coefficients, and the primitive part
+ */
+fn split_content(coefficients: &[MultiPolynomial]) -> (MultiPolynomial, Vec<MultiPolynomial>) {
+    let variables = coefficients[0].variables().to_vec();
+    let content = coefficients
+        .iter()
+        .fold(MultiPolynomial::zero(variables), |gcd, coefficient| {
+            gcd.gcd(coefficient)
+        });
+    let primitive = coefficients
+        .iter()
+        .map(|coefficient| coefficient.divide(&content).unwrap())
+        .collect();
+    (content, primitive)
+}
+
+/**
+ * Pseudo-remainder: lc(b)^(deg a - deg b + 1) * a mod b
+ */
+fn pseudo_remainder(a: &[MultiPolynomial], b: &[MultiPolynomial]) -> Vec<MultiPolynomial> {
+    let divisor_degree = b.len() - 1;
+    let leading = &b[divisor_degree];
+    let mut remainder = a.to_vec();
+    let mut steps = a.len() - divisor_degree;
+
+    while remainder.len() > divisor_degree {
+        let shift = remainder.len() - 1 - divisor_degree;
+        let coefficient = remainder[remainder.len() - 1].clone();
+        let mut next: Vec<MultiPolynomial> = remainder.iter().map(|term| leading * term).collect();
+        for (power, term) in b.iter().enumerate() {
+            next[power + shift] = &next[power + shift] - &(&coefficient * term);
+        }
+        remainder = trimmed(next);
+        steps -= 1;
+    }
+
+    let factor = leading.pow(steps);
+    remainder.iter().map(|term| &factor * term).collect()
+}
+
+/**
+ * Last non vanishing element of the subresultant sequence of two primitive polynomials
+ */
+fn subresultant_gcd(a: Vec<MultiPolynomial>, b: Vec<MultiPolynomial>) -> Vec<MultiPolynomial> {
+    let (mut a, mut b) = if a.len() >= b.len() { (a, b) } else { (b, a) };
+    let variables = a[0].variables().to_vec();
+    let one = MultiPolynomial::constant(variables, Symbol::rational(1, 1));
+    let (mut g, mut h) = (one.clone(), one.clone());
+
+    loop {
+        let delta = a.len() - b.len();
+        let remainder = pseudo_remainder(&a, &b);
+        if remainder.is_empty() {
+            return b;
+        }
+        if remainder.len() == 1 {
+            return vec![one];
+        }
+
+        let divisor = &g * &h.pow(delta);
+        a = b;
+        b = remainder
+            .iter()
+            .map(|term| term.divide(&divisor).unwrap())
+            .collect();
+        g = a[a.len() - 1].clone();
+        h = match delta {
+            0 => h,
+            _ => g.pow(delta).divide(&h.pow(delta - 1)).unwrap(),
+        };
+    }
+}
@@ -0,0 +1,113 @@
+#[cfg(test)]
+mod content {
+    use crate::base::{expression::Expression, symbol::Symbol};
+
+    #[test]
+    fn splits_integer_content() {
+        let x = Symbol::variable("x");
+        let p = Expression::parse("-x^2/2 + 3/4")
+            .unwrap()
+            .as_polynomial(&x)
+            .unwrap();
+
+        assert_eq!(p.content(), Symbol::rational(-1, 4));
+        assert_eq!(
+            p.primitive_part().coefficients(),
+            &[
+                Symbol::rational(-3, 1),
+                Symbol::rational(0, 1),
+                Symbol::rational(2, 1)
+            ][..]
+        );
+    }
+
+    #[test]
+    fn splits_multivariate_content() {
+        let variables = [Symbol::variable("x"), Symbol::variable("y")];
+        let p = Expression::parse("6*x*y - 4*y^2")
+            .unwrap()
+            .as_multi_polynomial(&variables)
+            .unwrap();
+
+        assert_eq!(p.content(), Symbol::rational(2, 1));
+        assert_eq!(p.primitive_part(), p.scale(&Symbol::rational(1, 2)));
+    }
+}
+
+#[cfg(test)]
+mod univariate {
+    use crate::polynomials::polynomial::polynomial;
+
+    #[test]
+    fn common_factor() {
+        assert_eq!(
+            polynomial("x^2 - 1").gcd(&polynomial("x^2 - 2*x + 1")),
+            polynomial("x - 1")
+        );
+        assert_eq!(
+            polynomial("4*x^3 - 4*x").gcd(&polynomial("6*x^2 + 6*x")),
+            polynomial("x^2 + x")
+        );
+    }
+
+    #[test]
+    fn coprime_polynomials() {
+        assert_eq!(
+            polynomial("x^2 + 1").gcd(&polynomial("x - 1")),
+            polynomial("1")
+        );
+        assert_eq!(
+            polynomial("0").gcd(&polynomial("-2*x + 4")),
+            polynomial("x - 2")
+        );
+    }
+}
+
+#[cfg(test)]
+mod multivariate {
+    use crate::base::{expression::Expression, symbol::Symbol};
+
+    fn gcd(a: &str, b: &str, expected: &str) {
+        let variables = [
+            Symbol::variable("x"),
+            Symbol::variable("y"),
+            Symbol::variable("z"),
+        ];
+        let a = Expression::parse(a).unwrap();
+        let b = Expression::parse(b).unwrap();
+        let expected = Expression::parse(expected).unwrap().expand();
+
+        assert_eq!(
+            Expression::polynomial_gcd(&a, &b, &variables),
+            Some(expected)
+        );
+    }
+
+    #[test]
+    fn common_factor() {
+        gcd("(x + y)*(x - y)", "(x + y)^2", "x + y");
+        gcd("x^2*y - x*y^2", "x^3 - x*y^2", "x^2 - x*y");
+        gcd("(x*y + z)^2*(x - 1)", "(x*y + z)*(x + 1)", "x*y + z");
+    }
+
+    #[test]
+    fn content_in_other_variables() {
+        gcd("y*x^2 + y^2*x", "y^2*x + y^3", "x*y + y^2");
+        gcd("2*y*(x + 1)", "4*y^2*(x - 1)", "y");
+    }
+
+    #[test]
+    fn coprime_polynomials() {
+        gcd("x^2 + y^2", "x + y", "1");
+        gcd("x*y + 1", "x + z", "1");
+    }
+
+    #[test]
+    fn rejects_non_polynomials() {
+        let x = Symbol::variable("x");
+        let a = Expression::parse("sin(x)").unwrap();
+        let b = Expression::parse("x").unwrap();
+
+        assert_eq!(Expression::polynomial_gcd(&a, &b, &[x]), None);
+    }
+}
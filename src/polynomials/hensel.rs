@@ -0,0 +1,144 @@
+use crate::base::symbol::Symbol;
+use crate::polynomials::{finite_field_polynomial::FiniteFieldPolynomial, polynomial::Polynomial};
+use num::bigint::BigInt;
+use num::Integer;
+
+/**
+ * Lifts a factorisation modulo p to a factorisation modulo p^exponent
+ *  - the polynomial has integer coefficients and lc * f1 * ... * fr == polynomial mod p,
+ *    with monic, pairwise coprime factors
+ *  - the lifted factors are monic, with coefficients in (-p^k/2, p^k/2]
+ */
+pub fn hensel_lift(
+    polynomial: &Polynomial,
+    factors: &[FiniteFieldPolynomial],
+    exponent: u32,
+) -> Vec<Polynomial> {
+    let field = factors[0].field();
+    let modulus = field.modulus().pow(exponent);
+    let leading = polynomial.leading_coefficient().numerator().data;
+
+    if factors.len() == 1 {
+        let inverse = leading.extended_gcd(&modulus).x;
+        return vec![symmetric_modulo(
+            &polynomial.scale(&Symbol::from(Symbol { data: inverse })),
+            &modulus,
+        )];
+    }
+
+    let (left, right) = factors.split_at(factors.len() / 2);
+    let product = |factors: &[FiniteFieldPolynomial]| {
+        factors.iter().fold(
+            FiniteFieldPolynomial::one(field.clone()),
+            |product, factor| &product * factor,
+        )
+    };
+    let (g, h) = lift_pair(polynomial, &product(left), &product(right), exponent);
+
+    let mut lifted = hensel_lift(&g, left, exponent);
+    lifted.append(&mut hensel_lift(&h, right, exponent));
+    lifted
+}
+
+/**
+ * Linear lifting of lc * g * h == f mod p, one power of p at a time
+ *  - with s * g + t * h == 1 mod p, the corrections solve g * dh + h * dg == e mod p
+ *    for the scaled error e == (f - lc * g * h) / (lc * p^i)
+ */
+fn lift_pair(
+    polynomial: &Polynomial,
+    g_modular: &FiniteFieldPolynomial,
+    h_modular: &FiniteFieldPolynomial,
+    exponent: u32,
+) -> (Polynomial, Polynomial) {
+    let field = g_modular.field().clone();
+    let variable = polynomial.variable().clone();
+    let leading = polynomial.leading_coefficient();
+    let leading_inverse = field
+        .inverse(&leading.numerator().data)
+        .expect("leading coefficient vanishes modulo p");
+    let (s, t) = bezout(g_modular, h_modular);
+
+    let mut g = g_modular.to_polynomial(variable.clone(), true);
+    let mut h = h_modular.to_polynomial(variable.clone(), true);
+    let mut power = field.modulus().clone();
+    for _ in 1..exponent {
+        let error = polynomial - &(&g * &h).scale(&leading);
+        let scaled = Symbol::from(Symbol {
+            data: power.clone(),
+        })
+        .inverse()
+        .unwrap();
+        let error = error
+            .scale(&scaled)
+            .modulo(&field)
+            .unwrap()
+            .scale(&leading_inverse);
+
+        let (quotient, dg) = (&t * &error).div_rem(g_modular).unwrap();
+        let dh = &(&s * &error) + &(&quotient * h_modular);
+
+        let shift = Symbol::from(Symbol {
+            data: power.clone(),
+        });
+        g = &g + &dg.to_polynomial(variable.clone(), true).scale(&shift);
+        h = &h + &dh.to_polynomial(variable.clone(), true).scale(&shift);
+        power *= field.modulus();
+    }
+    (symmetric_modulo(&g, &power), symmetric_modulo(&h, &power))
+}
+
+/**
+ * Bezout coefficients s * a + t * b == 1 of coprime polynomials, by the extended Euclidean
+ * algorithm
+ */
+fn bezout(
+    a: &FiniteFieldPolynomial,
+    b: &FiniteFieldPolynomial,
+) -> (FiniteFieldPolynomial, FiniteFieldPolynomial) {
+    let field = a.field().clone();
+    let (mut r0, mut r1) = (a.clone(), b.clone());
+    let (mut s0, mut s1) = (
+        FiniteFieldPolynomial::one(field.clone()),
+        FiniteFieldPolynomial::zero(field.clone()),
+    );
+    let (mut t0, mut t1) = (
+        FiniteFieldPolynomial::zero(field.clone()),
+        FiniteFieldPolynomial::one(field.clone()),
+    );
+    while let Some((quotient, remainder)) = r0.div_rem(&r1) {
+        let s = &s0 - &(&quotient * &s1);
+        let t = &t0 - &(&quotient * &t1);
+        r0 = std::mem::replace(&mut r1, remainder);
+        s0 = std::mem::replace(&mut s1, s);
+        t0 = std::mem::replace(&mut t1, t);
+    }
+
+    /* r0 is the constant gcd */
+    let inverse = field
+        .inverse(&r0.leading_coefficient())
+        .expect("factors are not coprime modulo p");
+    (s0.scale(&inverse), t0.scale(&inverse))
+}
+
+/**
+ * Integer polynomial with coefficients reduced to (-m/2, m/2]
+ */
+pub(crate) fn symmetric_modulo(polynomial: &Polynomial, modulus: &BigInt) -> Polynomial {
+    Polynomial::new(
+        polynomial.variable().clone(),
+        polynomial
+            .coefficients()
+            .iter()
+            .map(|coefficient| {
+                let residue = coefficient.numerator().data.mod_floor(modulus);
+                let residue = if &residue * 2 > *modulus {
+                    residue - modulus
+                } else {
+                    residue
+                };
+                Symbol::from(Symbol { data: residue })
+            })
+            .collect(),
+    )
+}
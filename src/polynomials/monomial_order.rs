@@ -0,0 +1,34 @@
+use std::cmp::Ordering;
+
+/**
+ * Total orders on monomials, given by their exponent vectors
+ *  - variables are ranked by their position: the first one is the greatest
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MonomialOrder {
+    /* lexicographic: x > y^5 */
+    Lex,
+    /* total degree first, ties by lex: y^5 > x, x*y > y^2 */
+    GradedLex,
+    /* total degree first, ties by the smallest power of the last variable: x*z > y^2 */
+    GradedReverseLex,
+}
+
+impl MonomialOrder {
+    pub fn compare(self, left: &[usize], right: &[usize]) -> Ordering {
+        let total = |exponents: &[usize]| exponents.iter().sum::<usize>();
+        match self {
+            MonomialOrder::Lex => left.cmp(right),
+            MonomialOrder::GradedLex => {
+                total(left).cmp(&total(right)).then_with(|| left.cmp(right))
+            }
+            MonomialOrder::GradedReverseLex => total(left).cmp(&total(right)).then_with(|| {
+                left.iter()
+                    .zip(right.iter())
+                    .rev()
+                    .find(|(l, r)| l != r)
+                    .map_or(Ordering::Equal, |(l, r)| r.cmp(l))
+            }),
+        }
+    }
+}
@@ -0,0 +1,334 @@
+use crate::base::{expression::Expression, symbol::Symbol};
+use crate::polynomials::monomial_order::MonomialOrder;
+use num::rational::BigRational;
+use std::collections::BTreeMap;
+
+/**
+ * Sparse multivariate polynomial with exact rational coefficients
+ *  - terms are keyed by exponent vectors, one exponent per variable in the given order
+ *  - zero coefficients are never stored
+ */
+#[derive(Debug, Clone, PartialEq)]
+pub struct MultiPolynomial {
+    variables: Vec<Symbol<String>>,
+    terms: BTreeMap<Vec<usize>, Symbol<BigRational>>,
+}
+
+impl MultiPolynomial {
+    /**
+     * Sums the terms, merging repeated exponents
+     */
+    pub fn new(
+        variables: Vec<Symbol<String>>,
+        terms: Vec<(Vec<usize>, Symbol<BigRational>)>,
+    ) -> Self {
+        let mut polynomial = Self::zero(variables);
+        for (exponents, coefficient) in terms.into_iter() {
+            assert_eq!(
+                exponents.len(),
+                polynomial.variables.len(),
+                "exponent vector does not match the variables"
+            );
+            polynomial.add_term(exponents, coefficient);
+        }
+        polynomial
+    }
+    pub fn zero(variables: Vec<Symbol<String>>) -> Self {
+        Self {
+            variables,
+            terms: BTreeMap::new(),
+        }
+    }
+    pub fn constant(variables: Vec<Symbol<String>>, value: Symbol<BigRational>) -> Self {
+        let exponents = vec![0; variables.len()];
+        Self::new(variables, vec![(exponents, value)])
+    }
+    /**
+     * Polynomial made of the variable at the position
+     */
+    pub fn generator(variables: Vec<Symbol<String>>, index: usize) -> Self {
+        let mut exponents = vec![0; variables.len()];
+        exponents[index] = 1;
+        Self::new(variables, vec![(exponents, Symbol::rational(1, 1))])
+    }
+
+    pub fn variables(&self) -> &[Symbol<String>] {
+        &self.variables
+    }
+    pub fn terms(&self) -> &BTreeMap<Vec<usize>, Symbol<BigRational>> {
+        &self.terms
+    }
+    pub fn coefficient(&self, exponents: &[usize]) -> Symbol<BigRational> {
+        self.terms
+            .get(exponents)
+            .cloned()
+            .unwrap_or_else(|| Symbol::rational(0, 1))
+    }
+    pub fn is_zero(&self) -> bool {
+        self.terms.is_empty()
+    }
+    /**
+     * Highest sum of exponents, `None` for the zero polynomial
+     */
+    pub fn total_degree(&self) -> Option<usize> {
+        self.terms
+            .keys()
+            .map(|exponents| exponents.iter().sum())
+            .max()
+    }
+    /**
+     * Highest exponent of the variable at the position, `None` for the zero polynomial
+     */
+    pub fn degree(&self, index: usize) -> Option<usize> {
+        self.terms.keys().map(|exponents| exponents[index]).max()
+    }
+
+    /**
+     * Terms from the greatest to the smallest monomial
+     */
+    pub fn sorted_terms(&self, order: MonomialOrder) -> Vec<(Vec<usize>, Symbol<BigRational>)> {
+        let mut terms: Vec<(Vec<usize>, Symbol<BigRational>)> = self
+            .terms
+            .iter()
+            .map(|(exponents, coefficient)| (exponents.clone(), coefficient.clone()))
+            .collect();
+        terms.sort_by(|(left, _), (right, _)| order.compare(right, left));
+        terms
+    }
+    /**
+     * Greatest term in the order, `None` for the zero polynomial
+     */
+    pub fn leading_term(&self, order: MonomialOrder) -> Option<(Vec<usize>, Symbol<BigRational>)> {
+        self.terms
+            .iter()
+            .max_by(|(left, _), (right, _)| order.compare(left, right))
+            .map(|(exponents, coefficient)| (exponents.clone(), coefficient.clone()))
+    }
+    pub fn leading_monomial(&self, order: MonomialOrder) -> Option<Vec<usize>> {
+        self.leading_term(order).map(|(exponents, _)| exponents)
+    }
+    /**
+     * Coefficient of the leading term, zero for the zero polynomial
+     */
+    pub fn leading_coefficient(&self, order: MonomialOrder) -> Symbol<BigRational> {
+        self.leading_term(order)
+            .map_or_else(|| Symbol::rational(0, 1), |(_, coefficient)| coefficient)
+    }
+
+    /**
+     * Power by repeated squaring
+     */
+    pub fn pow(&self, exponent: usize) -> Self {
+        let mut result = Self::constant(self.variables.clone(), Symbol::rational(1, 1));
+        let mut square = self.clone();
+        let mut exponent = exponent;
+        while exponent > 0 {
+            if exponent % 2 == 1 {
+                result = &result * &square;
+            }
+            square = &square * &square;
+            exponent /= 2;
+        }
+        result
+    }
+
+    /**
+     * Multiplies every coefficient by a rational
+     */
+    pub fn scale(&self, factor: &Symbol<BigRational>) -> Self {
+        let terms = self
+            .terms
+            .iter()
+            .map(|(exponents, coefficient)| (exponents.clone(), coefficient * factor))
+            .collect();
+        Self::new(self.variables.clone(), terms)
+    }
+
+    pub fn to_expression(&self) -> Expression {
+        Expression::addition(
+            self.terms
+                .iter()
+                .map(|(exponents, coefficient)| {
+                    let mut factors: Vec<Expression> = self
+                        .variables
+                        .iter()
+                        .zip(exponents.iter())
+                        .map(|(variable, exponent)| {
+                            Expression::power(
+                                variable.clone().expr(),
+                                Symbol::integer(*exponent as isize).expr(),
+                            )
+                        })
+                        .collect();
+                    factors.push(coefficient.clone().expr());
+                    Expression::multiplication(factors)
+                })
+                .collect(),
+        )
+    }
+
+    fn add_term(&mut self, exponents: Vec<usize>, coefficient: Symbol<BigRational>) {
+        let sum = self.coefficient(&exponents) + coefficient;
+        if sum.is_zero() {
+            self.terms.remove(&exponents);
+        } else {
+            self.terms.insert(exponents, sum);
+        }
+    }
+
+    fn assert_same_variables(&self, other: &MultiPolynomial) {
+        assert_eq!(
+            self.variables, other.variables,
+            "polynomials in different variables"
+        );
+    }
+}
+
+impl std::fmt::Display for MultiPolynomial {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.to_expression())
+    }
+}
+
+impl Expression {
+    /**
+     * Polynomial in the variables, when the expression is a polynomial with rational coefficients
+     *  - variables out of the list, constants and reals make it fail, as does any non polynomial node
+     */
+    pub fn as_multi_polynomial(&self, variables: &[Symbol<String>]) -> Option<MultiPolynomial> {
+        let variables_vec = variables.to_vec();
+        match self {
+            Expression::Integer(n) => Some(MultiPolynomial::constant(
+                variables_vec,
+                Symbol::from(n.clone()),
+            )),
+            Expression::Rational(q) => Some(MultiPolynomial::constant(variables_vec, q.clone())),
+            Expression::Variable(v) => {
+                let index = variables.iter().position(|variable| variable == v)?;
+                Some(MultiPolynomial::generator(variables_vec, index))
+            }
+            Expression::Addition(addends) => addends
+                .items()
+                .iter()
+                .try_fold(MultiPolynomial::zero(variables_vec), |sum, addend| {
+                    Some(&sum + &addend.as_multi_polynomial(variables)?)
+                }),
+            Expression::Multiplication(factors) => factors.items().iter().try_fold(
+                MultiPolynomial::constant(variables_vec, Symbol::rational(1, 1)),
+                |product, factor| Some(&product * &factor.as_multi_polynomial(variables)?),
+            ),
+            Expression::Power(power) => match power.modifier() {
+                Expression::Integer(n) if !n.is_negative() => {
+                    let exponent = n.value()? as usize;
+                    Some(
+                        power
+                            .argument()
+                            .as_multi_polynomial(variables)?
+                            .pow(exponent),
+                    )
+                }
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+}
+
+// ================================ //
+//      Arithmetic operations       //
+// ================================ //
+impl std::ops::Add<&MultiPolynomial> for &MultiPolynomial {
+    type Output = MultiPolynomial;
+    fn add(self, other: &MultiPolynomial) -> MultiPolynomial {
+        self.assert_same_variables(other);
+        let mut sum = self.clone();
+        for (exponents, coefficient) in other.terms.iter() {
+            sum.add_term(exponents.clone(), coefficient.clone());
+        }
+        sum
+    }
+}
+
+impl std::ops::Add for MultiPolynomial {
+    type Output = MultiPolynomial;
+    fn add(self, other: MultiPolynomial) -> MultiPolynomial {
+        &self + &other
+    }
+}
+
+impl std::ops::Neg for &MultiPolynomial {
+    type Output = MultiPolynomial;
+    fn neg(self) -> MultiPolynomial {
+        self.scale(&Symbol::rational(-1, 1))
+    }
+}
+
+impl std::ops::Neg for MultiPolynomial {
+    type Output = MultiPolynomial;
+    fn neg(self) -> MultiPolynomial {
+        -&self
+    }
+}
+
+impl std::ops::Sub<&MultiPolynomial> for &MultiPolynomial {
+    type Output = MultiPolynomial;
+    fn sub(self, other: &MultiPolynomial) -> MultiPolynomial {
+        self + &(-other)
+    }
+}
+
+impl std::ops::Sub for MultiPolynomial {
+    type Output = MultiPolynomial;
+    fn sub(self, other: MultiPolynomial) -> MultiPolynomial {
+        &self - &other
+    }
+}
+
+impl std::ops::Mul<&MultiPolynomial> for &MultiPolynomial {
+    type Output = MultiPolynomial;
+    fn mul(self, other: &MultiPolynomial) -> MultiPolynomial {
+        self.assert_same_variables(other);
+        let mut product = MultiPolynomial::zero(self.variables.clone());
+        for (left_exponents, left) in self.terms.iter() {
+            for (right_exponents, right) in other.terms.iter() {
+                product.add_term(
+                    monomial_product(left_exponents, right_exponents),
+                    left * right,
+                );
+            }
+        }
+        product
+    }
+}
+
+impl std::ops::Mul for MultiPolynomial {
+    type Output = MultiPolynomial;
+    fn mul(self, other: MultiPolynomial) -> MultiPolynomial {
+        &self * &other
+    }
+}
+
+/**
+ * Exponents of the product of two monomials
+ */
+fn monomial_product(left: &[usize], right: &[usize]) -> Vec<usize> {
+    left.iter()
+        .zip(right.iter())
+        .map(|(left, right)| left + right)
+        .collect()
+}
+
+/**
+ * Reads a test fixture as a polynomial in x, y and z, panicking on other input
+ */
+#[cfg(test)]
+pub(crate) fn polynomial(input: &str) -> MultiPolynomial {
+    let variables = [
+        Symbol::variable("x"),
+        Symbol::variable("y"),
+        Symbol::variable("z"),
+    ];
+    crate::parsing::parser::parse(input)
+        .as_multi_polynomial(&variables)
+        .unwrap()
+}
@@ -0,0 +1,146 @@
+#[cfg(test)]
+mod orders {
+    use crate::polynomials::monomial_order::MonomialOrder;
+    use std::cmp::Ordering;
+
+    #[test]
+    fn compares_lexicographically() {
+        /* x > y^5 */
+        assert_eq!(
+            MonomialOrder::Lex.compare(&[1, 0], &[0, 5]),
+            Ordering::Greater
+        );
+        assert_eq!(
+            MonomialOrder::Lex.compare(&[1, 2], &[1, 2]),
+            Ordering::Equal
+        );
+    }
+
+    #[test]
+    fn compares_by_degree_first() {
+        /* y^5 > x */
+        assert_eq!(
+            MonomialOrder::GradedLex.compare(&[1, 0], &[0, 5]),
+            Ordering::Less
+        );
+        /* x*z^2 > y^3 in grlex, y^3 > x*z^2 in grevlex */
+        assert_eq!(
+            MonomialOrder::GradedLex.compare(&[1, 0, 2], &[0, 3, 0]),
+            Ordering::Greater
+        );
+        assert_eq!(
+            MonomialOrder::GradedReverseLex.compare(&[1, 0, 2], &[0, 3, 0]),
+            Ordering::Less
+        );
+        /* x*z > y^2 in both */
+        assert_eq!(
+            MonomialOrder::GradedReverseLex.compare(&[1, 0, 1], &[0, 2, 0]),
+            Ordering::Less
+        );
+    }
+}
+
+#[cfg(test)]
+mod polynomial {
+    use crate::base::{expression::Expression, symbol::Symbol};
+    use crate::polynomials::monomial_order::MonomialOrder;
+    use crate::polynomials::multi_polynomial::{polynomial, MultiPolynomial};
+
+    fn variables() -> Vec<Symbol<String>> {
+        vec![
+            Symbol::variable("x"),
+            Symbol::variable("y"),
+            Symbol::variable("z"),
+        ]
+    }
+
+    #[test]
+    fn converts_from_expression() {
+        let p = polynomial("(x + y)^2 - 2*x*y + z/2");
+
+        assert_eq!(p.terms().len(), 3);
+        assert_eq!(p.coefficient(&[2, 0, 0]), Symbol::rational(1, 1));
+        assert_eq!(p.coefficient(&[0, 0, 1]), Symbol::rational(1, 2));
+        assert_eq!(p.coefficient(&[1, 1, 0]), Symbol::rational(0, 1));
+        assert_eq!(p.total_degree(), Some(2));
+        assert_eq!(p.degree(2), Some(1));
+        assert_eq!(
+            p.to_expression(),
+            Expression::parse("x^2 + y^2 + z/2").unwrap()
+        );
+    }
+
+    #[test]
+    fn rejects_non_polynomials() {
+        for input in ["x / y", "w * x", "sin(x)", "x^y", "2.5 * z"].iter() {
+            let expression = Expression::parse(input).unwrap();
+            assert_eq!(expression.as_multi_polynomial(&variables()), None);
+        }
+    }
+
+    #[test]
+    fn operates() {
+        assert_eq!(
+            polynomial("x - y") * polynomial("x + y"),
+            polynomial("x^2 - y^2")
+        );
+        assert_eq!(
+            polynomial("x + z") + polynomial("y - z"),
+            polynomial("x + y")
+        );
+        assert!((polynomial("x*y") - polynomial("y*x")).is_zero());
+        assert_eq!(polynomial("x + 1").pow(2), polynomial("x^2 + 2*x + 1"));
+    }
+
+    #[test]
+    fn queries_leading_terms() {
+        let p = polynomial("x*z^2 + 3*y^3 + 2*x^2 + z");
+
+        assert_eq!(p.leading_monomial(MonomialOrder::Lex), Some(vec![2, 0, 0]));
+        assert_eq!(
+            p.leading_coefficient(MonomialOrder::Lex),
+            Symbol::rational(2, 1)
+        );
+        assert_eq!(
+            p.leading_term(MonomialOrder::GradedLex),
+            Some((vec![1, 0, 2], Symbol::rational(1, 1)))
+        );
+        assert_eq!(
+            p.leading_term(MonomialOrder::GradedReverseLex),
+            Some((vec![0, 3, 0], Symbol::rational(3, 1)))
+        );
+        assert_eq!(
+            p.sorted_terms(MonomialOrder::Lex)
+                .iter()
+                .map(|(exponents, _)| exponents.clone())
+                .collect::<Vec<Vec<usize>>>(),
+            vec![vec![2, 0, 0], vec![1, 0, 2], vec![0, 3, 0], vec![0, 0, 1]]
+        );
+        assert_eq!(
+            MultiPolynomial::zero(variables()).leading_term(MonomialOrder::Lex),
+            None
+        );
+    }
+}
+
+#[cfg(test)]
+mod expansion {
+    use crate::parsing::parser::parse;
+
+    #[test]
+    fn collects_polynomial_inputs() {
+        assert_eq!(
+            parse("(x + y)^3 - x^3").expand(),
+            parse("3*x^2*y + 3*x*y^2 + y^3")
+        );
+        assert_eq!(parse("(x - 1)*(x + 1) + 1").expand(), parse("x^2"));
+    }
+
+    #[test]
+    fn collects_polynomial_subtrees() {
+        assert_eq!(
+            parse("sin(x) * (x + 1)^2").expand(),
+            parse("sin(x)*x^2 + 2*x*sin(x) + sin(x)")
+        );
+    }
+}
@@ -0,0 +1,305 @@
+use crate::base::{expression::Expression, symbol::Symbol};
+use num::rational::BigRational;
+
+/**
+ * Dense univariate polynomial with exact rational coefficients
+ *  - coefficients are ordered from the constant term up to the leading term
+ *  - trailing zeros are dropped, the zero polynomial has no coefficients
+ */
+#[derive(Debug, Clone, PartialEq)]
+pub struct Polynomial {
+    variable: Symbol<String>,
+    coefficients: Vec<Symbol<BigRational>>,
+}
+
+impl Polynomial {
+    pub fn new(variable: Symbol<String>, coefficients: Vec<Symbol<BigRational>>) -> Self {
+        let mut coefficients = coefficients;
+        while coefficients.last().filter(|last| last.is_zero()).is_some() {
+            coefficients.pop();
+        }
+        Self {
+            variable,
+            coefficients,
+        }
+    }
+    pub fn zero(variable: Symbol<String>) -> Self {
+        Self::new(variable, vec![])
+    }
+    pub fn constant(variable: Symbol<String>, value: Symbol<BigRational>) -> Self {
+        Self::new(variable, vec![value])
+    }
+    /**
+     * Single term: coefficient * variable ^ degree
+     */
+    pub fn monomial(
+        variable: Symbol<String>,
+        coefficient: Symbol<BigRational>,
+        degree: usize,
+    ) -> Self {
+        let mut coefficients = vec![Symbol::rational(0, 1); degree];
+        coefficients.push(coefficient);
+        Self::new(variable, coefficients)
+    }
+
+    pub fn variable(&self) -> &Symbol<String> {
+        &self.variable
+    }
+    pub fn coefficients(&self) -> &[Symbol<BigRational>] {
+        &self.coefficients
+    }
+    /**
+     * Coefficient of variable ^ power, zero above the degree
+     */
+    pub fn coefficient(&self, power: usize) -> Symbol<BigRational> {
+        self.coefficients
+            .get(power)
+            .cloned()
+            .unwrap_or_else(|| Symbol::rational(0, 1))
+    }
+    pub fn is_zero(&self) -> bool {
+        self.coefficients.is_empty()
+    }
+    /**
+     * Highest power with non zero coefficient, `None` for the zero polynomial
+     */
+    pub fn degree(&self) -> Option<usize> {
+        self.coefficients.len().checked_sub(1)
+    }
+    /**
+     * Coefficient of the highest power, zero for the zero polynomial
+     */
+    pub fn leading_coefficient(&self) -> Symbol<BigRational> {
+        self.coefficients
+            .last()
+            .cloned()
+            .unwrap_or_else(|| Symbol::rational(0, 1))
+    }
+
+    /**
+     * Value at a rational point, by Horner's scheme
+     */
+    pub fn evaluate(&self, value: &Symbol<BigRational>) -> Symbol<BigRational> {
+        self.coefficients
+            .iter()
+            .rev()
+            .fold(Symbol::rational(0, 1), |result, coefficient| {
+                result * value + coefficient
+            })
+    }
+
+    pub fn derivative(&self) -> Self {
+        let coefficients = self
+            .coefficients
+            .iter()
+            .enumerate()
+            .skip(1)
+            .map(|(power, coefficient)| coefficient * Symbol::rational(power as isize, 1))
+            .collect();
+        Self::new(self.variable.clone(), coefficients)
+    }
+
+    /**
+     * Substitutes the variable by another polynomial: p(q(x)), in the variable of q
+     */
+    pub fn compose(&self, inner: &Polynomial) -> Self {
+        self.coefficients.iter().rev().fold(
+            Self::zero(inner.variable.clone()),
+            |result, coefficient| {
+                &(&result * inner) + &Self::constant(inner.variable.clone(), coefficient.clone())
+            },
+        )
+    }
+
+    /**
+     * Power by repeated squaring
+     */
+    pub fn pow(&self, exponent: usize) -> Self {
+        let mut result = Self::constant(self.variable.clone(), Symbol::rational(1, 1));
+        let mut square = self.clone();
+        let mut exponent = exponent;
+        while exponent > 0 {
+            if exponent % 2 == 1 {
+                result = &result * &square;
+            }
+            square = &square * &square;
+            exponent /= 2;
+        }
+        result
+    }
+
+    /**
+     * Multiplies every coefficient by a rational
+     */
+    pub fn scale(&self, factor: &Symbol<BigRational>) -> Self {
+        let coefficients = self
+            .coefficients
+            .iter()
+            .map(|coefficient| coefficient * factor)
+            .collect();
+        Self::new(self.variable.clone(), coefficients)
+    }
+
+    pub fn to_expression(&self) -> Expression {
+        let variable = self.variable.clone().expr();
+        Expression::addition(
+            self.coefficients
+                .iter()
+                .enumerate()
+                .map(|(power, coefficient)| {
+                    coefficient.clone().expr()
+                        * Expression::power(
+                            variable.clone(),
+                            Symbol::integer(power as isize).expr(),
+                        )
+                })
+                .collect(),
+        )
+    }
+
+    /**
+     * Variable shared by both operands, a constant adopts the variable of the other
+     */
+    fn common_variable(&self, other: &Polynomial) -> Symbol<String> {
+        match (self.degree(), other.degree()) {
+            (Some(degree), _) if degree > 0 => {
+                assert!(
+                    other.degree().unwrap_or(0) == 0 || self.variable == other.variable,
+                    "polynomials in different variables"
+                );
+                self.variable.clone()
+            }
+            _ => other.variable.clone(),
+        }
+    }
+}
+
+impl std::fmt::Display for Polynomial {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.to_expression())
+    }
+}
+
+impl Expression {
+    /**
+     * Polynomial in the variable, when the expression is a polynomial with rational coefficients
+     *  - other variables, constants and reals make it fail, as does any non polynomial node
+     */
+    pub fn as_polynomial(&self, variable: &Symbol<String>) -> Option<Polynomial> {
+        match self {
+            Expression::Integer(n) => Some(Polynomial::constant(
+                variable.clone(),
+                Symbol::from(n.clone()),
+            )),
+            Expression::Rational(q) => Some(Polynomial::constant(variable.clone(), q.clone())),
+            Expression::Variable(v) if v == variable => Some(Polynomial::monomial(
+                variable.clone(),
+                Symbol::rational(1, 1),
+                1,
+            )),
+            Expression::Addition(addends) => addends
+                .items()
+                .iter()
+                .try_fold(Polynomial::zero(variable.clone()), |sum, addend| {
+                    Some(&sum + &addend.as_polynomial(variable)?)
+                }),
+            Expression::Multiplication(factors) => factors.items().iter().try_fold(
+                Polynomial::constant(variable.clone(), Symbol::rational(1, 1)),
+                |product, factor| Some(&product * &factor.as_polynomial(variable)?),
+            ),
+            Expression::Power(power) => match power.modifier() {
+                Expression::Integer(n) if !n.is_negative() => {
+                    let exponent = n.value()? as usize;
+                    Some(power.argument().as_polynomial(variable)?.pow(exponent))
+                }
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+}
+
+// ================================ //
+//      Arithmetic operations       //
+// ================================ //
+impl std::ops::Add<&Polynomial> for &Polynomial {
+    type Output = Polynomial;
+    fn add(self, other: &Polynomial) -> Polynomial {
+        let length = self.coefficients.len().max(other.coefficients.len());
+        let coefficients = (0..length)
+            .map(|power| self.coefficient(power) + other.coefficient(power))
+            .collect();
+        Polynomial::new(self.common_variable(other), coefficients)
+    }
+}
+
+impl std::ops::Add for Polynomial {
+    type Output = Polynomial;
+    fn add(self, other: Polynomial) -> Polynomial {
+        &self + &other
+    }
+}
+
+impl std::ops::Neg for &Polynomial {
+    type Output = Polynomial;
+    fn neg(self) -> Polynomial {
+        self.scale(&Symbol::rational(-1, 1))
+    }
+}
+
+impl std::ops::Neg for Polynomial {
+    type Output = Polynomial;
+    fn neg(self) -> Polynomial {
+        -&self
+    }
+}
+
+impl std::ops::Sub<&Polynomial> for &Polynomial {
+    type Output = Polynomial;
+    fn sub(self, other: &Polynomial) -> Polynomial {
+        self + &(-other)
+    }
+}
+
+impl std::ops::Sub for Polynomial {
+    type Output = Polynomial;
+    fn sub(self, other: Polynomial) -> Polynomial {
+        &self - &other
+    }
+}
+
+impl std::ops::Mul<&Polynomial> for &Polynomial {
+    type Output = Polynomial;
+    fn mul(self, other: &Polynomial) -> Polynomial {
+        let variable = self.common_variable(other);
+        if self.is_zero() || other.is_zero() {
+            return Polynomial::zero(variable);
+        }
+
+        let mut coefficients =
+            vec![Symbol::rational(0, 1); self.coefficients.len() + other.coefficients.len() - 1];
+        for (i, left) in self.coefficients.iter().enumerate() {
+            for (j, right) in other.coefficients.iter().enumerate() {
+                coefficients[i + j] = &coefficients[i + j] + &(left * right);
+            }
+        }
+        Polynomial::new(variable, coefficients)
+    }
+}
+
+impl std::ops::Mul for Polynomial {
+    type Output = Polynomial;
+    fn mul(self, other: Polynomial) -> Polynomial {
+        &self * &other
+    }
+}
+
+/**
+ * Reads a test fixture as a polynomial in x, panicking on other input
+ */
+#[cfg(test)]
+pub(crate) fn polynomial(input: &str) -> Polynomial {
+    crate::parsing::parser::parse(input)
+        .as_polynomial(&Symbol::variable("x"))
+        .unwrap()
+}
@@ -0,0 +1,124 @@
+#[cfg(test)]
+mod conversion {
+    use crate::base::symbol::Symbol;
+    use crate::parsing::parser::parse;
+
+    #[test]
+    fn reads_coefficients() {
+        let x = Symbol::variable("x");
+        let p = parse("(x + 1)^2 * (x - 1) / 2").as_polynomial(&x).unwrap();
+
+        assert_eq!(p.degree(), Some(3));
+        assert_eq!(p.leading_coefficient(), Symbol::rational(1, 2));
+        assert_eq!(
+            p.coefficients(),
+            &[
+                Symbol::rational(-1, 2),
+                Symbol::rational(-1, 2),
+                Symbol::rational(1, 2),
+                Symbol::rational(1, 2)
+            ][..]
+        );
+        assert_eq!(p.coefficient(7), Symbol::rational(0, 1));
+    }
+
+    #[test]
+    fn rejects_non_polynomials() {
+        let x = Symbol::variable("x");
+        for input in ["x^(1/2)", "1/x", "x * y", "sin(x)", "pi * x", "1.5 * x"].iter() {
+            assert_eq!(parse(input).as_polynomial(&x), None);
+        }
+    }
+
+    #[test]
+    fn round_trips_through_expression() {
+        let x = Symbol::variable("x");
+        let p = parse("x^3 - 2*x + 1/3").as_polynomial(&x).unwrap();
+
+        assert_eq!(p.to_expression(), parse("x^3 - 2*x + 1/3"));
+        assert_eq!(p.to_expression().as_polynomial(&x), Some(p));
+        assert_eq!(parse("x - x").as_polynomial(&x).unwrap().degree(), None);
+    }
+}
+
+#[cfg(test)]
+mod arithmetic {
+    use crate::base::symbol::Symbol;
+    use crate::polynomials::polynomial::{polynomial, Polynomial};
+
+    #[test]
+    fn adds_and_subtracts() {
+        assert_eq!(
+            polynomial("x^2 + 1") + polynomial("x - 1"),
+            polynomial("x^2 + x")
+        );
+        assert_eq!(polynomial("x^2 + x") - polynomial("x^2"), polynomial("x"));
+        assert!((polynomial("x^3") - polynomial("x^3")).is_zero());
+    }
+
+    #[test]
+    fn multiplies() {
+        assert_eq!(
+            polynomial("x - 1") * polynomial("x + 1"),
+            polynomial("x^2 - 1")
+        );
+        assert_eq!(
+            polynomial("x + 1").pow(3),
+            polynomial("x^3 + 3*x^2 + 3*x + 1")
+        );
+        assert_eq!(polynomial("2") * polynomial("x"), polynomial("2*x"));
+    }
+
+    #[test]
+    fn keeps_constants_variable_free() {
+        let y = Symbol::variable("y");
+        let constant = Polynomial::constant(y, Symbol::rational(3, 1));
+        assert_eq!(&constant * &polynomial("x"), polynomial("3*x"));
+    }
+
+    #[test]
+    fn displays_as_expression() {
+        assert_eq!(format!("{}", polynomial("x^2 - 3*x")), "x^2 - 3 * x");
+    }
+}
+
+#[cfg(test)]
+mod calculus {
+    use crate::base::{expression::Expression, symbol::Symbol};
+    use crate::polynomials::polynomial::polynomial;
+
+    #[test]
+    fn evaluates() {
+        let p = polynomial("x^3 - 2*x + 1");
+        assert_eq!(p.evaluate(&Symbol::rational(2, 1)), Symbol::rational(5, 1));
+        assert_eq!(p.evaluate(&Symbol::rational(1, 2)), Symbol::rational(1, 8));
+    }
+
+    #[test]
+    fn differentiates() {
+        assert_eq!(
+            polynomial("x^3 - 2*x + 1").derivative(),
+            polynomial("3*x^2 - 2")
+        );
+        assert!(polynomial("7").derivative().is_zero());
+    }
+
+    #[test]
+    fn composes() {
+        let t = Symbol::variable("t");
+        let inner = Expression::parse("t + 1")
+            .unwrap()
+            .as_polynomial(&t)
+            .unwrap();
+        let composed = polynomial("x^2 - 1").compose(&inner);
+
+        assert_eq!(composed.variable(), &t);
+        assert_eq!(
+            composed,
+            Expression::parse("t^2 + 2*t")
+                .unwrap()
+                .as_polynomial(&t)
+                .unwrap()
+        );
+    }
+}
@@ -0,0 +1,41 @@
+use num::bigint::{BigInt, Sign};
+
+/**
+ * Source of random bits for the randomised algorithms, supplied by the caller
+ */
+pub trait RandomSource {
+    fn next_u64(&mut self) -> u64;
+
+    /**
+     * Uniform enough integer in [0, bound), for a positive bound
+     */
+    fn below(&mut self, bound: &BigInt) -> BigInt {
+        let words = bound.bits() / 32 + 2;
+        let digits: Vec<u32> = (0..words).map(|_| self.next_u64() as u32).collect();
+        BigInt::new(Sign::Plus, digits) % bound
+    }
+}
+
+/**
+ * Seeded SplitMix64 generator, reproducible across runs
+ */
+#[derive(Debug, Clone)]
+pub struct SplitMix64 {
+    state: u64,
+}
+
+impl SplitMix64 {
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+}
+
+impl RandomSource for SplitMix64 {
+    fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+}
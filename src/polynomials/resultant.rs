@@ -0,0 +1,71 @@
+use crate::base::symbol::Symbol;
+use crate::polynomials::{gcd::coefficients_in, multi_polynomial::MultiPolynomial};
+
+impl MultiPolynomial {
+    /**
+     * Resultant with respect to the variable at the index, free of that variable
+     *  - determinant of the Sylvester matrix, by Bareiss' fraction free elimination
+     *  - vanishes exactly when both polynomials share a factor in that variable
+     */
+    pub fn resultant(&self, other: &MultiPolynomial, index: usize) -> MultiPolynomial {
+        assert_eq!(
+            self.variables(),
+            other.variables(),
+            "polynomials in different variables"
+        );
+        let variables = self.variables().to_vec();
+        let zero = MultiPolynomial::zero(variables.clone());
+        let one = MultiPolynomial::constant(variables, Symbol::rational(1, 1));
+        if self.is_zero() || other.is_zero() {
+            return zero;
+        }
+
+        let a = coefficients_in(self, index);
+        let b = coefficients_in(other, index);
+        let (m, n) = (a.len() - 1, b.len() - 1);
+        let size = m + n;
+        if size == 0 {
+            return one;
+        }
+
+        /* n shifted rows of a, then m shifted rows of b, from the leading coefficients */
+        let mut matrix = vec![vec![zero.clone(); size]; size];
+        for row in 0..n {
+            for (offset, coefficient) in a.iter().rev().enumerate() {
+                matrix[row][row + offset] = coefficient.clone();
+            }
+        }
+        for row in 0..m {
+            for (offset, coefficient) in b.iter().rev().enumerate() {
+                matrix[n + row][row + offset] = coefficient.clone();
+            }
+        }
+
+        let mut negative = false;
+        let mut previous = one;
+        for k in 0..size - 1 {
+            if matrix[k][k].is_zero() {
+                match (k + 1..size).find(|&row| !matrix[row][k].is_zero()) {
+                    Some(row) => {
+                        matrix.swap(k, row);
+                        negative = !negative;
+                    }
+                    None => return zero,
+                }
+            }
+            for i in k + 1..size {
+                for j in k + 1..size {
+                    let cross = &(&matrix[i][j] * &matrix[k][k]) - &(&matrix[i][k] * &matrix[k][j]);
+                    matrix[i][j] = cross.divide(&previous).unwrap();
+                }
+            }
+            previous = matrix[k][k].clone();
+        }
+
+        let determinant = matrix[size - 1][size - 1].clone();
+        match negative {
+            true => -&determinant,
+            false => determinant,
+        }
+    }
+}
@@ -0,0 +1,36 @@
+#[cfg(test)]
+mod resultant {
+    use crate::polynomials::multi_polynomial::polynomial;
+
+    #[test]
+    fn eliminates_variable() {
+        assert_eq!(
+            polynomial("y^2 - 2").resultant(&polynomial("x - y"), 1),
+            polynomial("x^2 - 2")
+        );
+        /* x == y^2 and x == y + 1 meet where x == (1 +- sqrt(5))/2 + 1 */
+        assert_eq!(
+            polynomial("y^2 - x").resultant(&polynomial("y + 1 - x"), 1),
+            polynomial("x^2 - 3*x + 1")
+        );
+    }
+
+    #[test]
+    fn vanishes_on_common_factors() {
+        assert!(polynomial("(y - x)*(y + 1)")
+            .resultant(&polynomial("(y - x)*(y - 2)"), 1)
+            .is_zero());
+    }
+
+    #[test]
+    fn constant_polynomials() {
+        assert_eq!(
+            polynomial("y^3 + x").resultant(&polynomial("2"), 1),
+            polynomial("8")
+        );
+        assert_eq!(
+            polynomial("3").resultant(&polynomial("5"), 1),
+            polynomial("1")
+        );
+    }
+}
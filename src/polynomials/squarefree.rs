@@ -0,0 +1,82 @@
+use crate::base::{expression::Expression, symbol::Symbol};
+use crate::polynomials::polynomial::Polynomial;
+use num::rational::BigRational;
+
+impl Polynomial {
+    /**
+     * Square-free decomposition by Yun's algorithm
+     *  - self == constant * a1 * a2^2 * ... * ak^k, with each ai square-free and pairwise coprime
+     *  - the factors are primitive with positive leading coefficient, constant ones are left out
+     */
+    pub fn squarefree_decomposition(&self) -> (Symbol<BigRational>, Vec<(Polynomial, usize)>) {
+        if self.degree().unwrap_or(0) == 0 {
+            return (self.coefficient(0), vec![]);
+        }
+
+        let derivative = self.derivative();
+        let common = self.gcd(&derivative);
+        let mut b = exact_quotient(self, &common);
+        let mut d = &exact_quotient(&derivative, &common) - &b.derivative();
+
+        let mut factors = vec![];
+        let mut multiplicity = 1;
+        while b.degree() > Some(0) {
+            let factor = b.gcd(&d);
+            b = exact_quotient(&b, &factor);
+            d = &exact_quotient(&d, &factor) - &b.derivative();
+            if factor.degree() > Some(0) {
+                factors.push((factor, multiplicity));
+            }
+            multiplicity += 1;
+        }
+
+        let leading =
+            factors
+                .iter()
+                .fold(Symbol::rational(1, 1), |leading, (factor, multiplicity)| {
+                    let exponent = Symbol::integer(*multiplicity as isize);
+                    leading * factor.leading_coefficient().pow(&exponent).unwrap()
+                });
+        let constant = self.leading_coefficient() / leading;
+        (constant, factors)
+    }
+}
+
+impl Expression {
+    /**
+     * Square-free factorisation of a polynomial in the variable, as a product of powers
+     *  - (x^3 - x^2 - x + 1) == (x + 1) * (x - 1)^2
+     *  - expressions that are not polynomials in the variable are left unchanged
+     */
+    pub fn factor_squarefree(&self, variable: &Symbol<String>) -> Expression {
+        match self.as_polynomial(variable) {
+            Some(polynomial) if !polynomial.is_zero() => {
+                let (constant, factors) = polynomial.squarefree_decomposition();
+                product_of_powers(&constant, &factors)
+            }
+            _ => self.clone(),
+        }
+    }
+}
+
+/**
+ * constant * factor1^multiplicity1 * ... * factork^multiplicityk
+ */
+pub(crate) fn product_of_powers(
+    constant: &Symbol<BigRational>,
+    factors: &[(Polynomial, usize)],
+) -> Expression {
+    let mut product = vec![constant.clone().expr()];
+    product.extend(factors.iter().map(|(factor, multiplicity)| {
+        Expression::power(
+            factor.to_expression(),
+            Symbol::integer(*multiplicity as isize).expr(),
+        )
+    }));
+    Expression::multiplication(product)
+}
+
+fn exact_quotient(dividend: &Polynomial, divisor: &Polynomial) -> Polynomial {
+    let (quotient, _) = dividend.div_rem(divisor).unwrap();
+    quotient
+}
@@ -0,0 +1,70 @@
+#[cfg(test)]
+mod decomposition {
+    use crate::base::symbol::Symbol;
+    use crate::polynomials::polynomial::polynomial;
+
+    #[test]
+    fn groups_factors_by_multiplicity() {
+        let (constant, factors) =
+            polynomial("(x - 1)^3 * (x + 2)^2 * (x^2 + 1) * 3/2").squarefree_decomposition();
+
+        assert_eq!(constant, Symbol::rational(3, 2));
+        assert_eq!(
+            factors,
+            vec![
+                (polynomial("x^2 + 1"), 1),
+                (polynomial("x + 2"), 2),
+                (polynomial("x - 1"), 3)
+            ]
+        );
+    }
+
+    #[test]
+    fn merges_factors_of_same_multiplicity() {
+        let (constant, factors) = polynomial("x^4 - 2*x^2 + 1").squarefree_decomposition();
+
+        assert_eq!(constant, Symbol::rational(1, 1));
+        assert_eq!(factors, vec![(polynomial("x^2 - 1"), 2)]);
+    }
+
+    #[test]
+    fn keeps_squarefree_polynomials() {
+        let (constant, factors) = polynomial("-2*x^2 + 4").squarefree_decomposition();
+
+        assert_eq!(constant, Symbol::rational(-2, 1));
+        assert_eq!(factors, vec![(polynomial("x^2 - 2"), 1)]);
+    }
+
+    #[test]
+    fn constants_have_no_factors() {
+        assert_eq!(
+            polynomial("5").squarefree_decomposition(),
+            (Symbol::rational(5, 1), vec![])
+        );
+    }
+}
+
+#[cfg(test)]
+mod factorisation {
+    use crate::base::{expression::Expression, symbol::Symbol};
+
+    fn factor(input: &str, expected: &str) {
+        let x = Symbol::variable("x");
+        let trial = Expression::parse(input).unwrap().factor_squarefree(&x);
+        let expected = Expression::parse(expected).unwrap();
+
+        assert_eq!(trial, expected);
+    }
+
+    #[test]
+    fn builds_powers() {
+        factor("x^3 - x^2 - x + 1", "(x + 1) * (x - 1)^2");
+        factor("2*x^5 + 4*x^4 + 2*x^3", "2 * x^3 * (x + 1)^2");
+    }
+
+    #[test]
+    fn leaves_non_polynomials() {
+        factor("sin(x) + 1", "sin(x) + 1");
+        factor("x*y + y", "x*y + y");
+    }
+}
@@ -0,0 +1,327 @@
+use crate::base::{expression::Expression, symbol::Symbol};
+use crate::polynomials::{
+    algebraic_extension::AlgebraicExtension, multi_polynomial::MultiPolynomial,
+    polynomial::Polynomial,
+};
+use num::rational::BigRational;
+
+/**
+ * Polynomial in the main variable with coefficients in the extension, from the constant term
+ */
+type ExtensionPolynomial = Vec<Polynomial>;
+
+impl Expression {
+    /**
+     * Factorisation over an algebraic extension of a polynomial in a single other variable
+     *  - by Trager's algorithm: factor the norm over the integers, then take the gcd of
+     *    each factor with the shifted polynomial
+     *  - (x^2 - 2) == (x - a) * (x + a) over Q(a) with a^2 - 2 == 0
+     *  - other expressions are left unchanged
+     */
+    pub(crate) fn factor_over(&self, extension: &AlgebraicExtension) -> Expression {
+        let generator = extension.generator().clone();
+        let variables: Vec<Symbol<String>> = self
+            .variables()
+            .into_iter()
+            .filter(|variable| variable != &generator)
+            .collect();
+        let variable = match variables.as_slice() {
+            [variable] => variable.clone(),
+            _ => return self.clone(),
+        };
+        let polynomial = match self.as_multi_polynomial(&[variable.clone(), generator.clone()]) {
+            Some(polynomial) => from_multi_polynomial(extension, &polynomial),
+            None => return self.clone(),
+        };
+        if polynomial.is_empty() {
+            return self.clone();
+        }
+
+        let leading = polynomial[polynomial.len() - 1].clone();
+        let mut product = vec![leading.to_expression()];
+        for (squarefree, multiplicity) in
+            squarefree_decomposition(extension, &monic(extension, &polynomial))
+        {
+            for factor in trager(extension, &variable, &squarefree) {
+                product.push(Expression::power(
+                    to_multi_polynomial(extension, &variable, &factor).to_expression(),
+                    Symbol::integer(multiplicity as isize).expr(),
+                ));
+            }
+        }
+        Expression::multiplication(product)
+    }
+}
+
+/**
+ * Irreducible monic factors of a monic square-free polynomial
+ *  - the norm of f(x - s a) is square-free for all but finitely many s, and then its
+ *    factors over the rationals are norms of the factors of f(x - s a)
+ */
+fn trager(
+    extension: &AlgebraicExtension,
+    variable: &Symbol<String>,
+    polynomial: &ExtensionPolynomial,
+) -> Vec<ExtensionPolynomial> {
+    if polynomial.len() <= 2 {
+        return vec![polynomial.clone()];
+    }
+
+    let generator = extension.generator().clone();
+    let minimal = to_multi_polynomial(extension, variable, &vec![extension.minimal().clone()]);
+    let alpha = Polynomial::monomial(generator, Symbol::rational(1, 1), 1);
+
+    let mut shift = 0;
+    loop {
+        let step = alpha.scale(&Symbol::rational(shift, 1));
+        let shifted = translate(extension, polynomial, &step.scale(&Symbol::rational(-1, 1)));
+        let norm = minimal.resultant(&to_multi_polynomial(extension, variable, &shifted), 1);
+        let norm = rational_polynomial(variable, &norm);
+
+        if norm.gcd(&norm.derivative()).degree() == Some(0) {
+            let (_, factors) = norm.factor_integer();
+            if factors.len() == 1 {
+                return vec![polynomial.clone()];
+            }
+            return factors
+                .iter()
+                .map(|(factor, _)| {
+                    let factor: ExtensionPolynomial = factor
+                        .coefficients()
+                        .iter()
+                        .map(|coefficient| extension.constant(coefficient.clone()))
+                        .collect();
+                    translate(extension, &gcd(extension, &factor, &shifted), &step)
+                })
+                .collect();
+        }
+
+        /* 0, 1, -1, 2, -2, ... */
+        shift = if shift > 0 { -shift } else { 1 - shift };
+    }
+}
+
+// ==================================================== //
+//      Polynomials with coefficients in Q(a)           //
+// ==================================================== //
+
+fn from_multi_polynomial(
+    extension: &AlgebraicExtension,
+    polynomial: &MultiPolynomial,
+) -> ExtensionPolynomial {
+    let degree = polynomial.degree(0).unwrap_or(0);
+    let mut coefficients = vec![vec![]; degree + 1];
+    for (exponents, coefficient) in polynomial.terms().iter() {
+        let row: &mut Vec<Symbol<BigRational>> = &mut coefficients[exponents[0]];
+        if row.len() <= exponents[1] {
+            row.resize(exponents[1] + 1, Symbol::rational(0, 1));
+        }
+        row[exponents[1]] = coefficient.clone();
+    }
+    trimmed(
+        coefficients
+            .into_iter()
+            .map(|coefficients| {
+                extension.element(&Polynomial::new(
+                    extension.generator().clone(),
+                    coefficients,
+                ))
+            })
+            .collect(),
+    )
+}
+
+fn to_multi_polynomial(
+    extension: &AlgebraicExtension,
+    variable: &Symbol<String>,
+    polynomial: &ExtensionPolynomial,
+) -> MultiPolynomial {
+    let terms = polynomial
+        .iter()
+        .enumerate()
+        .flat_map(|(power, coefficient)| {
+            coefficient
+                .coefficients()
+                .iter()
+                .enumerate()
+                .map(move |(degree, value)| (vec![power, degree], value.clone()))
+        })
+        .collect();
+    MultiPolynomial::new(vec![variable.clone(), extension.generator().clone()], terms)
+}
+
+/**
+ * Rational polynomial in the variable, from one free of the generator
+ */
+fn rational_polynomial(variable: &Symbol<String>, polynomial: &MultiPolynomial) -> Polynomial {
+    let degree = polynomial.degree(0).unwrap_or(0);
+    Polynomial::new(
+        variable.clone(),
+        (0..=degree)
+            .map(|power| polynomial.coefficient(&[power, 0]))
+            .collect(),
+    )
+}
+
+fn trimmed(polynomial: ExtensionPolynomial) -> ExtensionPolynomial {
+    let mut polynomial = polynomial;
+    while polynomial.last().filter(|last| last.is_zero()).is_some() {
+        polynomial.pop();
+    }
+    polynomial
+}
+
+fn add(left: &ExtensionPolynomial, right: &ExtensionPolynomial) -> ExtensionPolynomial {
+    let length = left.len().max(right.len());
+    trimmed(
+        (0..length)
+            .map(|power| match (left.get(power), right.get(power)) {
+                (Some(left), Some(right)) => left + right,
+                (Some(term), None) | (None, Some(term)) => term.clone(),
+                (None, None) => unreachable!(),
+            })
+            .collect(),
+    )
+}
+
+fn sub(left: &ExtensionPolynomial, right: &ExtensionPolynomial) -> ExtensionPolynomial {
+    add(left, &right.iter().map(|term| -term).collect())
+}
+
+fn mul(
+    extension: &AlgebraicExtension,
+    left: &ExtensionPolynomial,
+    right: &ExtensionPolynomial,
+) -> ExtensionPolynomial {
+    if left.is_empty() || right.is_empty() {
+        return vec![];
+    }
+    let zero = Polynomial::zero(extension.generator().clone());
+    let mut product = vec![zero; left.len() + right.len() - 1];
+    for (i, a) in left.iter().enumerate() {
+        for (j, b) in right.iter().enumerate() {
+            product[i + j] = &product[i + j] + &extension.mul(a, b);
+        }
+    }
+    trimmed(product)
+}
+
+fn scale(
+    extension: &AlgebraicExtension,
+    polynomial: &ExtensionPolynomial,
+    factor: &Polynomial,
+) -> ExtensionPolynomial {
+    trimmed(
+        polynomial
+            .iter()
+            .map(|term| extension.mul(term, factor))
+            .collect(),
+    )
+}
+
+fn monic(extension: &AlgebraicExtension, polynomial: &ExtensionPolynomial) -> ExtensionPolynomial {
+    match polynomial
+        .last()
+        .and_then(|leading| extension.inverse(leading))
+    {
+        Some(inverse) => scale(extension, polynomial, &inverse),
+        None => polynomial.clone(),
+    }
+}
+
+fn derivative(polynomial: &ExtensionPolynomial) -> ExtensionPolynomial {
+    trimmed(
+        polynomial
+            .iter()
+            .enumerate()
+            .skip(1)
+            .map(|(power, term)| term.scale(&Symbol::rational(power as isize, 1)))
+            .collect(),
+    )
+}
+
+/**
+ * Euclidean division, the divisor must not be zero
+ */
+fn div_rem(
+    extension: &AlgebraicExtension,
+    dividend: &ExtensionPolynomial,
+    divisor: &ExtensionPolynomial,
+) -> (ExtensionPolynomial, ExtensionPolynomial) {
+    let inverse = extension.inverse(&divisor[divisor.len() - 1]).unwrap();
+    let zero = Polynomial::zero(extension.generator().clone());
+    let mut quotient = vec![zero.clone(); dividend.len()];
+    let mut remainder = dividend.clone();
+    while remainder.len() >= divisor.len() {
+        let shift = remainder.len() - divisor.len();
+        let coefficient = extension.mul(&remainder[remainder.len() - 1], &inverse);
+        let mut term = vec![zero.clone(); shift];
+        term.push(coefficient.clone());
+        remainder = sub(&remainder, &mul(extension, &term, divisor));
+        quotient[shift] = coefficient;
+    }
+    (trimmed(quotient), remainder)
+}
+
+/**
+ * Monic greatest common divisor by the Euclidean algorithm
+ */
+fn gcd(
+    extension: &AlgebraicExtension,
+    left: &ExtensionPolynomial,
+    right: &ExtensionPolynomial,
+) -> ExtensionPolynomial {
+    let (mut a, mut b) = (left.clone(), right.clone());
+    while !b.is_empty() {
+        let (_, remainder) = div_rem(extension, &a, &b);
+        a = b;
+        b = remainder;
+    }
+    monic(extension, &a)
+}
+
+/**
+ * Square-free decomposition of a monic polynomial by Yun's algorithm
+ */
+fn squarefree_decomposition(
+    extension: &AlgebraicExtension,
+    polynomial: &ExtensionPolynomial,
+) -> Vec<(ExtensionPolynomial, usize)> {
+    let quotient = |dividend: &ExtensionPolynomial, divisor: &ExtensionPolynomial| {
+        div_rem(extension, dividend, divisor).0
+    };
+    let common = gcd(extension, polynomial, &derivative(polynomial));
+    let mut b = quotient(polynomial, &common);
+    let mut d = sub(&quotient(&derivative(polynomial), &common), &derivative(&b));
+
+    let mut factors = vec![];
+    let mut multiplicity = 1;
+    while b.len() > 1 {
+        let factor = gcd(extension, &b, &d);
+        b = quotient(&b, &factor);
+        d = sub(&quotient(&d, &factor), &derivative(&b));
+        if factor.len() > 1 {
+            factors.push((factor, multiplicity));
+        }
+        multiplicity += 1;
+    }
+    factors
+}
+
+/**
+ * p(x + shift), by Horner's scheme
+ */
+fn translate(
+    extension: &AlgebraicExtension,
+    polynomial: &ExtensionPolynomial,
+    shift: &Polynomial,
+) -> ExtensionPolynomial {
+    let one = extension.constant(Symbol::rational(1, 1));
+    let linear = trimmed(vec![extension.element(shift), one]);
+    polynomial.iter().rev().fold(vec![], |result, coefficient| {
+        add(
+            &mul(extension, &result, &linear),
+            &trimmed(vec![coefficient.clone()]),
+        )
+    })
+}
@@ -0,0 +1,91 @@
+#[cfg(test)]
+mod extension {
+    use crate::base::{expression::Expression, symbol::Symbol};
+    use crate::polynomials::algebraic_extension::AlgebraicExtension;
+
+    fn extension(minimal: &str) -> Option<AlgebraicExtension> {
+        AlgebraicExtension::new(Symbol::variable("a"), &Expression::parse(minimal).unwrap())
+    }
+
+    #[test]
+    fn requires_irreducible_minimal_polynomial() {
+        assert!(extension("a^2 - 2").is_some());
+        assert!(extension("2*a^3 - 4").is_some());
+        assert!(extension("a^2 - 1").is_none());
+        assert!(extension("a^2 - 4*a + 4").is_none());
+        assert!(extension("3").is_none());
+    }
+
+    #[test]
+    fn inverts_elements() {
+        /* (1 + a)^-1 == a - 1 in Q(sqrt(2)) */
+        let q = extension("a^2 - 2").unwrap();
+        let a = Symbol::variable("a");
+        let element = Expression::parse("1 + a")
+            .unwrap()
+            .as_polynomial(&a)
+            .unwrap();
+
+        assert_eq!(
+            q.inverse(&element),
+            Expression::parse("a - 1").unwrap().as_polynomial(&a)
+        );
+    }
+}
+
+#[cfg(test)]
+mod factorisation {
+    use crate::base::{expression::Expression, symbol::Symbol};
+    use crate::polynomials::algebraic_extension::AlgebraicExtension;
+
+    fn factor(minimal: &str, input: &str, expected: &str) {
+        let extension =
+            AlgebraicExtension::new(Symbol::variable("a"), &Expression::parse(minimal).unwrap())
+                .unwrap();
+        let trial = Expression::parse(input)
+            .unwrap()
+            .factor_with(Some(&extension));
+        let expected = Expression::parse(expected).unwrap();
+
+        assert_eq!(trial, expected);
+    }
+
+    #[test]
+    fn splits_surds() {
+        factor("a^2 - 2", "x^2 - 2", "(x - a) * (x + a)");
+        factor("a^2 - 2", "3*x^2 - 6", "3 * (x - a) * (x + a)");
+        factor(
+            "a^2 - 2",
+            "x^4 - 10*x^2 + 1",
+            "(x^2 - 2*a*x - 1) * (x^2 + 2*a*x - 1)",
+        );
+    }
+
+    #[test]
+    fn splits_over_gaussian_rationals() {
+        factor("a^2 + 1", "x^4 + 1", "(x^2 - a) * (x^2 + a)");
+        factor("a^2 + 1", "x^2 + 4", "(x - 2*a) * (x + 2*a)");
+    }
+
+    #[test]
+    fn keeps_irreducibles() {
+        factor("a^2 - 2", "x^2 - 3", "x^2 - 3");
+        factor("a^3 - 2", "x^2 - 2", "x^2 - 2");
+    }
+
+    #[test]
+    fn coefficients_in_the_extension() {
+        factor("a^2 - 2", "x^2 - 2*a*x + 2", "(x - a)^2");
+        factor(
+            "a^2 - 2",
+            "(x^2 - 2)^2 * (x + 1)",
+            "(x + 1) * (x - a)^2 * (x + a)^2",
+        );
+    }
+
+    #[test]
+    fn leaves_other_expressions() {
+        factor("a^2 - 2", "x*y - 2", "x*y - 2");
+        factor("a^2 - 2", "sin(x) - a", "sin(x) - a");
+    }
+}
@@ -0,0 +1,146 @@
+use crate::base::{expression::Expression, symbol::Symbol};
+use crate::printing::layout::{ordered_addends, split_fraction, split_sign, Precedence};
+
+impl Expression {
+    /**
+     * Infix rendering accepted back by `Expression::parse`
+     *  - `-1 *` factors collapse to subtraction and negative powers to division
+     */
+    pub fn to_infix(&self) -> String {
+        infix(self).0
+    }
+}
+
+fn infix(expression: &Expression) -> (String, Precedence) {
+    match expression {
+        Expression::Integer(n) if n.is_negative() => (n.label(), Precedence::Negation),
+        Expression::Integer(n) => (n.label(), Precedence::Atom),
+        Expression::Real(r) if r.data < 0.0 => (real(r.data), Precedence::Negation),
+        Expression::Real(r) => (real(r.data), Precedence::Atom),
+        Expression::Variable(v) => (v.label(), Precedence::Atom),
+        Expression::Constant(c) => (c.label(), Precedence::Atom),
+
+        Expression::Addition(_) => (addition(expression), Precedence::Addition),
+        Expression::Multiplication(_) | Expression::Rational(_) => match split_sign(expression) {
+            (true, magnitude) => (
+                format!("-{}", wrapped(&magnitude, Precedence::Multiplication)),
+                Precedence::Negation,
+            ),
+            (false, magnitude) => product(&magnitude),
+        },
+
+        Expression::Power(power) => {
+            if power.modifier().split_sign().0 {
+                return product(expression);
+            }
+            if power.argument() == Symbol::e().expr() {
+                return function("exp", &power.modifier());
+            }
+            (
+                format!(
+                    "{}^{}",
+                    wrapped(&power.argument(), Precedence::Atom),
+                    wrapped(&power.modifier(), Precedence::Power)
+                ),
+                Precedence::Power,
+            )
+        }
+        Expression::Logarithm(log) if log.modifier() == Symbol::e().expr() => {
+            function("ln", &log.argument())
+        }
+        Expression::Logarithm(log) => (
+            format!(
+                "log({}, {})",
+                infix(&log.argument()).0,
+                infix(&log.modifier()).0
+            ),
+            Precedence::Atom,
+        ),
+
+        Expression::Sine(op) => function("sin", &op.argument()),
+        Expression::Cossine(op) => function("cos", &op.argument()),
+        Expression::Tangent(op) => function("tan", &op.argument()),
+        Expression::Cotangent(op) => function("cot", &op.argument()),
+        Expression::Secant(op) => function("sec", &op.argument()),
+        Expression::Cossecant(op) => function("csc", &op.argument()),
+        Expression::ArcSine(op) => function("asin", &op.argument()),
+        Expression::ArcCossine(op) => function("acos", &op.argument()),
+        Expression::ArcTangent(op) => function("atan", &op.argument()),
+        Expression::ArcCotangent(op) => function("acot", &op.argument()),
+        Expression::ArcSecant(op) => function("asec", &op.argument()),
+        Expression::ArcCossecant(op) => function("acsc", &op.argument()),
+        Expression::RealPart(op) => function("re", &op.argument()),
+        Expression::ImaginaryPart(op) => function("im", &op.argument()),
+        Expression::Conjugate(op) => function("conj", &op.argument()),
+        Expression::Absolute(op) => function("abs", &op.argument()),
+        Expression::Argument(op) => function("arg", &op.argument()),
+    }
+}
+
+/**
+ * Renders the expression, parenthesised when it binds weaker than `minimum`
+ */
+fn wrapped(expression: &Expression, minimum: Precedence) -> String {
+    let (rendered, precedence) = infix(expression);
+    if precedence < minimum {
+        format!("({})", rendered)
+    } else {
+        rendered
+    }
+}
+
+fn function(name: &str, argument: &Expression) -> (String, Precedence) {
+    (format!("{}({})", name, infix(argument).0), Precedence::Atom)
+}
+
+/**
+ * Reals always carry a decimal point, so they are not parsed back as integers
+ */
+fn real(value: f64) -> String {
+    let label = format!("{}", value);
+    if value.is_finite() && !label.contains('.') {
+        format!("{}.0", label)
+    } else {
+        label
+    }
+}
+
+fn addition(expression: &Expression) -> String {
+    let mut rendered = String::new();
+    for (index, addend) in ordered_addends(expression).iter().enumerate() {
+        let (negative, magnitude) = split_sign(addend);
+        let term = wrapped(&magnitude, Precedence::Multiplication);
+        match (index, negative) {
+            (0, false) => rendered.push_str(&term),
+            (0, true) => rendered.push_str(&format!("-{}", term)),
+            (_, false) => rendered.push_str(&format!(" + {}", term)),
+            (_, true) => rendered.push_str(&format!(" - {}", term)),
+        }
+    }
+    rendered
+}
+
+/**
+ * Renders a non-negative product, dividing by each factor with negative exponent in turn
+ */
+fn product(expression: &Expression) -> (String, Precedence) {
+    let (numerator, denominator) = split_fraction(expression);
+    let numerator = match numerator.as_slice() {
+        [] => String::from("1"),
+        [single] if denominator.is_empty() => return infix(single),
+        factors => multiplied(factors),
+    };
+    /* chained divisions, as `a / (b * c)` parses back as a power of the product */
+    let rendered = denominator.iter().fold(numerator, |rendered, factor| {
+        format!("{} / {}", rendered, wrapped(factor, Precedence::Negation))
+    });
+    (rendered, Precedence::Multiplication)
+}
+
+fn multiplied(factors: &[Expression]) -> String {
+    factors
+        .iter()
+        .map(|factor| wrapped(factor, Precedence::Multiplication))
+        .collect::<Vec<String>>()
+        .join(" * ")
+}
@@ -0,0 +1,129 @@
+#[cfg(test)]
+mod display {
+    use crate::base::{
+        commutative_association::CommutativeAssociation, expression::Expression, symbol::Symbol,
+    };
+
+    fn display(input: &str) -> String {
+        format!("{}", Expression::parse(input).unwrap())
+    }
+
+    #[test]
+    fn renders_infix() {
+        assert_eq!(display("x^2 - 3"), "x^2 - 3");
+        assert_eq!(display("2 * x * y"), "2 * x * y");
+        assert_eq!(display("(a + b) * (c + d)"), "(a + b) * (c + d)");
+        assert_eq!(display("sin(x)^2 + log(x, 2)"), "sin(x)^2 + log(x, 2)");
+    }
+
+    #[test]
+    fn collapses_negative_factors() {
+        assert_eq!(display("-x"), "-x");
+        assert_eq!(display("a - b"), "a - b");
+        assert_eq!(display("1 - 2 * x"), "-2 * x + 1");
+        assert_eq!(display("-x^2"), "-x^2");
+    }
+
+    #[test]
+    fn folds_unnormalised_negative_factors() {
+        let product = |factors: &[isize]| {
+            let mut items: Vec<Expression> = factors
+                .iter()
+                .map(|factor| Symbol::integer(*factor).expr())
+                .collect();
+            items.push(Symbol::variable("x").expr());
+            Expression::Multiplication(CommutativeAssociation::new(items))
+        };
+
+        assert_eq!(format!("{}", product(&[-1, -1])), "x");
+        assert_eq!(format!("{}", product(&[-1, -2])), "2 * x");
+        assert_eq!(format!("{}", product(&[-1, -1, -1])), "-x");
+        assert_eq!(
+            format!("{}", Symbol::variable("y").expr() + product(&[-1, 3])),
+            "y - 3 * x"
+        );
+    }
+
+    #[test]
+    fn renders_negative_powers_as_division() {
+        assert_eq!(display("1 / x"), "1 / x");
+        assert_eq!(display("3 / 2"), "3 / 2");
+        assert_eq!(display("x / (y + 1)"), "x / (y + 1)");
+        assert_eq!(display("(x^2 + 1) / (x * y)"), "(x^2 + 1) / (x * y)");
+        assert_eq!(display("a / (b / c)"), "a / (b / c)");
+        assert_eq!(display("a * b^-1 * c^-1"), "a / b / c");
+        assert_eq!(display("x^-1 * y^-2"), "1 / x / y^2");
+        assert_eq!(display("(2/3)^x"), "(2 / 3)^x");
+    }
+
+    #[test]
+    fn respects_power_associativity() {
+        assert_eq!(display("x^y^z"), "x^y^z");
+        assert_eq!(display("(x + 1)^2"), "(x + 1)^2");
+        assert_eq!(display("(-2)^x"), "(-2)^x");
+        assert_eq!(display("x^(a + b)"), "x^(a + b)");
+    }
+
+    #[test]
+    fn renders_natural_exponential_and_logarithm() {
+        assert_eq!(display("exp(x) + ln(x)"), "exp(x) + ln(x)");
+        assert_eq!(display("e^(2 * x)"), "exp(2 * x)");
+        assert_eq!(display("exp(-x)"), "1 / exp(x)");
+        assert_eq!(display("log(x, e)"), "ln(x)");
+        assert_eq!(
+            format!("{:#}", Expression::parse("exp(x) * ln(y)").unwrap()),
+            "(exp(x) * ln(y))"
+        );
+    }
+
+    #[test]
+    fn keeps_reals_distinct_from_integers() {
+        assert_eq!(format!("{}", Symbol::real(2.0).expr()), "2.0");
+        assert_eq!(format!("{}", Symbol::real(-1.5).expr()), "-1.5");
+    }
+
+    #[test]
+    fn alternate_form_prints_tree() {
+        let expression = Expression::parse("x^2 - 3").unwrap();
+        assert_eq!(format!("{:#}", expression), "(-3 + pow(x, 2))");
+        assert_eq!(
+            format!("{:#}", Expression::parse("1 / x").unwrap()),
+            "pow(x, -1)"
+        );
+        assert_eq!(
+            format!("{:#}", Expression::parse("1/3 + 1/6").unwrap()),
+            "1/2"
+        );
+    }
+
+    #[test]
+    fn round_trips_through_parser() {
+        let inputs = [
+            "x^2 - 3",
+            "-2 * x + 1",
+            "(x^2 + 1) / (x * y)",
+            "x^(-1/2)",
+            "a / (b / c)",
+            "a * b^-1 * c^-1",
+            "x^-1 * y^-1",
+            "-3 * x / (2 * y^2 * z)",
+            "2 * x^-1 * (y + 1)^-2 * sin(x)^-1",
+            "-(x + 1) * y",
+            "x^y^z",
+            "(-2)^x",
+            "1.5 * x + 2.0",
+            "sin(x^2) / cos(x) + log(y, x - 1)",
+            "tan(x) * csc(x) - acos(x)",
+            "atan(x / 2) + 2 * pi",
+            "exp(x^2) * ln(x + 1) - log(x, 2)",
+            "re(sin(i)) + im(sin(i)) * i - conj(sin(i)) / abs(z * w) + arg(z)",
+        ];
+        for input in inputs.iter() {
+            let expression = Expression::parse(input).unwrap();
+            assert_eq!(
+                Expression::parse(&format!("{}", expression)),
+                Ok(expression)
+            );
+        }
+    }
+}
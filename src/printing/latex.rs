@@ -0,0 +1,203 @@
+use crate::base::{expression::Expression, symbol::Symbol};
+use crate::printing::layout::{
+    ordered_addends, root_index, split_fraction, split_sign, Precedence,
+};
+
+const GREEK_LETTERS: [&str; 23] = [
+    "alpha", "beta", "gamma", "delta", "epsilon", "zeta", "eta", "theta", "iota", "kappa",
+    "lambda", "mu", "nu", "xi", "pi", "rho", "sigma", "tau", "upsilon", "phi", "chi", "psi",
+    "omega",
+];
+
+impl Expression {
+    /**
+     * LaTeX rendering with the minimal parentheses required by operator precedence
+     */
+    pub fn to_latex(&self) -> String {
+        latex(self).0
+    }
+}
+
+fn latex(expression: &Expression) -> (String, Precedence) {
+    match expression {
+        Expression::Integer(n) if n.is_negative() => (n.label(), Precedence::Negation),
+        Expression::Integer(n) => (n.label(), Precedence::Atom),
+        Expression::Real(r) if r.data < 0.0 => (r.label(), Precedence::Negation),
+        Expression::Real(r) => (r.label(), Precedence::Atom),
+        Expression::Variable(v) => (variable(&v.label()), Precedence::Atom),
+        Expression::Constant(c) => (variable(&c.label()), Precedence::Atom),
+
+        Expression::Addition(_) => (addition(expression), Precedence::Addition),
+        Expression::Multiplication(_) | Expression::Rational(_) => match split_sign(expression) {
+            (true, magnitude) => (
+                format!("-{}", wrapped(&magnitude, Precedence::Multiplication)),
+                Precedence::Negation,
+            ),
+            (false, magnitude) => product(&magnitude),
+        },
+
+        Expression::Power(power) => {
+            let base = power.argument();
+            let exponent = power.modifier();
+            if exponent.split_sign().0 {
+                return product(expression);
+            }
+            match root_index(&exponent) {
+                Some(index) if index.value() == Some(2) => {
+                    (format!("\\sqrt{{{}}}", latex(&base).0), Precedence::Atom)
+                }
+                Some(index) => (
+                    format!("\\sqrt[{}]{{{}}}", index.label(), latex(&base).0),
+                    Precedence::Atom,
+                ),
+                None => (
+                    format!(
+                        "{}^{{{}}}",
+                        wrapped(&base, Precedence::Atom),
+                        latex(&exponent).0
+                    ),
+                    Precedence::Power,
+                ),
+            }
+        }
+        Expression::Logarithm(log) if log.modifier() == Symbol::e().expr() => {
+            (function("\\ln", &log.argument()), Precedence::Atom)
+        }
+        Expression::Logarithm(log) => (
+            format!(
+                "\\log_{{{}}}\\left({}\\right)",
+                latex(&log.modifier()).0,
+                latex(&log.argument()).0
+            ),
+            Precedence::Atom,
+        ),
+
+        Expression::Sine(op) => (function("\\sin", &op.argument()), Precedence::Atom),
+        Expression::Cossine(op) => (function("\\cos", &op.argument()), Precedence::Atom),
+        Expression::Tangent(op) => (function("\\tan", &op.argument()), Precedence::Atom),
+        Expression::Cotangent(op) => (function("\\cot", &op.argument()), Precedence::Atom),
+        Expression::Secant(op) => (function("\\sec", &op.argument()), Precedence::Atom),
+        Expression::Cossecant(op) => (function("\\csc", &op.argument()), Precedence::Atom),
+        Expression::ArcSine(op) => (function("\\arcsin", &op.argument()), Precedence::Atom),
+        Expression::ArcCossine(op) => (function("\\arccos", &op.argument()), Precedence::Atom),
+        Expression::ArcTangent(op) => (function("\\arctan", &op.argument()), Precedence::Atom),
+        Expression::ArcCotangent(op) => (
+            function("\\operatorname{arccot}", &op.argument()),
+            Precedence::Atom,
+        ),
+        Expression::ArcSecant(op) => (
+            function("\\operatorname{arcsec}", &op.argument()),
+            Precedence::Atom,
+        ),
+        Expression::ArcCossecant(op) => (
+            function("\\operatorname{arccsc}", &op.argument()),
+            Precedence::Atom,
+        ),
+        Expression::RealPart(op) => (
+            function("\\operatorname{Re}", &op.argument()),
+            Precedence::Atom,
+        ),
+        Expression::ImaginaryPart(op) => (
+            function("\\operatorname{Im}", &op.argument()),
+            Precedence::Atom,
+        ),
+        Expression::Conjugate(op) => (
+            format!("\\overline{{{}}}", latex(&op.argument()).0),
+            Precedence::Atom,
+        ),
+        Expression::Absolute(op) => (
+            format!("\\left|{}\\right|", latex(&op.argument()).0),
+            Precedence::Atom,
+        ),
+        Expression::Argument(op) => (function("\\arg", &op.argument()), Precedence::Atom),
+    }
+}
+
+/**
+ * Renders the expression, parenthesised when it binds weaker than `minimum`
+ */
+fn wrapped(expression: &Expression, minimum: Precedence) -> String {
+    let (rendered, precedence) = latex(expression);
+    if precedence < minimum {
+        format!("\\left({}\\right)", rendered)
+    } else {
+        rendered
+    }
+}
+
+fn function(name: &str, argument: &Expression) -> String {
+    format!("{}\\left({}\\right)", name, latex(argument).0)
+}
+
+fn variable(label: &str) -> String {
+    if GREEK_LETTERS.contains(&label) {
+        return format!("\\{}", label);
+    }
+    match label.find('_') {
+        Some(index) if index > 0 => {
+            format!("{}_{{{}}}", variable(&label[..index]), &label[index + 1..])
+        }
+        _ if label.chars().count() == 1 => String::from(label),
+        _ => format!("\\mathrm{{{}}}", label),
+    }
+}
+
+fn addition(expression: &Expression) -> String {
+    let mut rendered = String::new();
+    for (index, addend) in ordered_addends(expression).iter().enumerate() {
+        let (negative, magnitude) = split_sign(addend);
+        let term = wrapped(&magnitude, Precedence::Multiplication);
+        match (index, negative) {
+            (0, false) => rendered.push_str(&term),
+            (0, true) => rendered.push_str(&format!("-{}", term)),
+            (_, false) => rendered.push_str(&format!(" + {}", term)),
+            (_, true) => rendered.push_str(&format!(" - {}", term)),
+        }
+    }
+    rendered
+}
+
+/**
+ * Renders a non-negative product, as a fraction when some factor has a negative exponent
+ */
+fn product(expression: &Expression) -> (String, Precedence) {
+    let (numerator, denominator) = split_fraction(expression);
+    if denominator.is_empty() {
+        return match numerator.as_slice() {
+            [single] => latex(single),
+            factors => (juxtaposed(factors), Precedence::Multiplication),
+        };
+    }
+
+    let numerator = match numerator.as_slice() {
+        [] => String::from("1"),
+        [single] => latex(single).0,
+        factors => juxtaposed(factors),
+    };
+    let denominator = match denominator.as_slice() {
+        [single] => latex(single).0,
+        factors => juxtaposed(factors),
+    };
+    (
+        format!("\\frac{{{}}}{{{}}}", numerator, denominator),
+        Precedence::Multiplication,
+    )
+}
+
+/**
+ * Implicit multiplication, with an explicit `\cdot` before factors starting with a digit
+ */
+fn juxtaposed(factors: &[Expression]) -> String {
+    let mut rendered = String::new();
+    for (index, factor) in factors.iter().enumerate() {
+        let term = wrapped(factor, Precedence::Multiplication);
+        if index > 0 {
+            match term.chars().next() {
+                Some(c) if c.is_ascii_digit() => rendered.push_str(" \\cdot "),
+                _ => rendered.push(' '),
+            }
+        }
+        rendered.push_str(&term);
+    }
+    rendered
+}
@@ -0,0 +1,105 @@
+#[cfg(test)]
+mod to_latex {
+    use crate::base::{
+        commutative_association::CommutativeAssociation, expression::Expression, symbol::Symbol,
+    };
+
+    fn latex(input: &str) -> String {
+        Expression::parse(input).unwrap().to_latex()
+    }
+
+    #[test]
+    fn renders_symbols() {
+        assert_eq!(Symbol::integer(-3).expr().to_latex(), "-3");
+        assert_eq!(Symbol::real(1.5).expr().to_latex(), "1.5");
+        assert_eq!(latex("x"), "x");
+        assert_eq!(latex("theta"), "\\theta");
+        assert_eq!(latex("x_1"), "x_{1}");
+        assert_eq!(latex("speed"), "\\mathrm{speed}");
+    }
+
+    #[test]
+    fn renders_powers_and_roots() {
+        assert_eq!(latex("x^2"), "x^{2}");
+        assert_eq!(latex("x^y^z"), "x^{y^{z}}");
+        assert_eq!(latex("x^(1/2)"), "\\sqrt{x}");
+        assert_eq!(latex("x^(1/3)"), "\\sqrt[3]{x}");
+    }
+
+    #[test]
+    fn renders_negative_powers_as_fractions() {
+        assert_eq!(latex("1/x"), "\\frac{1}{x}");
+        assert_eq!(latex("3/2"), "\\frac{3}{2}");
+        assert_eq!(latex("x^(-1/2)"), "\\frac{1}{\\sqrt{x}}");
+        assert_eq!(latex("(x^2 + 1) / (x * y)"), "\\frac{x^{2} + 1}{x y}");
+    }
+
+    #[test]
+    fn renders_functions() {
+        assert_eq!(latex("log(x, 2)"), "\\log_{2}\\left(x\\right)");
+        assert_eq!(
+            latex("sin(x)^2 + cos(x)"),
+            "\\sin\\left(x\\right)^{2} + \\cos\\left(x\\right)"
+        );
+    }
+
+    #[test]
+    fn uses_minimal_parentheses() {
+        assert_eq!(latex("2 * x * y"), "2 x y");
+        assert_eq!(latex("2^x * 3"), "3 \\cdot 2^{x}");
+        assert_eq!(latex("(x + 1)^2"), "\\left(x + 1\\right)^{2}");
+        assert_eq!(latex("(x * y)^2"), "\\left(x y\\right)^{2}");
+        assert_eq!(latex("(-2)^x"), "\\left(-2\\right)^{x}");
+        assert_eq!(latex("(2/3)^x"), "\\left(\\frac{2}{3}\\right)^{x}");
+        assert_eq!(
+            latex("(a + b) * (c + d)"),
+            "\\left(a + b\\right) \\left(c + d\\right)"
+        );
+    }
+
+    #[test]
+    fn detects_leading_minus() {
+        assert_eq!(latex("-x"), "-x");
+        assert_eq!(latex("a - b"), "a - b");
+        assert_eq!(latex("x^2 - 3"), "x^{2} - 3");
+        assert_eq!(latex("1 - 2 * x"), "-2 x + 1");
+        assert_eq!(latex("-(x + 1) * y"), "-y \\left(x + 1\\right)");
+    }
+
+    #[test]
+    fn folds_unnormalised_negative_factors() {
+        let product = Expression::Multiplication(CommutativeAssociation::new(vec![
+            Symbol::integer(-1).expr(),
+            Symbol::integer(-1).expr(),
+            Symbol::variable("x").expr(),
+        ]));
+
+        assert_eq!(product.to_latex(), "x");
+        assert_eq!((-product).to_latex(), "-x");
+    }
+
+    #[test]
+    fn renders_trigonometrics() {
+        assert_eq!(
+            latex("tan(x) + sec(x)"),
+            "\\tan\\left(x\\right) + \\sec\\left(x\\right)"
+        );
+        assert_eq!(latex("asin(x)"), "\\arcsin\\left(x\\right)");
+        assert_eq!(latex("acot(x)"), "\\operatorname{arccot}\\left(x\\right)");
+        assert_eq!(latex("2 * pi"), "2 \\pi");
+        assert_eq!(latex("ln(x)"), "\\ln\\left(x\\right)");
+        assert_eq!(latex("exp(x)"), "e^{x}");
+    }
+
+    #[test]
+    fn renders_complex_functions() {
+        assert_eq!(latex("2 + 3 * i"), "3 i + 2");
+        assert_eq!(latex("abs(x)"), "\\left|x\\right|");
+        assert_eq!(latex("arg(x)"), "\\arg\\left(x\\right)");
+        assert_eq!(latex("conj(sin(i))"), "\\overline{\\sin\\left(i\\right)}");
+        assert_eq!(
+            latex("re(sin(i))"),
+            "\\operatorname{Re}\\left(\\sin\\left(i\\right)\\right)"
+        );
+    }
+}
@@ -0,0 +1,121 @@
+use crate::base::{
+    commutative_association::CommutativeAssociation, expression::Expression, symbol::Symbol,
+};
+
+/**
+ * Binding strength of rendered nodes: a child binding weaker than its parent needs parentheses
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Precedence {
+    Addition,
+    Multiplication,
+    Negation,
+    Power,
+    Atom,
+}
+
+/**
+ * Exponent of a factor that belongs to the denominator, as the positive exponent
+ */
+fn inverted_exponent(exponent: &Expression) -> Option<Expression> {
+    match exponent.split_sign() {
+        (true, magnitude) => Some(magnitude),
+        (false, _) => None,
+    }
+}
+
+/**
+ * Splits a product into numerator and denominator factors
+ *  - powers with negative exponents go to the denominator with the exponent sign flipped
+ *  - rationals are split into integer numerator and denominator
+ */
+pub fn split_fraction(expression: &Expression) -> (Vec<Expression>, Vec<Expression>) {
+    let factors = match expression {
+        Expression::Multiplication(factors) => factors.items(),
+        _ => vec![expression.clone()],
+    };
+
+    let mut numerator: Vec<Expression> = Vec::new();
+    let mut denominator: Vec<Expression> = Vec::new();
+    for factor in factors.iter() {
+        match factor {
+            Expression::Power(power) => match inverted_exponent(&power.modifier()) {
+                Some(exponent) => denominator.push(Expression::power(power.argument(), exponent)),
+                None => numerator.push(factor.clone()),
+            },
+            Expression::Rational(q) => {
+                if q.numerator() != Symbol::integer(1) {
+                    numerator.push(q.numerator().expr());
+                }
+                denominator.push(q.denominator().expr());
+            }
+            _ => numerator.push(factor.clone()),
+        }
+    }
+
+    (numerator, denominator)
+}
+
+/**
+ * Sign and magnitude of a term, folding every negative number factor of a product
+ *  - unnormalised products keep their sign right: `-1 * -1 * x` is the positive `x`
+ */
+pub fn split_sign(expression: &Expression) -> (bool, Expression) {
+    let factors = match expression {
+        Expression::Multiplication(factors) => factors.items(),
+        _ => return expression.split_sign(),
+    };
+
+    let mut negative = false;
+    let mut magnitudes: Vec<Expression> = Vec::new();
+    for factor in factors {
+        match factor {
+            Expression::Integer(_) | Expression::Rational(_) | Expression::Real(_) => {
+                let (factor_negative, magnitude) = factor.split_sign();
+                negative ^= factor_negative;
+                if magnitude != Symbol::integer(1).expr() {
+                    magnitudes.push(magnitude);
+                }
+            }
+            _ => magnitudes.push(factor),
+        }
+    }
+
+    let magnitude = match magnitudes.len() {
+        0 => Symbol::integer(1).expr(),
+        1 => magnitudes.pop().unwrap(),
+        _ => Expression::Multiplication(CommutativeAssociation::new(magnitudes)),
+    };
+    (negative, magnitude)
+}
+
+/**
+ * Addends in reading order: symbolic terms first, numeric terms last
+ */
+pub fn ordered_addends(expression: &Expression) -> Vec<Expression> {
+    let addends = match expression {
+        Expression::Addition(addends) => addends.items(),
+        _ => vec![expression.clone()],
+    };
+
+    let is_numeric = |addend: &Expression| {
+        matches!(
+            addend.split_sign().1,
+            Expression::Integer(_) | Expression::Rational(_) | Expression::Real(_)
+        )
+    };
+
+    let mut ordered: Vec<Expression> = addends.iter().filter(|a| !is_numeric(a)).cloned().collect();
+    ordered.extend(addends.iter().filter(|a| is_numeric(a)).cloned());
+    ordered
+}
+
+/**
+ * Root index when the exponent is the reciprocal of an integer (1/2 -> 2)
+ */
+pub fn root_index(exponent: &Expression) -> Option<Symbol<num::BigInt>> {
+    match exponent {
+        Expression::Rational(q) if q.numerator() == Symbol::integer(1) => Some(q.denominator()),
+        _ => None,
+    }
+}
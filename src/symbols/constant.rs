@@ -0,0 +1,105 @@
+use crate::base::{expression::Expression, symbol::Symbol};
+
+/**
+ * Mathematical constants kept exact through manipulation
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Constant {
+    /* ratio of circumference to diameter */
+    Pi,
+    /* Euler's number, base of the natural logarithm */
+    E,
+    /* imaginary unit, i^2 = -1 */
+    I,
+    /* golden ratio, (1 + sqrt(5)) / 2 */
+    GoldenRatio,
+    /* Euler-Mascheroni constant */
+    EulerGamma,
+}
+
+impl Symbol<Constant> {
+    /**
+     * Constructor for constant as Symbol<Constant>
+     */
+    pub fn constant(constant: Constant) -> Self {
+        Self { data: constant }
+    }
+    pub fn pi() -> Self {
+        Self::constant(Constant::Pi)
+    }
+    pub fn e() -> Self {
+        Self::constant(Constant::E)
+    }
+    pub fn i() -> Self {
+        Self::constant(Constant::I)
+    }
+    pub fn golden_ratio() -> Self {
+        Self::constant(Constant::GoldenRatio)
+    }
+    pub fn euler_gamma() -> Self {
+        Self::constant(Constant::EulerGamma)
+    }
+    /**
+     * Numeric approximation, `None` for the non real imaginary unit
+     */
+    pub fn value(&self) -> Option<f64> {
+        match self.data {
+            Constant::Pi => Some(std::f64::consts::PI),
+            Constant::E => Some(std::f64::consts::E),
+            Constant::I => None,
+            Constant::GoldenRatio => Some((1.0 + 5f64.sqrt()) / 2.0),
+            Constant::EulerGamma => Some(0.577_215_664_901_532_9),
+        }
+    }
+    pub fn label(&self) -> String {
+        match self.data {
+            Constant::Pi => String::from("pi"),
+            Constant::E => String::from("e"),
+            Constant::I => String::from("i"),
+            Constant::GoldenRatio => String::from("phi"),
+            Constant::EulerGamma => String::from("gamma"),
+        }
+    }
+    /**
+     * Constant named by its label, as written in infix expressions
+     */
+    pub fn from_label(label: &str) -> Option<Self> {
+        match label {
+            "pi" => Some(Self::pi()),
+            "e" => Some(Self::e()),
+            "i" => Some(Self::i()),
+            "phi" => Some(Self::golden_ratio()),
+            "gamma" => Some(Self::euler_gamma()),
+            _ => None,
+        }
+    }
+    pub fn expr(self) -> Expression {
+        Expression::Constant(self)
+    }
+}
+
+impl std::hash::Hash for Symbol<Constant> {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.data.hash(state);
+    }
+}
+
+// ================= //
+//      Equality     //
+// ================= //
+impl Eq for Symbol<Constant> {}
+impl PartialEq for Symbol<Constant> {
+    fn eq(&self, other: &Self) -> bool {
+        self.data == other.data
+    }
+}
+impl Ord for Symbol<Constant> {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.data.cmp(&other.data)
+    }
+}
+impl PartialOrd for Symbol<Constant> {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
@@ -0,0 +1,110 @@
+#[cfg(test)]
+mod api {
+    use crate::base::symbol::*;
+    use crate::symbols::constant::Constant;
+
+    #[test]
+    fn displays_label() {
+        let foo: Symbol<Constant> = Symbol::pi();
+        assert_eq!(foo.label(), String::from("pi"));
+    }
+
+    #[test]
+    fn returns_f64() {
+        let foo: Symbol<Constant> = Symbol::pi();
+        assert_eq!(foo.value(), Some(std::f64::consts::PI));
+        assert_eq!(Symbol::e().value(), Some(std::f64::consts::E));
+        assert_eq!(Symbol::i().value(), None);
+        assert!((Symbol::golden_ratio().value().unwrap() - 1.618_033_988_749_895).abs() < 1e-15);
+        assert!((Symbol::euler_gamma().value().unwrap() - 0.577_215_664_901_532_9).abs() < 1e-15);
+    }
+
+    #[test]
+    fn parses_label() {
+        for constant in [
+            Symbol::pi(),
+            Symbol::e(),
+            Symbol::i(),
+            Symbol::golden_ratio(),
+            Symbol::euler_gamma(),
+        ]
+        .iter()
+        {
+            assert_eq!(
+                Symbol::from_label(&constant.label()),
+                Some(constant.clone())
+            );
+        }
+        assert_eq!(Symbol::from_label("x"), None);
+    }
+
+    #[test]
+    fn hashable() {
+        use std::collections::HashSet;
+        let mut set: HashSet<Symbol<Constant>> = HashSet::new();
+
+        set.insert(Symbol::pi());
+        set.insert(Symbol::constant(Constant::Pi));
+
+        assert!(set.contains(&Symbol::pi()));
+        assert_eq!(set.len(), 1);
+    }
+
+    #[test]
+    fn orders_among_expressions() {
+        let pi = Symbol::pi().expr();
+        let e = Symbol::e().expr();
+        assert!(pi < e);
+        assert!(Symbol::rational(1, 2).expr() < pi);
+        assert!(e < Symbol::variable("a").expr());
+    }
+}
+
+#[cfg(test)]
+mod manipulation {
+    use crate::base::{expression::Expression, symbol::Symbol};
+
+    #[test]
+    fn stays_symbolic() {
+        let e = Symbol::e().expr();
+        let pi = Symbol::pi().expr();
+        let trial = (pi.clone() + e.clone()) * Symbol::integer(2).expr();
+
+        assert_eq!(
+            trial.clone().expand(),
+            Symbol::integer(2).expr() * pi.clone() + Symbol::integer(2).expr() * e.clone()
+        );
+        assert_eq!(
+            (pi.clone() + pi.clone()).simplify(),
+            Symbol::integer(2).expr() * pi
+        );
+    }
+
+    #[test]
+    fn squares_imaginary_unit() {
+        let i = Symbol::i().expr();
+        let x = Symbol::variable("x").expr();
+        assert_eq!(
+            (i.clone() * i.clone()).simplify(),
+            Symbol::integer(-1).expr()
+        );
+        assert_eq!((i.clone() * x.clone() * i).simplify(), -x);
+    }
+
+    #[test]
+    fn evaluates_on_demand() {
+        use crate::manipulation::numeric_evaluation::EvalError;
+        use std::collections::HashMap;
+
+        let env: HashMap<String, f64> = HashMap::new();
+        let trial = Expression::parse("2 * pi + e").unwrap();
+        assert_eq!(
+            trial.evaluate(&env),
+            Ok(2.0 * std::f64::consts::PI + std::f64::consts::E)
+        );
+        assert_eq!(
+            Expression::parse("1 + i").unwrap().evaluate(&env),
+            Err(EvalError::NonRealConstant(String::from("i")))
+        );
+    }
+}
@@ -238,6 +238,14 @@ impl Symbol<BigInt> {
         }
     }
 
+    pub fn abs(&self) -> Self {
+        if self.is_negative() {
+            self.opposite()
+        } else {
+            self.clone()
+        }
+    }
+
     pub fn pow(&self, exponent: &Self) -> Option<Self> {
         if !exponent.is_negative() {
             let base_value = self.data.clone();
@@ -0,0 +1,292 @@
+use crate::base::{expression::Expression, symbol::Symbol};
+use num::bigint::BigInt;
+use num::rational::BigRational;
+use num::traits::{One, Signed, ToPrimitive, Zero};
+
+impl Symbol<BigRational> {
+    /**
+     * Constructor for rational as Symbol<BigRational>
+     *  - reduced to lowest terms with positive denominator
+     *  - panics on a zero denominator, as `BigRational::new`
+     */
+    pub fn rational(numerator: isize, denominator: isize) -> Self {
+        Self::fraction(Symbol::integer(numerator), Symbol::integer(denominator))
+    }
+    pub fn fraction(numerator: Symbol<BigInt>, denominator: Symbol<BigInt>) -> Self {
+        Self {
+            data: BigRational::new(numerator.data, denominator.data),
+        }
+    }
+    pub fn value(&self) -> Option<f64> {
+        self.data.to_f64()
+    }
+    pub fn label(&self) -> String {
+        format!("{}", self.data)
+    }
+    /**
+     * Expression leaf, as an integer when the denominator is one
+     */
+    pub fn expr(self) -> Expression {
+        if self.is_integer() {
+            Expression::Integer(self.numerator())
+        } else {
+            Expression::Rational(self)
+        }
+    }
+}
+
+impl std::hash::Hash for Symbol<BigRational> {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.data.hash(state);
+    }
+}
+
+impl From<Symbol<BigInt>> for Symbol<BigRational> {
+    fn from(integer: Symbol<BigInt>) -> Self {
+        Self {
+            data: BigRational::from_integer(integer.data),
+        }
+    }
+}
+
+// =================== //
+//      Comparison     //
+// =================== //
+impl Eq for Symbol<BigRational> {}
+impl PartialEq for Symbol<BigRational> {
+    fn eq(&self, other: &Self) -> bool {
+        self.data == other.data
+    }
+}
+impl Ord for Symbol<BigRational> {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.data.cmp(&other.data)
+    }
+}
+impl PartialOrd for Symbol<BigRational> {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+// ================= //
+//      Addition     //
+// ================= //
+impl std::ops::Add for Symbol<BigRational> {
+    type Output = Symbol<BigRational>;
+    fn add(self, other: Symbol<BigRational>) -> Symbol<BigRational> {
+        Self {
+            data: self.data + other.data,
+        }
+    }
+}
+
+impl std::ops::Add<&Symbol<BigRational>> for Symbol<BigRational> {
+    type Output = Symbol<BigRational>;
+    fn add(self, other: &Symbol<BigRational>) -> Symbol<BigRational> {
+        self + other.clone()
+    }
+}
+
+impl std::ops::Add for &Symbol<BigRational> {
+    type Output = Symbol<BigRational>;
+    fn add(self, other: &Symbol<BigRational>) -> Symbol<BigRational> {
+        self.clone() + other.clone()
+    }
+}
+
+impl std::ops::Add<Symbol<BigRational>> for &Symbol<BigRational> {
+    type Output = Symbol<BigRational>;
+    fn add(self, other: Symbol<BigRational>) -> Symbol<BigRational> {
+        self.clone() + other
+    }
+}
+
+// ================= //
+//      Subtract     //
+// ================= //
+impl std::ops::Sub for Symbol<BigRational> {
+    type Output = Symbol<BigRational>;
+    fn sub(self, other: Symbol<BigRational>) -> Symbol<BigRational> {
+        Self {
+            data: self.data - other.data,
+        }
+    }
+}
+
+impl std::ops::Sub<&Symbol<BigRational>> for Symbol<BigRational> {
+    type Output = Symbol<BigRational>;
+    fn sub(self, other: &Symbol<BigRational>) -> Symbol<BigRational> {
+        self - other.clone()
+    }
+}
+
+impl std::ops::Sub for &Symbol<BigRational> {
+    type Output = Symbol<BigRational>;
+    fn sub(self, other: &Symbol<BigRational>) -> Symbol<BigRational> {
+        self.clone() - other.clone()
+    }
+}
+
+impl std::ops::Sub<Symbol<BigRational>> for &Symbol<BigRational> {
+    type Output = Symbol<BigRational>;
+    fn sub(self, other: Symbol<BigRational>) -> Symbol<BigRational> {
+        self.clone() - other
+    }
+}
+
+// ======================= //
+//      Multiplication     //
+// ======================= //
+impl std::ops::Mul for Symbol<BigRational> {
+    type Output = Symbol<BigRational>;
+    fn mul(self, other: Symbol<BigRational>) -> Symbol<BigRational> {
+        Self {
+            data: self.data * other.data,
+        }
+    }
+}
+
+impl std::ops::Mul<&Symbol<BigRational>> for Symbol<BigRational> {
+    type Output = Symbol<BigRational>;
+    fn mul(self, other: &Symbol<BigRational>) -> Symbol<BigRational> {
+        self * other.clone()
+    }
+}
+
+impl std::ops::Mul for &Symbol<BigRational> {
+    type Output = Symbol<BigRational>;
+    fn mul(self, other: &Symbol<BigRational>) -> Symbol<BigRational> {
+        self.clone() * other.clone()
+    }
+}
+
+impl std::ops::Mul<Symbol<BigRational>> for &Symbol<BigRational> {
+    type Output = Symbol<BigRational>;
+    fn mul(self, other: Symbol<BigRational>) -> Symbol<BigRational> {
+        self.clone() * other
+    }
+}
+
+// ================= //
+//      Division     //
+// ================= //
+impl std::ops::Div for Symbol<BigRational> {
+    type Output = Symbol<BigRational>;
+    fn div(self, other: Symbol<BigRational>) -> Symbol<BigRational> {
+        Self {
+            data: self.data / other.data,
+        }
+    }
+}
+
+impl std::ops::Div<&Symbol<BigRational>> for Symbol<BigRational> {
+    type Output = Symbol<BigRational>;
+    fn div(self, other: &Symbol<BigRational>) -> Symbol<BigRational> {
+        self / other.clone()
+    }
+}
+
+impl std::ops::Div for &Symbol<BigRational> {
+    type Output = Symbol<BigRational>;
+    fn div(self, other: &Symbol<BigRational>) -> Symbol<BigRational> {
+        self.clone() / other.clone()
+    }
+}
+
+impl std::ops::Div<Symbol<BigRational>> for &Symbol<BigRational> {
+    type Output = Symbol<BigRational>;
+    fn div(self, other: Symbol<BigRational>) -> Symbol<BigRational> {
+        self.clone() / other
+    }
+}
+
+// ================== //
+//      Utilities     //
+// ================== //
+impl Symbol<BigRational> {
+    pub fn numerator(&self) -> Symbol<BigInt> {
+        Symbol {
+            data: self.data.numer().clone(),
+        }
+    }
+
+    pub fn denominator(&self) -> Symbol<BigInt> {
+        Symbol {
+            data: self.data.denom().clone(),
+        }
+    }
+
+    pub fn is_integer(&self) -> bool {
+        self.data.is_integer()
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.data.is_zero()
+    }
+
+    pub fn is_one(&self) -> bool {
+        self.data.is_one()
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.data.is_negative()
+    }
+
+    pub fn opposite(&self) -> Self {
+        Self {
+            data: -self.data.clone(),
+        }
+    }
+
+    pub fn abs(&self) -> Self {
+        Self {
+            data: self.data.abs(),
+        }
+    }
+
+    /**
+     * Multiplicative inverse, if not zero
+     */
+    pub fn inverse(&self) -> Option<Self> {
+        if self.is_zero() {
+            return None;
+        }
+        Some(Self {
+            data: self.data.recip(),
+        })
+    }
+
+    /**
+     * Integer power, negative exponents invert the base
+     */
+    pub fn pow(&self, exponent: &Symbol<BigInt>) -> Option<Self> {
+        let numerator = self.numerator().pow(&exponent.abs())?;
+        let denominator = self.denominator().pow(&exponent.abs())?;
+        if exponent.is_negative() {
+            if numerator == Symbol::integer(0) {
+                return None;
+            }
+            return Some(Self::fraction(denominator, numerator));
+        }
+        Some(Self::fraction(numerator, denominator))
+    }
+
+    /**
+     * Exact n-th root, if numerator and denominator are perfect powers
+     */
+    pub fn root(&self, index: &Symbol<BigInt>) -> Option<Self> {
+        let index = index.value()?.to_u32()?;
+        if index == 0 || (self.is_negative() && index % 2 == 0) {
+            return None;
+        }
+        let numerator = num::integer::Roots::nth_root(self.data.numer(), index);
+        let denominator = num::integer::Roots::nth_root(self.data.denom(), index);
+        let root = BigRational::new(numerator, denominator);
+        if num::traits::Pow::pow(&root, index) == self.data {
+            Some(Self { data: root })
+        } else {
+            None
+        }
+    }
+}
@@ -0,0 +1,124 @@
+#[cfg(test)]
+mod base {
+    use crate::base::symbol::*;
+    use num::rational::BigRational;
+
+    #[test]
+    fn displays_label() {
+        let foo: Symbol<BigRational> = Symbol::rational(2, 4);
+        assert_eq!(foo.label(), String::from("1/2"));
+    }
+
+    #[test]
+    fn returns_f64() {
+        let foo: Symbol<BigRational> = Symbol::rational(1, 4);
+        assert_eq!(foo.value(), Some(0.25));
+    }
+
+    #[test]
+    fn normalizes_sign_and_gcd() {
+        assert_eq!(Symbol::rational(6, 9), Symbol::rational(2, 3));
+        assert_eq!(Symbol::rational(1, -2), Symbol::rational(-1, 2));
+        assert_eq!(Symbol::rational(1, -2).numerator(), Symbol::integer(-1));
+        assert_eq!(Symbol::rational(1, -2).denominator(), Symbol::integer(2));
+    }
+
+    #[test]
+    fn integral_rational_is_integer_expression() {
+        assert_eq!(Symbol::rational(4, 2).expr(), Symbol::integer(2).expr());
+        assert_eq!(Symbol::rational(-1, 1).expr(), Symbol::integer(-1).expr());
+    }
+
+    #[test]
+    fn hashable() {
+        use std::collections::HashSet;
+        let mut set: HashSet<Symbol<BigRational>> = HashSet::new();
+        set.insert(Symbol::rational(1, 2));
+        set.insert(Symbol::rational(2, 4));
+        set.insert(Symbol::rational(1, 3));
+
+        assert!(set.contains(&Symbol::rational(1, 2)));
+        assert!(set.contains(&Symbol::rational(1, 3)));
+        assert_eq!(set.len(), 2);
+    }
+}
+
+#[cfg(test)]
+mod evaluable {
+    use crate::base::symbol::*;
+
+    #[test]
+    fn addition() {
+        assert_eq!(
+            Symbol::rational(2, 3) + Symbol::rational(5, 7),
+            Symbol::rational(29, 21)
+        );
+        assert_eq!(
+            Symbol::rational(1, 2) + &Symbol::rational(1, 3) + Symbol::rational(1, 6),
+            Symbol::rational(1, 1)
+        );
+    }
+
+    #[test]
+    fn subtraction() {
+        assert_eq!(
+            Symbol::rational(2, 3) - Symbol::rational(5, 7),
+            Symbol::rational(-1, 21)
+        );
+        assert_eq!(
+            &Symbol::rational(1, 6) + &Symbol::rational(1, 3) - Symbol::rational(1, 2),
+            Symbol::rational(0, 1)
+        );
+    }
+
+    #[test]
+    fn multiplication() {
+        assert_eq!(
+            Symbol::rational(2, 3) * Symbol::rational(5, 7),
+            Symbol::rational(10, 21)
+        );
+        assert_eq!(
+            &Symbol::rational(2, 3) * &Symbol::rational(6, 7),
+            Symbol::rational(4, 7)
+        );
+    }
+
+    #[test]
+    fn division() {
+        assert_eq!(
+            Symbol::rational(2, 3) / Symbol::rational(5, 7),
+            Symbol::rational(14, 15)
+        );
+        assert_eq!(
+            Symbol::rational(2, 3) / &Symbol::rational(6, 7),
+            Symbol::rational(7, 9)
+        );
+    }
+
+    #[test]
+    fn power() {
+        assert_eq!(
+            Symbol::rational(2, 3).pow(&Symbol::integer(3)),
+            Some(Symbol::rational(8, 27))
+        );
+        assert_eq!(
+            Symbol::rational(2, 3).pow(&Symbol::integer(-2)),
+            Some(Symbol::rational(9, 4))
+        );
+        assert_eq!(Symbol::rational(0, 1).pow(&Symbol::integer(-1)), None);
+    }
+
+    #[test]
+    fn root() {
+        assert_eq!(
+            Symbol::rational(4, 9).root(&Symbol::integer(2)),
+            Some(Symbol::rational(2, 3))
+        );
+        assert_eq!(
+            Symbol::rational(-8, 27).root(&Symbol::integer(3)),
+            Some(Symbol::rational(-2, 3))
+        );
+        assert_eq!(Symbol::rational(2, 1).root(&Symbol::integer(2)), None);
+        assert_eq!(Symbol::rational(-4, 1).root(&Symbol::integer(2)), None);
+    }
+}
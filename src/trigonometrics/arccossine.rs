@@ -0,0 +1,8 @@
+use crate::base::expression::Expression;
+use crate::trigonometrics::trigonometric::Trigonometric;
+
+impl Expression {
+    pub fn acos(value: Expression) -> Expression {
+        Trigonometric::Cossine.build_inverse(value)
+    }
+}
@@ -0,0 +1,8 @@
+use crate::base::expression::Expression;
+use crate::trigonometrics::trigonometric::Trigonometric;
+
+impl Expression {
+    pub fn acot(value: Expression) -> Expression {
+        Trigonometric::Cotangent.build_inverse(value)
+    }
+}
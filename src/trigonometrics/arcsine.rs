@@ -0,0 +1,8 @@
+use crate::base::expression::Expression;
+use crate::trigonometrics::trigonometric::Trigonometric;
+
+impl Expression {
+    pub fn asin(value: Expression) -> Expression {
+        Trigonometric::Sine.build_inverse(value)
+    }
+}
@@ -0,0 +1,8 @@
+use crate::base::expression::Expression;
+use crate::trigonometrics::trigonometric::Trigonometric;
+
+impl Expression {
+    pub fn csc(angle: Expression) -> Expression {
+        Trigonometric::Cossecant.build(angle)
+    }
+}
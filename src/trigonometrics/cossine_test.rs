@@ -0,0 +1,39 @@
+#[cfg(test)]
+mod constructor {
+    use crate::base::symbol::Symbol;
+    use crate::parsing::parser::parse;
+
+    #[test]
+    fn evaluates_reference_angles() {
+        assert_eq!(parse("cos(0)"), Symbol::integer(1).expr());
+        assert_eq!(parse("cos(pi/6)"), parse("3^(1/2) / 2"));
+        assert_eq!(parse("cos(pi/4)"), parse("2^(1/2) / 2"));
+        assert_eq!(parse("cos(pi/3)"), Symbol::rational(1, 2).expr());
+        assert_eq!(parse("cos(pi/2)"), Symbol::integer(0).expr());
+    }
+
+    #[test]
+    fn evaluates_other_quadrants() {
+        assert_eq!(parse("cos(pi)"), Symbol::integer(-1).expr());
+        assert_eq!(parse("cos(2*pi/3)"), Symbol::rational(-1, 2).expr());
+        assert_eq!(parse("cos(5*pi/4)"), parse("-2^(1/2) / 2"));
+        assert_eq!(parse("cos(5*pi/3)"), Symbol::rational(1, 2).expr());
+    }
+
+    #[test]
+    fn applies_parity() {
+        assert_eq!(parse("cos(-x)"), parse("cos(x)"));
+        assert_eq!(parse("cos(-2*pi/3)"), Symbol::rational(-1, 2).expr());
+    }
+
+    #[test]
+    fn reduces_period() {
+        assert_eq!(parse("cos(x - 4*pi)"), parse("cos(x)"));
+        assert_eq!(parse("cos(x + pi)"), -parse("cos(x)"));
+        assert_eq!(parse("cos(x + pi/2)"), -parse("sin(x)"));
+        assert_eq!(parse("cos(x + 3*pi/2)"), parse("sin(x)"));
+        assert_eq!(parse("cos(pi + x)"), -parse("cos(x)"));
+        assert_eq!(parse("cos(pi - x)"), -parse("cos(x)"));
+        assert_eq!(parse("cos(pi/2 - x)"), parse("sin(x)"));
+    }
+}
@@ -0,0 +1,45 @@
+#[cfg(test)]
+mod constructor {
+    use crate::base::{expression::Expression, operation::Operation, symbol::Symbol};
+    use crate::parsing::parser::parse;
+
+    #[test]
+    fn evaluates_reference_values() {
+        assert_eq!(parse("asin(1/2)"), parse("pi/6"));
+        assert_eq!(parse("acos(0)"), parse("pi/2"));
+        assert_eq!(parse("atan(1)"), parse("pi/4"));
+        assert_eq!(parse("acot(3^(1/2))"), parse("pi/6"));
+        assert_eq!(parse("asec(2)"), parse("pi/3"));
+        assert_eq!(parse("acsc(2^(1/2))"), parse("pi/4"));
+        assert_eq!(parse("asin(0)"), Symbol::integer(0).expr());
+    }
+
+    #[test]
+    fn evaluates_negative_values_in_principal_range() {
+        assert_eq!(parse("asin(-1/2)"), parse("-pi/6"));
+        assert_eq!(parse("atan(-1)"), parse("-pi/4"));
+        assert_eq!(parse("acos(-1/2)"), parse("2*pi/3"));
+        assert_eq!(parse("acot(-1)"), parse("3*pi/4"));
+        assert_eq!(parse("asec(-1)"), parse("pi"));
+        assert_eq!(parse("acsc(-2)"), parse("-pi/6"));
+    }
+
+    #[test]
+    fn applies_symmetries_to_symbolic_values() {
+        let x = Symbol::variable("x").expr();
+        assert_eq!(
+            parse("asin(x)"),
+            Expression::ArcSine(Operation::new(x.clone()))
+        );
+        assert_eq!(parse("asin(-x)"), -parse("asin(x)"));
+        assert_eq!(parse("atan(-x)"), -parse("atan(x)"));
+        assert_eq!(parse("acos(-x)"), parse("pi - acos(x)"));
+    }
+
+    #[test]
+    fn inverts_exact_values() {
+        assert_eq!(parse("asin(sin(pi/3))"), parse("pi/3"));
+        assert_eq!(parse("acos(cos(3*pi/4))"), parse("3*pi/4"));
+        assert_eq!(parse("atan(tan(-pi/6))"), parse("-pi/6"));
+    }
+}
@@ -0,0 +1,51 @@
+#[cfg(test)]
+mod secant {
+    use crate::base::{expression::Expression, operation::Operation, symbol::Symbol};
+    use crate::parsing::parser::parse;
+
+    #[test]
+    fn evaluates_reference_angles() {
+        assert_eq!(parse("sec(0)"), Symbol::integer(1).expr());
+        assert_eq!(parse("sec(pi/6)"), parse("2 * 3^(1/2) / 3"));
+        assert_eq!(parse("sec(pi/4)"), parse("2^(1/2)"));
+        assert_eq!(parse("sec(pi/3)"), Symbol::integer(2).expr());
+        assert_eq!(parse("sec(pi)"), Symbol::integer(-1).expr());
+        assert_eq!(
+            parse("sec(pi/2)"),
+            Expression::Secant(Operation::new(parse("pi/2")))
+        );
+    }
+
+    #[test]
+    fn applies_parity_and_period() {
+        assert_eq!(parse("sec(-x)"), parse("sec(x)"));
+        assert_eq!(parse("sec(x + 2*pi)"), parse("sec(x)"));
+        assert_eq!(parse("sec(x + pi/2)"), -parse("csc(x)"));
+    }
+}
+
+#[cfg(test)]
+mod cossecant {
+    use crate::base::{expression::Expression, operation::Operation, symbol::Symbol};
+    use crate::parsing::parser::parse;
+
+    #[test]
+    fn evaluates_reference_angles() {
+        assert_eq!(parse("csc(pi/6)"), Symbol::integer(2).expr());
+        assert_eq!(parse("csc(pi/4)"), parse("2^(1/2)"));
+        assert_eq!(parse("csc(pi/3)"), parse("2 * 3^(1/2) / 3"));
+        assert_eq!(parse("csc(pi/2)"), Symbol::integer(1).expr());
+        assert_eq!(parse("csc(3*pi/2)"), Symbol::integer(-1).expr());
+        assert_eq!(
+            parse("csc(0)"),
+            Expression::Cossecant(Operation::new(Symbol::integer(0).expr()))
+        );
+    }
+
+    #[test]
+    fn applies_parity_and_period() {
+        assert_eq!(parse("csc(-x)"), -parse("csc(x)"));
+        assert_eq!(parse("csc(x + pi)"), -parse("csc(x)"));
+        assert_eq!(parse("csc(x + pi/2)"), parse("sec(x)"));
+    }
+}
@@ -1,7 +1,8 @@
-use crate::base::{expression::Expression, operation::Operation, symbol::Symbol};
+use crate::base::expression::Expression;
+use crate::trigonometrics::trigonometric::Trigonometric;
 
 impl Expression {
     pub fn sin(angle: Expression) -> Expression {
-        return Expression::Sine(Operation::new(angle));
+        Trigonometric::Sine.build(angle)
     }
 }
@@ -0,0 +1,59 @@
+#[cfg(test)]
+mod constructor {
+    use crate::base::{expression::Expression, operation::Operation, symbol::Symbol};
+    use crate::parsing::parser::parse;
+
+    #[test]
+    fn evaluates_reference_angles() {
+        assert_eq!(parse("sin(0)"), Symbol::integer(0).expr());
+        assert_eq!(parse("sin(pi/6)"), Symbol::rational(1, 2).expr());
+        assert_eq!(parse("sin(pi/4)"), parse("2^(1/2) / 2"));
+        assert_eq!(parse("sin(pi/3)"), parse("3^(1/2) / 2"));
+        assert_eq!(parse("sin(pi/2)"), Symbol::integer(1).expr());
+    }
+
+    #[test]
+    fn evaluates_other_quadrants() {
+        assert_eq!(parse("sin(pi)"), Symbol::integer(0).expr());
+        assert_eq!(parse("sin(5*pi/6)"), Symbol::rational(1, 2).expr());
+        assert_eq!(parse("sin(7*pi/6)"), Symbol::rational(-1, 2).expr());
+        assert_eq!(parse("sin(3*pi/2)"), Symbol::integer(-1).expr());
+        assert_eq!(parse("sin(5*pi/3)"), parse("-3^(1/2) / 2"));
+    }
+
+    #[test]
+    fn keeps_unknown_angles() {
+        let x = Symbol::variable("x").expr();
+        assert_eq!(
+            Expression::sin(x.clone()),
+            Expression::Sine(Operation::new(x))
+        );
+        assert_eq!(
+            parse("sin(pi/5)"),
+            Expression::Sine(Operation::new(parse("pi/5")))
+        );
+        assert_eq!(
+            parse("sin(6*pi/5)"),
+            -Expression::Sine(Operation::new(parse("pi/5")))
+        );
+    }
+
+    #[test]
+    fn applies_parity() {
+        assert_eq!(parse("sin(-x)"), -parse("sin(x)"));
+        assert_eq!(parse("sin(-x - y)"), -parse("sin(x + y)"));
+        assert_eq!(parse("sin(-pi/6)"), Symbol::rational(-1, 2).expr());
+    }
+
+    #[test]
+    fn reduces_period() {
+        assert_eq!(parse("sin(x + 2*pi)"), parse("sin(x)"));
+        assert_eq!(parse("sin(13*pi/6)"), Symbol::rational(1, 2).expr());
+        assert_eq!(parse("sin(x + pi)"), -parse("sin(x)"));
+        assert_eq!(parse("sin(x + pi/2)"), parse("cos(x)"));
+        assert_eq!(parse("sin(x + 3*pi/4)"), parse("cos(x + pi/4)"));
+        assert_eq!(parse("sin(pi - x)"), parse("sin(x)"));
+        assert_eq!(parse("sin(pi/2 - x)"), parse("cos(x)"));
+        assert_eq!(parse("sin(3*pi - x - y)"), parse("sin(x + y)"));
+    }
+}
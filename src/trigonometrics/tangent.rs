@@ -0,0 +1,8 @@
+use crate::base::expression::Expression;
+use crate::trigonometrics::trigonometric::Trigonometric;
+
+impl Expression {
+    pub fn tan(angle: Expression) -> Expression {
+        Trigonometric::Tangent.build(angle)
+    }
+}
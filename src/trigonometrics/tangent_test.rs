@@ -0,0 +1,60 @@
+#[cfg(test)]
+mod tangent {
+    use crate::base::{expression::Expression, operation::Operation, symbol::Symbol};
+    use crate::parsing::parser::parse;
+
+    #[test]
+    fn evaluates_reference_angles() {
+        assert_eq!(parse("tan(0)"), Symbol::integer(0).expr());
+        assert_eq!(parse("tan(pi/6)"), parse("3^(1/2) / 3"));
+        assert_eq!(parse("tan(pi/4)"), Symbol::integer(1).expr());
+        assert_eq!(parse("tan(pi/3)"), parse("3^(1/2)"));
+        assert_eq!(parse("tan(3*pi/4)"), Symbol::integer(-1).expr());
+    }
+
+    #[test]
+    fn keeps_undefined_values() {
+        assert_eq!(
+            parse("tan(pi/2)"),
+            Expression::Tangent(Operation::new(parse("pi/2")))
+        );
+    }
+
+    #[test]
+    fn applies_parity_and_period() {
+        assert_eq!(parse("tan(-x)"), -parse("tan(x)"));
+        assert_eq!(parse("tan(x + pi)"), parse("tan(x)"));
+        assert_eq!(parse("tan(x + pi/2)"), -parse("cot(x)"));
+        assert_eq!(parse("tan(5*pi/4)"), Symbol::integer(1).expr());
+    }
+}
+
+#[cfg(test)]
+mod cotangent {
+    use crate::base::{expression::Expression, operation::Operation, symbol::Symbol};
+    use crate::parsing::parser::parse;
+
+    #[test]
+    fn evaluates_reference_angles() {
+        assert_eq!(parse("cot(pi/6)"), parse("3^(1/2)"));
+        assert_eq!(parse("cot(pi/4)"), Symbol::integer(1).expr());
+        assert_eq!(parse("cot(pi/3)"), parse("3^(1/2) / 3"));
+        assert_eq!(parse("cot(pi/2)"), Symbol::integer(0).expr());
+        assert_eq!(parse("cot(2*pi/3)"), parse("-3^(1/2) / 3"));
+    }
+
+    #[test]
+    fn keeps_undefined_values() {
+        assert_eq!(
+            parse("cot(0)"),
+            Expression::Cotangent(Operation::new(Symbol::integer(0).expr()))
+        );
+    }
+
+    #[test]
+    fn applies_parity_and_period() {
+        assert_eq!(parse("cot(-x)"), -parse("cot(x)"));
+        assert_eq!(parse("cot(x - pi)"), parse("cot(x)"));
+        assert_eq!(parse("cot(x + pi/2)"), -parse("tan(x)"));
+    }
+}
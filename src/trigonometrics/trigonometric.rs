@@ -0,0 +1,323 @@
+use crate::base::{expression::Expression, operation::Operation, symbol::Symbol};
+use num::rational::BigRational;
+
+/**
+ * Trigonometric ratios sharing exact values, symmetries and periods
+ *  - angles are reduced by parity and by the period of the ratio
+ *  - rational multiples of pi evaluate exactly at the reference angles of the first quadrant
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Trigonometric {
+    Sine,
+    Cossine,
+    Tangent,
+    Cotangent,
+    Secant,
+    Cossecant,
+}
+
+/* Reference angles of the first quadrant, as multiples of pi */
+const REFERENCE_ANGLES: [(isize, isize); 5] = [(0, 1), (1, 6), (1, 4), (1, 3), (1, 2)];
+
+impl Trigonometric {
+    /**
+     * Builds the ratio of the angle, reduced and exactly evaluated when possible
+     */
+    pub fn build(self, angle: Expression) -> Expression {
+        let (negative, magnitude) = angle_sign(&angle);
+        if negative {
+            let value = self.build(magnitude);
+            return if self.is_odd() { -value } else { value };
+        }
+
+        let (rest, coefficient) = pi_coefficient(&angle);
+        let coefficient = modulo(&coefficient, &self.period());
+        if rest == Symbol::integer(0).expr() {
+            return self.exact(&coefficient);
+        }
+
+        /* quarter period shifts turn the ratio into its cofunction: sin(x + pi/2) = cos(x) */
+        let quarter = Symbol::rational(1, 2);
+        let mut ratio = self;
+        let mut negative = false;
+        let mut coefficient = coefficient;
+        while coefficient >= quarter {
+            let (cofunction, opposite) = ratio.cofunction();
+            ratio = cofunction;
+            negative ^= opposite;
+            coefficient = coefficient - &quarter;
+        }
+
+        /* shifts may leave a negative angle, as sin(pi - x) == -sin(-x), so parity applies again */
+        let node = match angle_sign(&(rest + coefficient.expr() * Symbol::pi().expr())) {
+            (true, magnitude) => {
+                negative ^= ratio.is_odd();
+                ratio.node(magnitude)
+            }
+            (false, angle) => ratio.node(angle),
+        };
+        if negative {
+            -node
+        } else {
+            node
+        }
+    }
+
+    /**
+     * Builds the inverse ratio of the value, exactly evaluated at the reference angles
+     *  - results are principal values: [-pi/2, pi/2] for odd inverses, [0, pi] otherwise
+     */
+    pub fn build_inverse(self, value: Expression) -> Expression {
+        if let Some(angle) = self.reference_angle(&value) {
+            return angle;
+        }
+
+        let (negative, magnitude) = value.split_sign();
+        if negative {
+            let inverse = self.build_inverse(magnitude);
+            if self.has_odd_inverse() {
+                return -inverse;
+            }
+            return match pi_coefficient(&inverse) {
+                (rest, coefficient) if rest == Symbol::integer(0).expr() => {
+                    (Symbol::rational(1, 1) - coefficient).expr() * Symbol::pi().expr()
+                }
+                _ => Symbol::pi().expr() - inverse,
+            };
+        }
+
+        self.inverse_node(value)
+    }
+
+    /**
+     * Expression node without any reduction
+     */
+    pub fn node(self, angle: Expression) -> Expression {
+        let operation = Operation::new(angle);
+        match self {
+            Trigonometric::Sine => Expression::Sine(operation),
+            Trigonometric::Cossine => Expression::Cossine(operation),
+            Trigonometric::Tangent => Expression::Tangent(operation),
+            Trigonometric::Cotangent => Expression::Cotangent(operation),
+            Trigonometric::Secant => Expression::Secant(operation),
+            Trigonometric::Cossecant => Expression::Cossecant(operation),
+        }
+    }
+
+    /**
+     * Inverse expression node without any reduction
+     */
+    pub fn inverse_node(self, value: Expression) -> Expression {
+        let operation = Operation::new(value);
+        match self {
+            Trigonometric::Sine => Expression::ArcSine(operation),
+            Trigonometric::Cossine => Expression::ArcCossine(operation),
+            Trigonometric::Tangent => Expression::ArcTangent(operation),
+            Trigonometric::Cotangent => Expression::ArcCotangent(operation),
+            Trigonometric::Secant => Expression::ArcSecant(operation),
+            Trigonometric::Cossecant => Expression::ArcCossecant(operation),
+        }
+    }
+
+    fn is_odd(self) -> bool {
+        !matches!(self, Trigonometric::Cossine | Trigonometric::Secant)
+    }
+
+    fn has_odd_inverse(self) -> bool {
+        matches!(
+            self,
+            Trigonometric::Sine | Trigonometric::Tangent | Trigonometric::Cossecant
+        )
+    }
+
+    /**
+     * Period as a multiple of pi
+     */
+    fn period(self) -> Symbol<BigRational> {
+        match self {
+            Trigonometric::Tangent | Trigonometric::Cotangent => Symbol::rational(1, 1),
+            _ => Symbol::rational(2, 1),
+        }
+    }
+
+    /**
+     * Ratio and sign after a quarter period shift: f(x + pi/2) = +-g(x)
+     */
+    fn cofunction(self) -> (Trigonometric, bool) {
+        match self {
+            Trigonometric::Sine => (Trigonometric::Cossine, false),
+            Trigonometric::Cossine => (Trigonometric::Sine, true),
+            Trigonometric::Tangent => (Trigonometric::Cotangent, true),
+            Trigonometric::Cotangent => (Trigonometric::Tangent, true),
+            Trigonometric::Secant => (Trigonometric::Cossecant, true),
+            Trigonometric::Cossecant => (Trigonometric::Secant, false),
+        }
+    }
+
+    /**
+     * Sign of the ratio given the signs of sine and cossine in the quadrant
+     */
+    fn is_negative_in(self, sine_negative: bool, cossine_negative: bool) -> bool {
+        match self {
+            Trigonometric::Sine | Trigonometric::Cossecant => sine_negative,
+            Trigonometric::Cossine | Trigonometric::Secant => cossine_negative,
+            Trigonometric::Tangent | Trigonometric::Cotangent => sine_negative ^ cossine_negative,
+        }
+    }
+
+    /**
+     * Values at the reference angles, `None` where the ratio is undefined
+     */
+    fn reference_values(self) -> [Option<Expression>; 5] {
+        let integer = |n: isize| Some(Symbol::integer(n).expr());
+        let surd = |n: isize, numerator: isize, denominator: isize| {
+            Some(
+                Symbol::rational(numerator, denominator).expr()
+                    * Expression::power(Symbol::integer(n).expr(), Symbol::rational(1, 2).expr()),
+            )
+        };
+
+        match self {
+            Trigonometric::Sine => [
+                integer(0),
+                Some(Symbol::rational(1, 2).expr()),
+                surd(2, 1, 2),
+                surd(3, 1, 2),
+                integer(1),
+            ],
+            Trigonometric::Cossine => [
+                integer(1),
+                surd(3, 1, 2),
+                surd(2, 1, 2),
+                Some(Symbol::rational(1, 2).expr()),
+                integer(0),
+            ],
+            Trigonometric::Tangent => [integer(0), surd(3, 1, 3), integer(1), surd(3, 1, 1), None],
+            Trigonometric::Cotangent => {
+                [None, surd(3, 1, 1), integer(1), surd(3, 1, 3), integer(0)]
+            }
+            Trigonometric::Secant => [integer(1), surd(3, 2, 3), surd(2, 1, 1), integer(2), None],
+            Trigonometric::Cossecant => {
+                [None, integer(2), surd(2, 1, 1), surd(3, 2, 3), integer(1)]
+            }
+        }
+    }
+
+    /**
+     * Value at a multiple of pi already reduced to one period
+     */
+    fn exact(self, coefficient: &Symbol<BigRational>) -> Expression {
+        let half = Symbol::rational(1, 2);
+        let one = Symbol::rational(1, 1);
+        let three_halves = Symbol::rational(3, 2);
+
+        let (reference, sine_negative, cossine_negative) = if coefficient <= &half {
+            (coefficient.clone(), false, false)
+        } else if coefficient <= &one {
+            (&one - coefficient, false, true)
+        } else if coefficient <= &three_halves {
+            (coefficient - &one, true, true)
+        } else {
+            (Symbol::rational(2, 1) - coefficient, true, false)
+        };
+
+        let value = REFERENCE_ANGLES
+            .iter()
+            .position(|&(numerator, denominator)| {
+                Symbol::rational(numerator, denominator) == reference
+            })
+            .and_then(|index| self.reference_values()[index].clone());
+
+        let magnitude = match value {
+            Some(value) => value,
+            None => self.node(reference.expr() * Symbol::pi().expr()),
+        };
+
+        if self.is_negative_in(sine_negative, cossine_negative) {
+            -magnitude
+        } else {
+            magnitude
+        }
+    }
+
+    /**
+     * Reference angle whose ratio is the value
+     */
+    fn reference_angle(self, value: &Expression) -> Option<Expression> {
+        let values = self.reference_values();
+        let index = values
+            .iter()
+            .position(|reference| reference.as_ref() == Some(value))?;
+        let (numerator, denominator) = REFERENCE_ANGLES[index];
+        Some(Symbol::rational(numerator, denominator).expr() * Symbol::pi().expr())
+    }
+}
+
+/**
+ * Sign of an angle, an addition is negative when all its addends are
+ */
+fn angle_sign(angle: &Expression) -> (bool, Expression) {
+    match angle {
+        Expression::Addition(addends) => {
+            if addends.items().iter().all(|addend| addend.split_sign().0) {
+                (true, Expression::addition(addends.map(&|addend| -addend)))
+            } else {
+                (false, angle.clone())
+            }
+        }
+        _ => angle.split_sign(),
+    }
+}
+
+/**
+ * Exact coefficient of a rational multiple of pi
+ */
+fn pi_multiple(term: &Expression) -> Option<Symbol<BigRational>> {
+    let is_pi = |factor: &Expression| factor == &Symbol::pi().expr();
+    match term {
+        Expression::Constant(_) if is_pi(term) => Some(Symbol::rational(1, 1)),
+        Expression::Multiplication(factors) => {
+            let factors = factors.items();
+            if factors.len() != 2 || !factors.iter().any(is_pi) {
+                return None;
+            }
+            factors.iter().find_map(|factor| match factor {
+                Expression::Integer(n) => Some(Symbol::from(n.clone())),
+                Expression::Rational(q) => Some(q.clone()),
+                _ => None,
+            })
+        }
+        _ => None,
+    }
+}
+
+/**
+ * Splits an angle into the remaining addends and the coefficient of pi
+ */
+pub fn pi_coefficient(angle: &Expression) -> (Expression, Symbol<BigRational>) {
+    let addends = match angle {
+        Expression::Addition(addends) => addends.items(),
+        _ => vec![angle.clone()],
+    };
+
+    let mut rest: Vec<Expression> = Vec::new();
+    let mut coefficient = Symbol::rational(0, 1);
+    for addend in addends.iter() {
+        match pi_multiple(addend) {
+            Some(multiple) => coefficient = coefficient + multiple,
+            None => rest.push(addend.clone()),
+        }
+    }
+
+    (Expression::addition(rest), coefficient)
+}
+
+/**
+ * Non-negative remainder of the division by the period
+ */
+fn modulo(coefficient: &Symbol<BigRational>, period: &Symbol<BigRational>) -> Symbol<BigRational> {
+    let quotient = Symbol {
+        data: (coefficient / period).data.floor(),
+    };
+    coefficient - &(quotient * period)
+}